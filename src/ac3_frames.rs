@@ -0,0 +1,505 @@
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const AC3_TEMPLATE_CONTENT: &str = include_str!("templates/ac3_frames/ac3.tmpl");
+const EAC3_TEMPLATE_CONTENT: &str = include_str!("templates/ac3_frames/eac3.tmpl");
+
+pub const SYNC_WORD: &[u8] = &[0x0B, 0x77];
+pub const FRAME_INFO_LENGTH_IN_BYTES: usize = 6;
+pub const BSI_LENGTH_IN_BYTES: usize = 32;
+const MAXIMUM_AC3_BSID: u8 = 10;
+const MAXIMUM_EAC3_BSID: u8 = 16;
+const STANDARD_AC3_BSID: u8 = 8;
+const RESERVED_SAMPLE_RATE_CODE: u8 = 3;
+const NUMBER_OF_FRAME_SIZE_CODES: u8 = 38;
+const RESERVED_STREAM_TYPE: u8 = 3;
+const AC3_BLOCKS_PER_FRAME: u32 = 6;
+const SAMPLES_PER_BLOCK: u32 = 256;
+const DUAL_MONO_CHANNEL_MODE: u8 = 0;
+const MONO_CHANNEL_MODE: u8 = 1;
+const STEREO_CHANNEL_MODE: u8 = 2;
+const DEPENDENT_STREAM_TYPE: u8 = 1;
+const INDEPENDENT_STREAM_TYPE: u8 = 0;
+const MIXING_LEVEL_OFFSET_IN_DB: u8 = 80;
+const DIALOGUE_NORMALIZATION_ZERO_VALUE_IN_DB: u8 = 31;
+const RESERVED_SURROUND_MIX_LEVELS: u8 = 3;
+
+pub const SAMPLE_RATES: [u32; 3] = [48000, 44100, 32000];
+const REDUCED_SAMPLE_RATES: [u32; 3] = [24000, 22050, 16000];
+const BLOCKS_PER_FRAME: [u32; 4] = [1, 2, 3, 6];
+pub const BITRATES_IN_KBPS: [u32; 19] = [
+    32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 576, 640,
+];
+
+pub const BIT_STREAM_MODES: [&str; 8] = [
+    "Complete Main (CM)",
+    "Music and Effects (ME)",
+    "Visually Impaired (VI)",
+    "Hearing Impaired (HI)",
+    "Dialogue (D)",
+    "Commentary (C)",
+    "Emergency (E)",
+    "Voice Over (VO) / Karaoke",
+];
+
+const CHANNEL_LAYOUTS: [(&str, u8); 8] = [
+    ("1+1 (Dual Mono: Ch1, Ch2)", 2),
+    ("1/0 (Mono: C)", 1),
+    ("2/0 (Stereo: L, R)", 2),
+    ("3/0 (L, C, R)", 3),
+    ("2/1 (L, R, S)", 3),
+    ("3/1 (L, C, R, S)", 4),
+    ("2/2 (L, R, Ls, Rs)", 4),
+    ("3/2 (L, C, R, Ls, Rs)", 5),
+];
+
+const CENTER_MIX_LEVELS: [&str; 4] = ["-3.0 dB", "-4.5 dB", "-6.0 dB", "Reserved"];
+const SURROUND_MIX_LEVELS: [&str; 4] = ["-3 dB", "-6 dB", "Off", "Reserved"];
+const DOLBY_SURROUND_MODES: [&str; 4] = [
+    "Not Indicated",
+    "Not Dolby Surround Encoded",
+    "Dolby Surround Encoded",
+    "Reserved",
+];
+const ROOM_TYPES: [&str; 4] = [
+    "Not Indicated",
+    "Large Room, X Curve Monitor",
+    "Small Room, Flat Monitor",
+    "Reserved",
+];
+const STREAM_TYPES: [&str; 4] = [
+    "Independent",
+    "Dependent",
+    "Independent (Converted From AC-3)",
+    "Reserved",
+];
+const DOWNMIX_MODES: [&str; 4] = [
+    "Not Indicated",
+    "Lt/Rt Preferred",
+    "Lo/Ro Preferred",
+    "Lo/Ro Preferred (Pro Logic II)",
+];
+const DOWNMIX_LEVELS: [&str; 8] = [
+    "+3.0 dB", "+1.5 dB", "0.0 dB", "-1.5 dB", "-3.0 dB", "-4.5 dB", "-6.0 dB", "Off",
+];
+const CHANNEL_MAP_LOCATIONS: [&str; 16] = [
+    "L", "C", "R", "Ls", "Rs", "Lc/Rc", "Lrs/Rrs", "Cs", "Ts", "Lsd/Rsd", "Lw/Rw", "Lvh/Rvh",
+    "Cvh", "Reserved", "LFE2", "LFE",
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct FrameInfo {
+    pub is_enhanced: bool,
+    pub stream_type: u8,
+    pub substream_id: u8,
+    pub frame_length: usize,
+    pub sample_rate: u32,
+    pub samples_per_frame: u32,
+}
+
+impl FrameInfo {
+    pub fn is_independent(&self) -> bool {
+        !self.is_enhanced || self.stream_type != DEPENDENT_STREAM_TYPE
+    }
+
+    pub fn get_stream_name(&self) -> String {
+        match self.is_enhanced {
+            true => format!(
+                "{} Substream {}",
+                get_name_from_list(&STREAM_TYPES, self.stream_type),
+                self.substream_id
+            ),
+            false => "AC-3".to_string(),
+        }
+    }
+}
+
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn take_bits(&mut self, count: usize) -> u32 {
+        let mut value = 0;
+
+        for _ in 0..count {
+            let bit = self
+                .bytes
+                .get(self.position / 8)
+                .map_or(0, |byte| (byte >> (7 - self.position % 8)) & 0x01);
+            value = (value << 1) | bit as u32;
+            self.position += 1;
+        }
+
+        value
+    }
+
+    pub fn take_flag(&mut self) -> bool {
+        self.take_bits(1) == 1
+    }
+
+    pub fn remaining_bits(&self) -> usize {
+        (self.bytes.len() * 8).saturating_sub(self.position)
+    }
+
+    fn take_optional_bits(&mut self, count: usize) -> Option<u32> {
+        match self.take_flag() {
+            true => Some(self.take_bits(count)),
+            false => None,
+        }
+    }
+}
+
+pub fn is_ac3_sync(header_bytes: &[u8]) -> bool {
+    header_bytes.starts_with(SYNC_WORD)
+}
+
+pub fn get_frame_info_from_bytes(header_bytes: &[u8]) -> Option<FrameInfo> {
+    if header_bytes.len() < FRAME_INFO_LENGTH_IN_BYTES || !is_ac3_sync(header_bytes) {
+        return None;
+    }
+
+    let bsid = header_bytes[5] >> 3;
+
+    match bsid {
+        0..=MAXIMUM_AC3_BSID => get_ac3_frame_info(header_bytes, bsid),
+        11..=MAXIMUM_EAC3_BSID => get_eac3_frame_info(header_bytes),
+        _ => None,
+    }
+}
+
+pub fn get_metadata(frame_bytes: &[u8]) -> Result<OutputEntry, Box<dyn Error>> {
+    let frame_info = get_frame_info_from_bytes(frame_bytes)
+        .ok_or("The frame does not start with a valid AC-3 or E-AC-3 header")?;
+
+    let formated_output = match frame_info.is_enhanced {
+        true => get_eac3_bsi_output(frame_bytes, &frame_info)?,
+        false => get_ac3_bsi_output(frame_bytes, &frame_info)?,
+    };
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_ac3_frame_info(header_bytes: &[u8], bsid: u8) -> Option<FrameInfo> {
+    let sample_rate_code = header_bytes[4] >> 6;
+    let frame_size_code = header_bytes[4] & 0x3F;
+
+    if sample_rate_code == RESERVED_SAMPLE_RATE_CODE
+        || frame_size_code >= NUMBER_OF_FRAME_SIZE_CODES
+    {
+        return None;
+    }
+
+    let sample_rate_shift = bsid.saturating_sub(STANDARD_AC3_BSID);
+
+    Some(FrameInfo {
+        is_enhanced: false,
+        stream_type: INDEPENDENT_STREAM_TYPE,
+        substream_id: 0,
+        frame_length: get_ac3_frame_length(sample_rate_code, frame_size_code),
+        sample_rate: SAMPLE_RATES[sample_rate_code as usize] >> sample_rate_shift,
+        samples_per_frame: AC3_BLOCKS_PER_FRAME * SAMPLES_PER_BLOCK,
+    })
+}
+
+fn get_eac3_frame_info(header_bytes: &[u8]) -> Option<FrameInfo> {
+    let stream_type = header_bytes[2] >> 6;
+    let substream_id = (header_bytes[2] >> 3) & 0x07;
+    let frame_size = (((header_bytes[2] & 0x07) as usize) << 8) | header_bytes[3] as usize;
+    let sample_rate_code = header_bytes[4] >> 6;
+    let reduced_rate_or_blocks_code = (header_bytes[4] >> 4) & 0x03;
+
+    if stream_type == RESERVED_STREAM_TYPE {
+        return None;
+    }
+
+    let (sample_rate, blocks_per_frame) = match sample_rate_code {
+        RESERVED_SAMPLE_RATE_CODE => (
+            *REDUCED_SAMPLE_RATES.get(reduced_rate_or_blocks_code as usize)?,
+            AC3_BLOCKS_PER_FRAME,
+        ),
+        code => (
+            SAMPLE_RATES[code as usize],
+            BLOCKS_PER_FRAME[reduced_rate_or_blocks_code as usize],
+        ),
+    };
+
+    Some(FrameInfo {
+        is_enhanced: true,
+        stream_type,
+        substream_id,
+        frame_length: (frame_size + 1) * 2,
+        sample_rate,
+        samples_per_frame: blocks_per_frame * SAMPLES_PER_BLOCK,
+    })
+}
+
+fn get_ac3_frame_length(sample_rate_code: u8, frame_size_code: u8) -> usize {
+    let bitrate = BITRATES_IN_KBPS[(frame_size_code / 2) as usize] as usize;
+
+    match sample_rate_code {
+        0 => bitrate * 4,
+        1 => (bitrate * 320 / 147 + (frame_size_code & 0x01) as usize) * 2,
+        _ => bitrate * 6,
+    }
+}
+
+fn get_ac3_bsi_output(
+    frame_bytes: &[u8],
+    frame_info: &FrameInfo,
+) -> Result<String, Box<dyn Error>> {
+    let mut bits = BitReader::new(&frame_bytes[5..]);
+    let bsid = bits.take_bits(5) as u8;
+    let bsmod = bits.take_bits(3) as u8;
+    let acmod = bits.take_bits(3) as u8;
+
+    let mut center_mix_level = String::new();
+    let mut surround_mix_level = String::new();
+    let mut dolby_surround_mode = String::new();
+
+    if acmod & 0x01 != 0 && acmod != MONO_CHANNEL_MODE {
+        center_mix_level = get_name_from_list(&CENTER_MIX_LEVELS, bits.take_bits(2) as u8);
+    }
+    if acmod & 0x04 != 0 {
+        surround_mix_level = get_name_from_list(&SURROUND_MIX_LEVELS, bits.take_bits(2) as u8);
+    }
+    if acmod == STEREO_CHANNEL_MODE {
+        dolby_surround_mode = get_name_from_list(&DOLBY_SURROUND_MODES, bits.take_bits(2) as u8);
+    }
+
+    let lfe_on = bits.take_flag();
+    let dialogue_normalization = bits.take_bits(5) as u8;
+    let compression = bits.take_optional_bits(8);
+    let _language_code = bits.take_optional_bits(8);
+    let audio_production_info = bits.take_optional_bits(7);
+
+    if acmod == DUAL_MONO_CHANNEL_MODE {
+        let _dialogue_normalization_2 = bits.take_bits(5);
+        let _compression_2 = bits.take_optional_bits(8);
+        let _language_code_2 = bits.take_optional_bits(8);
+        let _audio_production_info_2 = bits.take_optional_bits(7);
+    }
+
+    let copyright = bits.take_flag();
+    let original = bits.take_flag();
+
+    let (mixing_level, room_type) = match audio_production_info {
+        Some(info) => (
+            format!("{} dB SPL", (info >> 2) as u8 + MIXING_LEVEL_OFFSET_IN_DB),
+            get_name_from_list(&ROOM_TYPES, (info & 0x03) as u8),
+        ),
+        None => (String::new(), String::new()),
+    };
+
+    let output_values: Value = upon::value! {
+        bsid: bsid,
+        bit_stream_mode: get_name_from_list(&BIT_STREAM_MODES, bsmod),
+        sample_rate: frame_info.sample_rate,
+        bitrate: get_bitrate_in_kbps(frame_info),
+        frame_length: frame_info.frame_length,
+        channel_layout: get_channel_layout(acmod, lfe_on),
+        channels: get_channel_count(acmod, lfe_on),
+        lfe_on: lfe_on,
+        dialogue_normalization: format_dialogue_normalization(dialogue_normalization),
+        compression: compression.map(format_compression_gain).unwrap_or_default(),
+        center_mix_level: center_mix_level,
+        surround_mix_level: surround_mix_level,
+        dolby_surround_mode: dolby_surround_mode,
+        mixing_level: mixing_level,
+        room_type: room_type,
+        copyright: copyright,
+        original: original,
+    };
+
+    Ok(get_file_chunk_output(AC3_TEMPLATE_CONTENT, output_values)?)
+}
+
+fn get_eac3_bsi_output(
+    frame_bytes: &[u8],
+    frame_info: &FrameInfo,
+) -> Result<String, Box<dyn Error>> {
+    let mut bits = BitReader::new(&frame_bytes[4..]);
+    let _sample_rate_and_number_of_blocks_codes = bits.take_bits(4);
+    let acmod = bits.take_bits(3) as u8;
+    let lfe_on = bits.take_flag();
+    let bsid = bits.take_bits(5) as u8;
+    let dialogue_normalization = bits.take_bits(5) as u8;
+    let compression = bits.take_optional_bits(8);
+
+    if acmod == DUAL_MONO_CHANNEL_MODE {
+        let _dialogue_normalization_2 = bits.take_bits(5);
+        let _compression_2 = bits.take_optional_bits(8);
+    }
+
+    let mut channel_map = String::new();
+    if frame_info.stream_type == DEPENDENT_STREAM_TYPE {
+        if let Some(map) = bits.take_optional_bits(16) {
+            channel_map = format_channel_map(map as u16);
+        }
+    }
+
+    let mut downmix_mode = String::new();
+    let mut ltrt_center_mix_level = String::new();
+    let mut loro_center_mix_level = String::new();
+    let mut ltrt_surround_mix_level = String::new();
+    let mut loro_surround_mix_level = String::new();
+
+    if bits.take_flag() {
+        if acmod > STEREO_CHANNEL_MODE {
+            downmix_mode = get_name_from_list(&DOWNMIX_MODES, bits.take_bits(2) as u8);
+        }
+        if acmod & 0x01 != 0 && acmod > STEREO_CHANNEL_MODE {
+            ltrt_center_mix_level = get_name_from_list(&DOWNMIX_LEVELS, bits.take_bits(3) as u8);
+            loro_center_mix_level = get_name_from_list(&DOWNMIX_LEVELS, bits.take_bits(3) as u8);
+        }
+        if acmod & 0x04 != 0 {
+            ltrt_surround_mix_level = get_surround_downmix_level(bits.take_bits(3) as u8);
+            loro_surround_mix_level = get_surround_downmix_level(bits.take_bits(3) as u8);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        stream_type: get_name_from_list(&STREAM_TYPES, frame_info.stream_type),
+        substream_id: frame_info.substream_id,
+        bsid: bsid,
+        sample_rate: frame_info.sample_rate,
+        blocks_per_frame: frame_info.samples_per_frame / SAMPLES_PER_BLOCK,
+        bitrate: get_bitrate_in_kbps(frame_info),
+        frame_length: frame_info.frame_length,
+        channel_layout: get_channel_layout(acmod, lfe_on),
+        channels: get_channel_count(acmod, lfe_on),
+        lfe_on: lfe_on,
+        dialogue_normalization: format_dialogue_normalization(dialogue_normalization),
+        compression: compression.map(format_compression_gain).unwrap_or_default(),
+        channel_map: channel_map,
+        downmix_mode: downmix_mode,
+        ltrt_center_mix_level: ltrt_center_mix_level,
+        loro_center_mix_level: loro_center_mix_level,
+        ltrt_surround_mix_level: ltrt_surround_mix_level,
+        loro_surround_mix_level: loro_surround_mix_level,
+    };
+
+    Ok(get_file_chunk_output(EAC3_TEMPLATE_CONTENT, output_values)?)
+}
+
+fn get_bitrate_in_kbps(frame_info: &FrameInfo) -> String {
+    let bitrate = frame_info.frame_length as f64 * 8.0 * frame_info.sample_rate as f64
+        / frame_info.samples_per_frame as f64
+        / 1000.0;
+
+    format!("{:.0}", bitrate)
+}
+
+pub fn get_channel_layout(acmod: u8, lfe_on: bool) -> String {
+    let (layout, _) = CHANNEL_LAYOUTS[acmod as usize];
+
+    match lfe_on {
+        true => format!("{} + LFE", layout),
+        false => layout.to_string(),
+    }
+}
+
+pub fn get_channel_count(acmod: u8, lfe_on: bool) -> u8 {
+    let (_, channels) = CHANNEL_LAYOUTS[acmod as usize];
+    channels + lfe_on as u8
+}
+
+fn format_dialogue_normalization(dialogue_normalization: u8) -> String {
+    match dialogue_normalization {
+        0 => format!("-{} dB", DIALOGUE_NORMALIZATION_ZERO_VALUE_IN_DB),
+        level => format!("-{} dB", level),
+    }
+}
+
+fn format_compression_gain(compression: u32) -> String {
+    let exponent = ((compression as u8 as i8) >> 4) as i32;
+    let mantissa = (compression & 0x0F) as f64;
+    let gain = 2f64.powi(exponent + 1) * (16.0 + mantissa) / 32.0;
+
+    format!("{:+.2} dB", 20.0 * gain.log10())
+}
+
+fn format_channel_map(channel_map: u16) -> String {
+    CHANNEL_MAP_LOCATIONS
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| channel_map & (0x8000 >> index) != 0)
+        .map(|(_, location)| *location)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+fn get_surround_downmix_level(level: u8) -> String {
+    match level {
+        0..RESERVED_SURROUND_MIX_LEVELS => "Reserved".to_string(),
+        _ => get_name_from_list(&DOWNMIX_LEVELS, level),
+    }
+}
+
+pub fn get_name_from_list(list: &[&str], index: u8) -> String {
+    match list.get(index as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown ({})", index),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_frame_info_from_ac3_and_eac3_headers() {
+        let ac3_header = [0x0B, 0x77, 0x00, 0x00, 0x1E, 0x40];
+        let ac3_result = FrameInfo {
+            is_enhanced: false,
+            stream_type: 0,
+            substream_id: 0,
+            frame_length: 1792,
+            sample_rate: 48000,
+            samples_per_frame: 1536,
+        };
+        assert_eq!(get_frame_info_from_bytes(&ac3_header), Some(ac3_result));
+
+        let eac3_header = [0x0B, 0x77, 0x42, 0xFF, 0x3F, 0x80];
+        let eac3_result = FrameInfo {
+            is_enhanced: true,
+            stream_type: 1,
+            substream_id: 0,
+            frame_length: 1536,
+            sample_rate: 48000,
+            samples_per_frame: 1536,
+        };
+        assert_eq!(get_frame_info_from_bytes(&eac3_header), Some(eac3_result));
+        assert_eq!(
+            get_frame_info_from_bytes(&[0x0B, 0x77, 0, 0, 0xFF, 0x40]),
+            None
+        );
+    }
+
+    #[test]
+    fn return_correct_frame_length_for_44100_hz_ac3_frames() {
+        assert_eq!(get_ac3_frame_length(1, 0), 138);
+        assert_eq!(get_ac3_frame_length(1, 1), 140);
+        assert_eq!(get_ac3_frame_length(1, 37), 2788);
+    }
+
+    #[test]
+    fn return_correct_formated_bsi_values() {
+        assert_eq!(format_compression_gain(0x00), "+0.00 dB");
+        assert_eq!(format_compression_gain(0xF0), "-6.02 dB");
+        assert_eq!(format_dialogue_normalization(0), "-31 dB");
+        assert_eq!(format_channel_map(0xA001), "L, R, LFE");
+        assert_eq!(get_channel_layout(7, true), "3/2 (L, C, R, Ls, Rs) + LFE");
+        assert_eq!(get_channel_count(7, true), 6);
+    }
+}
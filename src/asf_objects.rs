@@ -0,0 +1,270 @@
+pub mod codec_list;
+pub mod content_description;
+pub mod extended_content_description;
+pub mod file_properties;
+pub mod metadata;
+pub mod stream_properties;
+pub mod unknown;
+
+use crate::byte_arrays::{
+    take_first_eight_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::format_bytes_as_string_of_bytes;
+use chrono::DateTime;
+use uuid::Uuid;
+
+pub const GUID_LENGTH_IN_BYTES: usize = 16;
+pub const OBJECT_HEADER_LENGTH_IN_BYTES: usize = 24;
+pub const HEADER_OBJECT_GUID: &str = "75B22630-668E-11CF-A6D9-00AA0062CE6C";
+pub const FILE_PROPERTIES_OBJECT_GUID: &str = "8CABDCA1-A947-11CF-8EE4-00C00C205365";
+pub const STREAM_PROPERTIES_OBJECT_GUID: &str = "B7DC0791-A9B7-11CF-8EE6-00C00C205365";
+pub const HEADER_EXTENSION_OBJECT_GUID: &str = "5FBF03B5-A92E-11CF-8EE3-00C00C205365";
+pub const CODEC_LIST_OBJECT_GUID: &str = "86D15240-311D-11D0-A3A4-00A0C90348F6";
+pub const CONTENT_DESCRIPTION_OBJECT_GUID: &str = "75B22633-668E-11CF-A6D9-00AA0062CE6C";
+pub const EXTENDED_CONTENT_DESCRIPTION_OBJECT_GUID: &str = "D2D0A440-E307-11D2-97F0-00A0C95EA850";
+pub const METADATA_OBJECT_GUID: &str = "C5F8CBEA-5BAF-4877-8467-AA8C44FA4CCA";
+pub const METADATA_LIBRARY_OBJECT_GUID: &str = "44231C94-9498-49D1-A141-1D134E457054";
+const KNOWN_OBJECT_NAMES: [(&str, &str); 26] = [
+    (HEADER_OBJECT_GUID, "Header"),
+    (FILE_PROPERTIES_OBJECT_GUID, "File Properties"),
+    (STREAM_PROPERTIES_OBJECT_GUID, "Stream Properties"),
+    (HEADER_EXTENSION_OBJECT_GUID, "Header Extension"),
+    (CODEC_LIST_OBJECT_GUID, "Codec List"),
+    (CONTENT_DESCRIPTION_OBJECT_GUID, "Content Description"),
+    (
+        EXTENDED_CONTENT_DESCRIPTION_OBJECT_GUID,
+        "Extended Content Description",
+    ),
+    (METADATA_OBJECT_GUID, "Metadata"),
+    (METADATA_LIBRARY_OBJECT_GUID, "Metadata Library"),
+    ("75B22636-668E-11CF-A6D9-00AA0062CE6C", "Data"),
+    ("33000890-E5B1-11CF-89F4-00A0C90349CB", "Simple Index"),
+    ("D6E229D3-35DA-11D1-9034-00A0C90349BE", "Index"),
+    ("FEB103F8-12AD-4C64-840F-2A1D2F7AD48C", "Media Object Index"),
+    ("3CB73FD0-0C4A-4803-953D-EDF7B6228F0C", "Timecode Index"),
+    (
+        "7BF875CE-468D-11D1-8D82-006097C9A2B2",
+        "Stream Bitrate Properties",
+    ),
+    ("1EFB1A30-0B62-11D0-A39B-00A0C90348F6", "Script Command"),
+    ("F487CD01-A951-11CF-8EE6-00C00C205365", "Marker"),
+    (
+        "D6E229DC-35DA-11D1-9034-00A0C90349BE",
+        "Bitrate Mutual Exclusion",
+    ),
+    ("2211B3FA-BD23-11D2-B4B7-00A0C955FC6E", "Content Branding"),
+    ("2211B3FB-BD23-11D2-B4B7-00A0C955FC6E", "Content Encryption"),
+    (
+        "298AE614-2622-4C17-B935-DAE07EE9289C",
+        "Extended Content Encryption",
+    ),
+    ("1806D474-CADF-4509-A4BA-9AABCB96AAE8", "Padding"),
+    (
+        "14E6A5CB-C672-4332-8399-A96952065B5A",
+        "Extended Stream Properties",
+    ),
+    ("7C4346A9-EFE0-4BFC-B229-393EDE415C85", "Language List"),
+    ("D6E229DF-35DA-11D1-9034-00A0C90349BE", "Index Parameters"),
+    ("26F18B5D-4584-47EC-9F5F-0E651F0452C9", "Compatibility"),
+];
+const ATTRIBUTE_TYPE_UNICODE_STRING: u16 = 0;
+const ATTRIBUTE_TYPE_BYTE_ARRAY: u16 = 1;
+const ATTRIBUTE_TYPE_BOOL: u16 = 2;
+const ATTRIBUTE_TYPE_GUID: u16 = 6;
+const NUMBER_OF_BYTE_ARRAY_BYTES_TO_DISPLAY: usize = 16;
+const HUNDRED_NANOSECONDS_PER_MILLISECOND: u64 = 10_000;
+const HUNDRED_NANOSECONDS_PER_SECOND: u64 = 10_000_000;
+const FILETIME_EPOCH_OFFSET_IN_SECONDS: i64 = 11_644_473_600;
+const BAD_TIMESTAMP_MESSAGE: &str = "Invalid Date";
+
+#[derive(Debug, PartialEq)]
+pub struct AsfObject {
+    pub guid: String,
+    pub data: Vec<u8>,
+}
+
+pub fn get_guid_from_bytes(guid_bytes: &[u8]) -> String {
+    let mut guid_array: [u8; GUID_LENGTH_IN_BYTES] = Default::default();
+    guid_array.copy_from_slice(&guid_bytes[..GUID_LENGTH_IN_BYTES]);
+
+    Uuid::from_bytes_le(guid_array)
+        .hyphenated()
+        .to_string()
+        .to_uppercase()
+}
+
+pub fn take_first_guid(byte_data: &mut Vec<u8>) -> Result<String, LocalError> {
+    let guid_bytes = take_first_number_of_bytes(byte_data, GUID_LENGTH_IN_BYTES)?;
+    Ok(get_guid_from_bytes(&guid_bytes))
+}
+
+pub fn get_object_name_from_guid(guid: &str) -> String {
+    KNOWN_OBJECT_NAMES
+        .iter()
+        .find(|(object_guid, _)| *object_guid == guid)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown Object {}", guid))
+}
+
+pub fn is_known_object_guid(guid: &str) -> bool {
+    KNOWN_OBJECT_NAMES
+        .iter()
+        .any(|(object_guid, _)| *object_guid == guid)
+}
+
+pub fn get_child_objects_from_bytes(
+    mut object_data: Vec<u8>,
+) -> Result<Vec<AsfObject>, LocalError> {
+    let mut objects: Vec<AsfObject> = vec![];
+
+    while object_data.len() >= OBJECT_HEADER_LENGTH_IN_BYTES {
+        let guid = take_first_guid(&mut object_data)?;
+        let object_size =
+            take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let data_length = (object_size as usize)
+            .saturating_sub(OBJECT_HEADER_LENGTH_IN_BYTES)
+            .min(object_data.len());
+
+        objects.push(AsfObject {
+            guid,
+            data: take_first_number_of_bytes(&mut object_data, data_length)?,
+        });
+    }
+
+    Ok(objects)
+}
+
+pub fn get_string_from_utf16_bytes(bytes: &[u8]) -> String {
+    let code_units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+
+    String::from_utf16_lossy(&code_units)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+pub fn format_attribute_value(data_type: u16, value: &[u8]) -> String {
+    match data_type {
+        ATTRIBUTE_TYPE_UNICODE_STRING => get_string_from_utf16_bytes(value),
+        ATTRIBUTE_TYPE_BYTE_ARRAY => format_byte_array_summary(value),
+        ATTRIBUTE_TYPE_BOOL => {
+            if get_unsigned_integer_from_le_bytes(value) == 0 {
+                "False".to_string()
+            } else {
+                "True".to_string()
+            }
+        }
+        ATTRIBUTE_TYPE_GUID if value.len() >= GUID_LENGTH_IN_BYTES => get_guid_from_bytes(value),
+        _ => get_unsigned_integer_from_le_bytes(value).to_string(),
+    }
+}
+
+pub fn format_hundred_nanoseconds_as_time(hundred_nanoseconds: u64) -> String {
+    let total_milliseconds = hundred_nanoseconds / HUNDRED_NANOSECONDS_PER_MILLISECOND;
+    let milliseconds = total_milliseconds % 1000;
+    let total_seconds = total_milliseconds / 1000;
+
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+        milliseconds
+    )
+}
+
+pub fn format_filetime_as_date(filetime: u64) -> String {
+    let seconds =
+        (filetime / HUNDRED_NANOSECONDS_PER_SECOND) as i64 - FILETIME_EPOCH_OFFSET_IN_SECONDS;
+
+    match DateTime::from_timestamp(seconds, 0) {
+        Some(date) => date.to_string(),
+        None => BAD_TIMESTAMP_MESSAGE.to_string(),
+    }
+}
+
+fn get_unsigned_integer_from_le_bytes(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .take(8)
+        .rev()
+        .fold(0, |value, byte| (value << 8) | *byte as u64)
+}
+
+fn format_byte_array_summary(bytes: &[u8]) -> String {
+    let displayed_bytes = &bytes[..bytes.len().min(NUMBER_OF_BYTE_ARRAY_BYTES_TO_DISPLAY)];
+    let ellipsis = if bytes.len() > NUMBER_OF_BYTE_ARRAY_BYTES_TO_DISPLAY {
+        " ..."
+    } else {
+        ""
+    };
+
+    format!(
+        "{} bytes: {}{}",
+        bytes.len(),
+        format_bytes_as_string_of_bytes(displayed_bytes),
+        ellipsis
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_guid_string_from_little_endian_guid_bytes() {
+        let guid_bytes = [
+            0x30, 0x26, 0xB2, 0x75, 0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62,
+            0xCE, 0x6C,
+        ];
+        assert_eq!(get_guid_from_bytes(&guid_bytes), HEADER_OBJECT_GUID);
+    }
+
+    #[test]
+    fn return_correct_child_objects_from_valid_bytes() {
+        let mut object_data = vec![0xAA; GUID_LENGTH_IN_BYTES];
+        object_data.extend(26u64.to_le_bytes());
+        object_data.extend([0x01, 0x02]);
+        object_data.extend(vec![0xBB; GUID_LENGTH_IN_BYTES]);
+        object_data.extend(24u64.to_le_bytes());
+
+        let result = get_child_objects_from_bytes(object_data).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].guid, "AAAAAAAA-AAAA-AAAA-AAAA-AAAAAAAAAAAA");
+        assert_eq!(result[0].data, vec![0x01, 0x02]);
+        assert!(result[1].data.is_empty());
+    }
+
+    #[test]
+    fn return_correct_string_from_null_terminated_utf16_bytes() {
+        let bytes = [b'W', 0x00, b'M', 0x00, b'A', 0x00, 0x00, 0x00];
+        assert_eq!(get_string_from_utf16_bytes(&bytes), "WMA");
+    }
+
+    #[test]
+    fn return_correctly_formatted_attribute_values_for_each_data_type() {
+        assert_eq!(format_attribute_value(0, &[b'a', 0x00]), "a");
+        assert_eq!(format_attribute_value(1, &[0x01, 0xFF]), "2 bytes: 01 ff");
+        assert_eq!(format_attribute_value(2, &[0x01, 0x00, 0x00, 0x00]), "True");
+        assert_eq!(
+            format_attribute_value(3, &[0x10, 0x27, 0x00, 0x00]),
+            "10000"
+        );
+        assert_eq!(format_attribute_value(5, &[0x02, 0x00]), "2");
+    }
+
+    #[test]
+    fn return_correct_time_and_date_from_hundred_nanosecond_values() {
+        assert_eq!(
+            format_hundred_nanoseconds_as_time(36_615_000_000),
+            "01:01:01.500"
+        );
+        assert_eq!(
+            format_filetime_as_date(116_444_736_000_000_000),
+            "1970-01-01 00:00:00 UTC"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::asf_objects::{get_string_from_utf16_bytes, GUID_LENGTH_IN_BYTES};
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/codec_list.tmpl");
+const CODEC_TYPES: [(u16, &str); 3] = [(1, "Video"), (2, "Audio"), (0xFFFF, "Unknown")];
+const UTF16_CHARACTER_LENGTH_IN_BYTES: usize = 2;
+
+#[derive(Debug, PartialEq, Serialize)]
+struct CodecEntry {
+    codec_type: String,
+    name: String,
+    description: String,
+    information_size: u16,
+}
+
+pub fn get_metadata(object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let codecs = get_codec_entries_from_bytes(object_data)?;
+
+    let output_values: Value = upon::value! {
+        codecs: codecs,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_codec_entries_from_bytes(mut object_data: Vec<u8>) -> Result<Vec<CodecEntry>, LocalError> {
+    skip_over_bytes(&mut object_data, GUID_LENGTH_IN_BYTES)?;
+    let number_of_entries =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let mut codecs: Vec<CodecEntry> = vec![];
+
+    for _ in 0..number_of_entries {
+        let codec_type =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let name = take_first_utf16_string_with_character_count(&mut object_data)?;
+        let description = take_first_utf16_string_with_character_count(&mut object_data)?;
+        let information_size =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        skip_over_bytes(&mut object_data, information_size as usize)?;
+
+        codecs.push(CodecEntry {
+            codec_type: get_codec_type_name(codec_type),
+            name,
+            description,
+            information_size,
+        });
+    }
+
+    Ok(codecs)
+}
+
+fn take_first_utf16_string_with_character_count(
+    byte_data: &mut Vec<u8>,
+) -> Result<String, LocalError> {
+    let number_of_characters = take_first_two_bytes_as_unsigned_integer(byte_data, Endian::Little)?;
+    let string_bytes = take_first_number_of_bytes(
+        byte_data,
+        number_of_characters as usize * UTF16_CHARACTER_LENGTH_IN_BYTES,
+    )?;
+
+    Ok(get_string_from_utf16_bytes(&string_bytes))
+}
+
+fn get_codec_type_name(codec_type: u16) -> String {
+    CODEC_TYPES
+        .iter()
+        .find(|(id, _)| *id == codec_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", codec_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_codec_entries_from_valid_bytes() {
+        let mut object_data = vec![0x00; GUID_LENGTH_IN_BYTES];
+        object_data.extend([0x01, 0x00, 0x00, 0x00, 0x02, 0x00]);
+        object_data.extend([0x03, 0x00, b'W', 0x00, b'M', 0x00, b'A', 0x00]);
+        object_data.extend([0x01, 0x00, b'x', 0x00]);
+        object_data.extend([0x02, 0x00, 0x61, 0x01]);
+
+        let correct_result = vec![CodecEntry {
+            codec_type: "Audio".to_string(),
+            name: "WMA".to_string(),
+            description: "x".to_string(),
+            information_size: 2,
+        }];
+        let result = get_codec_entries_from_bytes(object_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+}
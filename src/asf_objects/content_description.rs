@@ -0,0 +1,58 @@
+use crate::asf_objects::get_string_from_utf16_bytes;
+use crate::byte_arrays::{
+    take_first_number_of_bytes, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/content_description.tmpl");
+const NUMBER_OF_DESCRIPTION_FIELDS: usize = 5;
+
+pub fn get_metadata(mut object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut field_lengths: Vec<u16> = vec![];
+    for _ in 0..NUMBER_OF_DESCRIPTION_FIELDS {
+        field_lengths.push(take_first_two_bytes_as_unsigned_integer(
+            &mut object_data,
+            Endian::Little,
+        )?);
+    }
+
+    let mut fields: Vec<String> = vec![];
+    for field_length in field_lengths {
+        let field_bytes = take_first_number_of_bytes(&mut object_data, field_length as usize)?;
+        fields.push(get_string_from_utf16_bytes(&field_bytes));
+    }
+
+    let output_values: Value = upon::value! {
+        title: &fields[0],
+        author: &fields[1],
+        copyright: &fields[2],
+        description: &fields[3],
+        rating: &fields[4],
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_content_description_from_valid_bytes() {
+        let object_data = vec![
+            0x04, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, b'H', 0x00, b'i', 0x00,
+            b'A', 0x00,
+        ];
+        let correct_result_text = "-------------------\nContent Description\n-------------------\nTitle:                 Hi\nAuthor:                A\n";
+        let result = get_metadata(object_data).unwrap();
+        assert_eq!(result.text, correct_result_text);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::asf_objects::{format_attribute_value, get_string_from_utf16_bytes};
+use crate::byte_arrays::{
+    take_first_number_of_bytes, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as Descriptor};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str =
+    include_str!("../templates/asf_objects/extended_content_description.tmpl");
+
+pub fn get_metadata(object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let descriptors = get_descriptors_from_bytes(object_data)?;
+
+    let output_values: Value = upon::value! {
+        descriptors: descriptors,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_descriptors_from_bytes(mut object_data: Vec<u8>) -> Result<Vec<Descriptor>, LocalError> {
+    let number_of_descriptors =
+        take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let mut descriptors: Vec<Descriptor> = vec![];
+
+    for _ in 0..number_of_descriptors {
+        let name_length =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let name_bytes = take_first_number_of_bytes(&mut object_data, name_length as usize)?;
+        let value_type =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let value_length =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let value_bytes = take_first_number_of_bytes(&mut object_data, value_length as usize)?;
+
+        descriptors.push(Descriptor {
+            key: get_string_from_utf16_bytes(&name_bytes),
+            spacer: String::new(),
+            value: format_attribute_value(value_type, &value_bytes),
+        });
+    }
+
+    set_key_value_pair_spacers(&mut descriptors);
+
+    Ok(descriptors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_descriptors_from_valid_bytes() {
+        let object_data = vec![
+            0x02, 0x00, 0x04, 0x00, b'W', 0x00, b'M', 0x00, 0x00, 0x00, 0x04, 0x00, b'a', 0x00,
+            b'b', 0x00, 0x02, 0x00, b'X', 0x00, 0x03, 0x00, 0x04, 0x00, 0x07, 0x00, 0x00, 0x00,
+        ];
+        let correct_result = vec![
+            Descriptor {
+                key: "WM".to_string(),
+                spacer: String::new(),
+                value: "ab".to_string(),
+            },
+            Descriptor {
+                key: "X".to_string(),
+                spacer: " ".to_string(),
+                value: "7".to_string(),
+            },
+        ];
+        let result = get_descriptors_from_bytes(object_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+}
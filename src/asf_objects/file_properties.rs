@@ -0,0 +1,108 @@
+use crate::asf_objects::{
+    format_filetime_as_date, format_hundred_nanoseconds_as_time, take_first_guid,
+};
+use crate::byte_arrays::{
+    take_first_eight_bytes_as_unsigned_integer, take_first_four_bytes_as_unsigned_integer, Endian,
+};
+use crate::formating::format_file_size_as_string;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/file_properties.tmpl");
+const BROADCAST_FLAG_MASK: u32 = 0x01;
+const SEEKABLE_FLAG_MASK: u32 = 0x02;
+const HUNDRED_NANOSECONDS_PER_MILLISECOND: u64 = 10_000;
+
+pub fn get_metadata(mut object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let file_id = take_first_guid(&mut object_data)?;
+    let file_size = take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let creation_date =
+        take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let data_packets_count =
+        take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let play_duration =
+        take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let send_duration =
+        take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let preroll = take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let flags = take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let minimum_data_packet_size =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let maximum_data_packet_size =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let maximum_bitrate =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+
+    let duration =
+        play_duration.saturating_sub(preroll.saturating_mul(HUNDRED_NANOSECONDS_PER_MILLISECOND));
+
+    let output_values: Value = upon::value! {
+        file_id: file_id,
+        file_size: format_file_size_as_string(file_size),
+        creation_date: format_filetime_as_date(creation_date),
+        data_packets_count: data_packets_count.to_string(),
+        duration: format_hundred_nanoseconds_as_time(duration),
+        play_duration: format_hundred_nanoseconds_as_time(play_duration),
+        send_duration: format_hundred_nanoseconds_as_time(send_duration),
+        preroll: preroll.to_string(),
+        is_broadcast: (flags & BROADCAST_FLAG_MASK) != 0,
+        is_seekable: (flags & SEEKABLE_FLAG_MASK) != 0,
+        minimum_data_packet_size: minimum_data_packet_size,
+        maximum_data_packet_size: maximum_data_packet_size,
+        maximum_bitrate: maximum_bitrate as f64 / 1000.0,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_duration_with_preroll_removed_from_valid_bytes() {
+        let mut object_data = vec![0x00; 16];
+        object_data.extend(1024u64.to_le_bytes());
+        object_data.extend(116_444_736_000_000_000u64.to_le_bytes());
+        object_data.extend(4u64.to_le_bytes());
+        object_data.extend(30_000_000u64.to_le_bytes());
+        object_data.extend(10_000_000u64.to_le_bytes());
+        object_data.extend(1000u64.to_le_bytes());
+        object_data.extend(2u32.to_le_bytes());
+        object_data.extend(256u32.to_le_bytes());
+        object_data.extend(256u32.to_le_bytes());
+        object_data.extend(128_000u32.to_le_bytes());
+
+        let result = get_metadata(object_data).unwrap();
+        assert_eq!(result.section, Section::Mandatory);
+        assert!(result.text.contains("Duration:              00:00:02.000"));
+        assert!(result.text.contains("Seekable:              true"));
+        assert!(result.text.contains("Maximum Bitrate:       128 kbit/s"));
+    }
+
+    #[test]
+    fn return_metadata_when_preroll_and_packet_count_exceed_the_signed_integer_range() {
+        let mut object_data = vec![0x00; 16];
+        object_data.extend(1024u64.to_le_bytes());
+        object_data.extend(116_444_736_000_000_000u64.to_le_bytes());
+        object_data.extend(u64::MAX.to_le_bytes());
+        object_data.extend(30_000_000u64.to_le_bytes());
+        object_data.extend(10_000_000u64.to_le_bytes());
+        object_data.extend(u64::MAX.to_le_bytes());
+        object_data.extend(2u32.to_le_bytes());
+        object_data.extend(256u32.to_le_bytes());
+        object_data.extend(256u32.to_le_bytes());
+        object_data.extend(128_000u32.to_le_bytes());
+
+        let result = get_metadata(object_data).unwrap();
+        assert!(result.text.contains("Duration:              00:00:00.000"));
+        assert!(result.text.contains(&u64::MAX.to_string()));
+    }
+}
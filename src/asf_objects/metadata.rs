@@ -0,0 +1,93 @@
+use crate::asf_objects::{format_attribute_value, get_string_from_utf16_bytes};
+use crate::byte_arrays::{
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/metadata.tmpl");
+
+#[derive(Debug, PartialEq, Serialize)]
+struct DescriptionRecord {
+    language_index: u16,
+    stream_number: u16,
+    name: String,
+    value: String,
+}
+
+pub fn get_metadata(
+    object_name: &str,
+    object_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let records = get_description_records_from_bytes(object_data)?;
+
+    let output_values: Value = upon::value! {
+        title: object_name,
+        title_underline: "-".repeat(object_name.len()),
+        records: records,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_description_records_from_bytes(
+    mut object_data: Vec<u8>,
+) -> Result<Vec<DescriptionRecord>, LocalError> {
+    let number_of_records =
+        take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let mut records: Vec<DescriptionRecord> = vec![];
+
+    for _ in 0..number_of_records {
+        let language_index =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let stream_number =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let name_length =
+            take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let data_type = take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let data_length =
+            take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+        let name_bytes = take_first_number_of_bytes(&mut object_data, name_length as usize)?;
+        let data_bytes = take_first_number_of_bytes(&mut object_data, data_length as usize)?;
+
+        records.push(DescriptionRecord {
+            language_index,
+            stream_number,
+            name: get_string_from_utf16_bytes(&name_bytes),
+            value: format_attribute_value(data_type, &data_bytes),
+        });
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_description_records_from_valid_bytes() {
+        let object_data = vec![
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x05, 0x00, 0x02, 0x00, 0x00, 0x00,
+            b'I', 0x00, b'D', 0x00, 0x2A, 0x00,
+        ];
+        let correct_result = vec![DescriptionRecord {
+            language_index: 0,
+            stream_number: 1,
+            name: "ID".to_string(),
+            value: "42".to_string(),
+        }];
+        let result = get_description_records_from_bytes(object_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+}
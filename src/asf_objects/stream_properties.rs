@@ -0,0 +1,185 @@
+use crate::asf_objects::take_first_guid;
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_eight_bytes_as_unsigned_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_number_of_bytes_as_string, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::chunks::fmt::get_format_name_from_format_id;
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/stream_properties.tmpl");
+const AUDIO_MEDIA_GUID: &str = "F8699E40-5B4D-11CF-A8FD-00805F5C442B";
+const VIDEO_MEDIA_GUID: &str = "BC19EFC0-5B4D-11CF-A8FD-00805F5C442B";
+const STREAM_TYPES: [(&str, &str); 7] = [
+    (AUDIO_MEDIA_GUID, "Audio"),
+    (VIDEO_MEDIA_GUID, "Video"),
+    ("59DACFC0-59E6-11D0-A3AC-00A0C90348F6", "Command"),
+    ("B61BE100-5B4E-11CF-A8FD-00805F5C442B", "JFIF"),
+    ("35907DE0-E415-11CF-A917-00805F5C442B", "Degradable JPEG"),
+    ("91BD222C-F21C-497A-8B6D-5AA86BFC0185", "File Transfer"),
+    ("3AFB65E2-47EF-40F2-AC2C-70A90D71D343", "Binary"),
+];
+const ERROR_CORRECTION_TYPES: [(&str, &str); 2] = [
+    ("20FB5700-5B55-11CF-A8FD-00805F5C442B", "None"),
+    ("BFC3CD50-618F-11CF-8BB2-00AA00B4E220", "Audio Spread"),
+];
+const RESERVED_FIELD_LENGTH_IN_BYTES: usize = 4;
+const STREAM_NUMBER_MASK: u16 = 0x7F;
+const ENCRYPTED_FLAG_MASK: u16 = 0x8000;
+const VIDEO_FLAGS_AND_FORMAT_SIZE_LENGTH_IN_BYTES: usize = 3;
+const BITMAP_HEADER_SIZE_AND_DIMENSIONS_LENGTH_IN_BYTES: usize = 14;
+const HUNDRED_NANOSECONDS_PER_MILLISECOND: u64 = 10_000;
+
+#[derive(Debug, Default, Serialize)]
+struct AudioFormat {
+    format_code: String,
+    number_of_channels: u16,
+    samples_per_second: f64,
+    average_data_rate: f64,
+    block_alignment: u16,
+    bits_per_sample: u16,
+    codec_specific_data_size: u16,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct VideoFormat {
+    width: u32,
+    height: u32,
+    bits_per_pixel: u16,
+    compression_id: String,
+}
+
+pub fn get_metadata(mut object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let stream_type_guid = take_first_guid(&mut object_data)?;
+    let error_correction_guid = take_first_guid(&mut object_data)?;
+    let time_offset = take_first_eight_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let type_specific_data_length =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let error_correction_data_length =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let flags = take_first_two_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    skip_over_bytes(&mut object_data, RESERVED_FIELD_LENGTH_IN_BYTES)?;
+    let type_specific_data =
+        take_first_number_of_bytes(&mut object_data, type_specific_data_length as usize)?;
+
+    let mut audio_format: Option<AudioFormat> = None;
+    let mut video_format: Option<VideoFormat> = None;
+
+    match stream_type_guid.as_str() {
+        AUDIO_MEDIA_GUID => audio_format = Some(get_audio_format_from_bytes(type_specific_data)?),
+        VIDEO_MEDIA_GUID => video_format = Some(get_video_format_from_bytes(type_specific_data)?),
+        _ => {}
+    }
+
+    let output_values: Value = upon::value! {
+        stream_number: flags & STREAM_NUMBER_MASK,
+        stream_type: get_name_from_guid(&STREAM_TYPES, &stream_type_guid),
+        error_correction: get_name_from_guid(&ERROR_CORRECTION_TYPES, &error_correction_guid),
+        error_correction_data_length: error_correction_data_length,
+        time_offset: time_offset / HUNDRED_NANOSECONDS_PER_MILLISECOND,
+        is_encrypted: (flags & ENCRYPTED_FLAG_MASK) != 0,
+        has_audio_format: audio_format.is_some(),
+        audio: audio_format.unwrap_or_default(),
+        has_video_format: video_format.is_some(),
+        video: video_format.unwrap_or_default(),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_audio_format_from_bytes(mut format_data: Vec<u8>) -> Result<AudioFormat, LocalError> {
+    let format_id = take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let number_of_channels =
+        take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let samples_per_second =
+        take_first_four_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let average_bytes_per_second =
+        take_first_four_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let block_alignment =
+        take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let bits_per_sample =
+        take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let codec_specific_data_size = if format_data.len() >= 2 {
+        take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?
+    } else {
+        0
+    };
+
+    Ok(AudioFormat {
+        format_code: get_format_name_from_format_id(format_id),
+        number_of_channels,
+        samples_per_second: samples_per_second as f64 / 1000.0,
+        average_data_rate: average_bytes_per_second as f64 / 1000.0,
+        block_alignment,
+        bits_per_sample,
+        codec_specific_data_size,
+    })
+}
+
+fn get_video_format_from_bytes(mut format_data: Vec<u8>) -> Result<VideoFormat, LocalError> {
+    let width = take_first_four_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let height = take_first_four_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    skip_over_bytes(
+        &mut format_data,
+        VIDEO_FLAGS_AND_FORMAT_SIZE_LENGTH_IN_BYTES,
+    )?;
+    skip_over_bytes(
+        &mut format_data,
+        BITMAP_HEADER_SIZE_AND_DIMENSIONS_LENGTH_IN_BYTES,
+    )?;
+    let bits_per_pixel =
+        take_first_two_bytes_as_unsigned_integer(&mut format_data, Endian::Little)?;
+    let compression_id = take_first_number_of_bytes_as_string(&mut format_data, 4)?;
+
+    Ok(VideoFormat {
+        width,
+        height,
+        bits_per_pixel,
+        compression_id,
+    })
+}
+
+fn get_name_from_guid(names: &[(&str, &str)], guid: &str) -> String {
+    names
+        .iter()
+        .find(|(name_guid, _)| *name_guid == guid)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(guid.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_audio_format_from_valid_waveformatex_bytes() {
+        let mut format_data = vec![0x61, 0x01, 0x02, 0x00];
+        format_data.extend(44100u32.to_le_bytes());
+        format_data.extend(16000u32.to_le_bytes());
+        format_data.extend([0xE7, 0x05, 0x10, 0x00, 0x0A, 0x00]);
+
+        let result = get_audio_format_from_bytes(format_data).unwrap();
+        assert_eq!(result.format_code, "Windows Media Audio v2");
+        assert_eq!(result.number_of_channels, 2);
+        assert_eq!(result.samples_per_second, 44.1);
+        assert_eq!(result.average_data_rate, 16.0);
+        assert_eq!(result.codec_specific_data_size, 10);
+    }
+
+    #[test]
+    fn return_guid_when_stream_type_name_is_unknown() {
+        let guid = "00000000-0000-0000-0000-000000000000";
+        assert_eq!(get_name_from_guid(&STREAM_TYPES, AUDIO_MEDIA_GUID), "Audio");
+        assert_eq!(get_name_from_guid(&STREAM_TYPES, guid), guid);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/asf_objects/unknown.tmpl");
+
+pub fn get_metadata(guid: &str, object_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        guid: guid,
+        object_size: object_data.len(),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Unsupported,
+        text: formated_output,
+    })
+}
@@ -7,7 +7,9 @@ mod seek_table;
 mod stream_info;
 mod vorbis_comment;
 
-use crate::byte_arrays::{take_first_four_bytes_as_unsigned_integer, Endian};
+use crate::byte_arrays::{
+    take_first_byte, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
 use crate::fileio::{read_byte_from_file, read_bytes_from_file, skip_over_bytes_in_file};
 use crate::output::OutputEntry;
 use std::error::Error;
@@ -51,6 +53,30 @@ pub fn get_metadata_from_blocks(
     Ok(output)
 }
 
+pub fn get_metadata_from_block_bytes(
+    blocks: Vec<Vec<u8>>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+
+    for block_bytes in blocks {
+        let metadata_block = get_metadata_block_from_bytes(block_bytes)?;
+
+        if !mandatory_sections_only || metadata_block.header_type == STREAM_INFO_BLOCK_ID {
+            output.push(get_block_metadata(
+                metadata_block.header_type,
+                metadata_block.data,
+            )?);
+        }
+
+        if metadata_block.is_last_block {
+            break;
+        }
+    }
+
+    Ok(output)
+}
+
 pub fn get_block_metadata(
     block_type: u32,
     block_data: Vec<u8>,
@@ -88,6 +114,28 @@ fn read_metadata_block_from_file(flac_file: &mut File) -> Result<MetadataBlock,
     Ok(metadata)
 }
 
+fn get_metadata_block_from_bytes(
+    mut block_bytes: Vec<u8>,
+) -> Result<MetadataBlock, Box<dyn Error>> {
+    let header_byte = take_first_byte(&mut block_bytes)?;
+    let mut block_data_length_bytes =
+        take_first_number_of_bytes(&mut block_bytes, BLOCK_LENGTH_FIELD_IN_BYTES)?;
+
+    let header_type = get_header_type_from_header_byte(header_byte);
+    let is_last_block: bool = (header_byte >> 7) == 1;
+    let block_data_length = get_block_data_length_from_bytes(&mut block_data_length_bytes)?;
+
+    let data = take_first_number_of_bytes(&mut block_bytes, block_data_length as usize)?;
+
+    let metadata = MetadataBlock {
+        header_type,
+        is_last_block,
+        data,
+    };
+
+    Ok(metadata)
+}
+
 fn get_header_type_from_header_byte(header_byte: u8) -> u32 {
     let mut header_type = header_byte as u32;
 
@@ -124,6 +172,15 @@ mod tests {
         assert_eq!(header_type, correct_result);
     }
 
+    #[test]
+    fn return_correct_metadata_block_from_valid_block_bytes() {
+        let test_bytes: Vec<u8> = vec![0x84, 0x00, 0x00, 0x02, 0x0A, 0x0B];
+        let result = get_metadata_block_from_bytes(test_bytes).unwrap();
+        assert_eq!(result.header_type, 4);
+        assert!(result.is_last_block);
+        assert_eq!(result.data, vec![0x0A, 0x0B]);
+    }
+
     #[test]
     fn return_correct_u32_block_data_length_from_3_bytes_vector() {
         let mut test_data: Vec<u8> = vec![0x01, 0x01, 0x01];
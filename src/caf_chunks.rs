@@ -1,5 +1,5 @@
 mod chan;
-mod desc;
+pub mod desc;
 mod info;
 mod mark;
 mod midi;
@@ -8,12 +8,8 @@ mod regn;
 mod strg;
 mod uuid;
 
-use crate::byte_arrays::Endian;
 use crate::chunks::{extra, id3, skipped, text};
-use crate::fileio::{
-    read_bytes_from_file, read_chunk_id_from_file, read_chunk_size_from_file,
-    skip_over_bytes_in_file,
-};
+use crate::fileio::{read_bytes_from_file, read_chunk_id_from_file, skip_over_bytes_in_file};
 use crate::output::OutputEntry;
 use std::error::Error;
 use std::fs::File;
@@ -45,16 +41,12 @@ pub const MANDATORY_CHUNKS: [&str; 3] = [
     PACKET_DESCRIPTION_CHUNK_ID,
     MAGIC_COOKIE_CHUNK_ID,
 ];
-pub const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 3] = [
-    AUDIO_DATA_CHUNK_ID,
-    PACKET_DESCRIPTION_CHUNK_ID,
-    ID3_CHUNK_ID,
-];
+pub const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 2] =
+    [AUDIO_DATA_CHUNK_ID, PACKET_DESCRIPTION_CHUNK_ID];
 pub const ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE: &str = "failed to fill whole buffer";
 
 pub fn get_metadata_from_caf_chunks(
     input_file: &mut File,
-    file_path: &str,
     mandatory_sections_only: bool,
 ) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
     let mut output: Vec<OutputEntry> = vec![];
@@ -66,11 +58,7 @@ pub fn get_metadata_from_caf_chunks(
             break;
         }
 
-        let chunk_size = if chunk_id == ID3_CHUNK_ID {
-            read_chunk_size_from_file(input_file, Endian::Little)?
-        } else {
-            read_caf_chunk_size_from_file(input_file)?
-        };
+        let chunk_size = read_caf_chunk_size_from_file(input_file)?;
 
         if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
             skip_over_bytes_in_file(input_file, chunk_size)?;
@@ -79,7 +67,7 @@ pub fn get_metadata_from_caf_chunks(
 
         let chunk_data =
             get_caf_chunk_data_bytes_from_file(input_file, chunk_id.clone(), chunk_size)?;
-        output.push(get_caf_chunk_metadata(chunk_id, file_path, chunk_data)?);
+        output.push(get_caf_chunk_metadata(chunk_id, chunk_data)?);
     }
 
     Ok(output)
@@ -112,7 +100,6 @@ fn get_caf_chunk_data_bytes_from_file(
 
 fn get_caf_chunk_metadata(
     chunk_id: String,
-    file_path: &str,
     chunk_data: Vec<u8>,
 ) -> Result<OutputEntry, Box<dyn Error>> {
     let result = match chunk_id.as_str() {
@@ -127,7 +114,7 @@ fn get_caf_chunk_metadata(
         PACKET_DESCRIPTION_CHUNK_ID => skipped::get_metadata(chunk_id)?,
         MIDI_CHUNK_ID => midi::get_metadata(chunk_data)?,
         MAGIC_COOKIE_CHUNK_ID => skipped::get_metadata(chunk_id)?,
-        ID3_CHUNK_ID => id3::get_metadata(file_path)?,
+        ID3_CHUNK_ID => id3::get_metadata_from_chunk(chunk_id, chunk_data)?,
         REGION_CHUNK_ID => regn::get_metadata(chunk_data)?,
         MARKER_CHUNK_ID => mark::get_metadata(chunk_data)?,
         _ => extra::get_metadata(chunk_id, chunk_data)?,
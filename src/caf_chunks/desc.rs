@@ -33,7 +33,7 @@ const FORMAT_ID_LONG_FORM: [(&str, &str); 12] = [
 ];
 const UNKNOW_FORMAT_ID_MESSAGE: &str = "Unknown format: ";
 
-const MPEG_4_AAC_OBJECT_TYPES: [&str; 46] = [
+pub const MPEG_4_AAC_OBJECT_TYPES: [&str; 46] = [
     "Null",
     "AAC Main",
     "AAC LC (Low Complexity)",
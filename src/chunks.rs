@@ -4,12 +4,13 @@ mod cart;
 pub mod comm;
 pub mod comt;
 mod cue;
+mod data;
 pub mod extra;
 mod fact;
-mod fmt;
+pub mod fmt;
 pub mod fver;
 pub mod id3;
-mod list;
+pub mod list;
 pub mod mark;
 mod resu;
 pub mod skipped;
@@ -18,10 +19,13 @@ mod sndm;
 pub mod text;
 mod umid;
 
-use crate::byte_arrays::Endian;
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_number_of_bytes_as_string, Endian,
+};
 use crate::fileio::{
     read_bytes_from_file, read_chunk_id_from_file, read_chunk_size_from_file,
-    skip_over_bytes_in_file,
+    read_up_to_number_of_bytes_from_file, skip_over_bytes_in_file,
 };
 use crate::output::OutputEntry;
 use std::error::Error;
@@ -41,6 +45,7 @@ const DISP_CHUNK_ID: &str = "disp";
 const FACT_CHUNK_ID: &str = "fact";
 const FMT_CHUNK_ID: &str = "fmt ";
 pub const ID3_CHUNK_ID: &str = "id3 ";
+const ID32_CHUNK_ID: &str = "id32";
 const IXML_CHUNK_ID: &str = "ixml";
 const IXML_TEMPLATE_TITLE: &str = "iXML";
 const JUNK_CHUNK_ID: &str = "junk";
@@ -76,7 +81,7 @@ pub const AUDIO_SAMPLES_CHUNK_ID: &str = "ssnd";
 pub const NAME_CHUNK_ID: &str = "name";
 const NAME_TEMPLATE_TITLE: &str = "Name";
 pub const MANDATORY_CHUNKS: [&str; 3] = [FMT_CHUNK_ID, FACT_CHUNK_ID, COMMON_CHUNK_ID];
-pub const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 10] = [
+pub const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 9] = [
     DATA_CHUNK_ID,
     AUDIO_SAMPLES_CHUNK_ID,
     CHAN_CHUNK_ID,
@@ -86,16 +91,15 @@ pub const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 10] = [
     PRO_TOOLS_REGN_CHUNK_ID,
     DISP_CHUNK_ID,
     LOGIC_PRO_CHUNK_ID,
-    ID3_CHUNK_ID,
 ];
 
 pub fn get_metadata_from_chunks(
     input_file: &mut File,
-    file_path: &str,
     mandatory_sections_only: bool,
     endianness: Endian,
 ) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
     let mut output: Vec<OutputEntry> = vec![];
+    let mut format_id: Option<u16> = None;
 
     loop {
         let chunk_id: String = read_chunk_id_from_file(input_file)?;
@@ -104,7 +108,7 @@ pub fn get_metadata_from_chunks(
             break;
         }
 
-        let chunk_size = get_chunk_size_from_file(input_file, &endianness, &chunk_id)?;
+        let chunk_size = read_chunk_size_from_file(input_file, endianness.to_owned())?;
 
         if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
             skip_over_bytes_in_file(input_file, chunk_size)?;
@@ -112,22 +116,53 @@ pub fn get_metadata_from_chunks(
         }
 
         let chunk_data = get_chunk_data_bytes_from_file(input_file, &chunk_id, chunk_size)?;
-        output.push(get_chunk_metadata(chunk_id, chunk_data, file_path)?);
+        output.push(get_chunk_metadata_with_format_id(
+            chunk_id,
+            chunk_data,
+            &mut format_id,
+        )?);
     }
 
     Ok(output)
 }
 
-fn get_chunk_size_from_file(
-    input_file: &mut File,
-    endianness: &Endian,
-    chunk_id: &str,
-) -> Result<usize, Box<dyn Error>> {
-    let chunk_size = match chunk_id {
-        ID3_CHUNK_ID => read_chunk_size_from_file(input_file, Endian::Little)?,
-        _ => read_chunk_size_from_file(input_file, endianness.to_owned())?,
-    };
-    Ok(chunk_size)
+pub fn get_metadata_from_chunk_bytes(
+    mut chunk_bytes: Vec<u8>,
+    mandatory_sections_only: bool,
+    endianness: Endian,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+    let mut format_id: Option<u16> = None;
+
+    while chunk_bytes.len() >= CHUNK_ID_FIELD_LENGTH_IN_BYTES + CHUNK_SIZE_FIELD_LENGTH_IN_BYTES {
+        let chunk_id =
+            take_first_number_of_bytes_as_string(&mut chunk_bytes, CHUNK_ID_FIELD_LENGTH_IN_BYTES)?
+                .to_lowercase();
+        let chunk_size =
+            take_first_four_bytes_as_unsigned_integer(&mut chunk_bytes, endianness.to_owned())?
+                as usize;
+
+        let data_size = chunk_size.min(chunk_bytes.len());
+        let mut chunk_data = take_first_number_of_bytes(&mut chunk_bytes, data_size)?;
+        let padding_size = (data_size % 2).min(chunk_bytes.len());
+        skip_over_bytes(&mut chunk_bytes, padding_size)?;
+
+        if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
+            continue;
+        }
+
+        if CHUNKS_NOT_TO_EXTRACT_DATA_FROM.contains(&chunk_id.as_str()) {
+            chunk_data = Vec::new();
+        }
+
+        output.push(get_chunk_metadata_with_format_id(
+            chunk_id,
+            chunk_data,
+            &mut format_id,
+        )?);
+    }
+
+    Ok(output)
 }
 
 fn get_chunk_data_bytes_from_file(
@@ -135,6 +170,13 @@ fn get_chunk_data_bytes_from_file(
     chunk_id: &str,
     chunk_size: usize,
 ) -> Result<Vec<u8>, Box<dyn Error>> {
+    if chunk_id == DATA_CHUNK_ID {
+        let data_peek_length = chunk_size.min(data::DATA_PEEK_LENGTH_IN_BYTES);
+        let chunk_data = read_up_to_number_of_bytes_from_file(input_file, data_peek_length)?;
+        skip_over_bytes_in_file(input_file, chunk_size - chunk_data.len())?;
+        return Ok(chunk_data);
+    }
+
     Ok(if CHUNKS_NOT_TO_EXTRACT_DATA_FROM.contains(&chunk_id) {
         skip_over_bytes_in_file(input_file, chunk_size)?;
         Vec::new()
@@ -143,10 +185,23 @@ fn get_chunk_data_bytes_from_file(
     })
 }
 
+fn get_chunk_metadata_with_format_id(
+    chunk_id: String,
+    chunk_data: Vec<u8>,
+    format_id: &mut Option<u16>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    match chunk_id.as_str() {
+        FMT_CHUNK_ID => *format_id = fmt::get_format_id_from_bytes(&chunk_data),
+        DATA_CHUNK_ID => return data::get_metadata(chunk_id, chunk_data, *format_id),
+        _ => {}
+    }
+
+    get_chunk_metadata(chunk_id, chunk_data)
+}
+
 pub fn get_chunk_metadata(
     chunk_id: String,
     chunk_data: Vec<u8>,
-    file_path: &str,
 ) -> Result<OutputEntry, Box<dyn Error>> {
     let result = match chunk_id.as_str() {
         FMT_CHUNK_ID => fmt::get_metadata(chunk_data)?,
@@ -162,7 +217,7 @@ pub fn get_chunk_metadata(
         JUNK_CHUNK_ID => text::get_metadata(JUNK_TEMPLATE_TITLE, chunk_data)?,
         PAD_CHUNK_ID => text::get_metadata(PAD_TEMPLATE_TITLE, chunk_data)?,
         LIST_CHUNK_ID => list::get_metadata(chunk_data)?,
-        ID3_CHUNK_ID => id3::get_metadata(file_path)?,
+        ID3_CHUNK_ID | ID32_CHUNK_ID => id3::get_metadata_from_chunk(chunk_id, chunk_data)?,
         IXML_CHUNK_ID => text::get_metadata(IXML_TEMPLATE_TITLE, chunk_data)?,
         XMP_CHUNK_ID => text::get_metadata(XMP_TEMPLATE_TITLE, chunk_data)?,
         AXML_CHUNK_ID => text::get_metadata(AXML_TEMPLATE_TITLE, chunk_data)?,
@@ -175,7 +230,7 @@ pub fn get_chunk_metadata(
         NAME_CHUNK_ID => text::get_metadata(NAME_TEMPLATE_TITLE, chunk_data)?,
         COPYRIGHT_CHUNK_ID => text::get_metadata(COPYRIGHT_TEMPLATE_TITLE, chunk_data)?,
         PRO_TOOLS_UMID_CHUNK_ID => umid::get_metadata(chunk_data)?,
-        DATA_CHUNK_ID => skipped::get_metadata(chunk_id)?,
+        DATA_CHUNK_ID => data::get_metadata(chunk_id, chunk_data, None)?,
         AUDIO_SAMPLES_CHUNK_ID => skipped::get_metadata(chunk_id)?,
         CHAN_CHUNK_ID => skipped::get_metadata(chunk_id)?,
         PRO_TOOLS_ELM1_CHUNK_ID => skipped::get_metadata(chunk_id)?,
@@ -184,8 +239,32 @@ pub fn get_chunk_metadata(
         PRO_TOOLS_REGN_CHUNK_ID => skipped::get_metadata(chunk_id)?,
         DISP_CHUNK_ID => skipped::get_metadata(chunk_id)?,
         LOGIC_PRO_CHUNK_ID => skipped::get_metadata(chunk_id)?,
+        _ if id3::is_id3_tag(&chunk_data) => id3::get_metadata_from_chunk(chunk_id, chunk_data)?,
         _ => extra::get_metadata(chunk_id, chunk_data)?,
     };
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Section;
+
+    #[test]
+    fn return_correct_metadata_from_chunk_bytes_with_a_data_chunk_header_only() {
+        let mut chunk_bytes: Vec<u8> = b"fmt ".to_vec();
+        chunk_bytes.extend(16u32.to_le_bytes());
+        chunk_bytes.extend([0x01, 0x00, 0x02, 0x00]);
+        chunk_bytes.extend(44100u32.to_le_bytes());
+        chunk_bytes.extend(176400u32.to_le_bytes());
+        chunk_bytes.extend([0x04, 0x00, 0x10, 0x00]);
+        chunk_bytes.extend(b"data");
+        chunk_bytes.extend(1000u32.to_le_bytes());
+
+        let result = get_metadata_from_chunk_bytes(chunk_bytes, false, Endian::Little).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].section, Section::Mandatory);
+        assert_eq!(result[1].section, Section::Skipped);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::ac3_frames::{get_metadata as get_frame_metadata, is_ac3_sync, BSI_LENGTH_IN_BYTES};
+use crate::chunks::fmt::{DOLBY_AC3_FORMAT_ID, DOLBY_AC3_SPDIF_FORMAT_ID, PCM_FORMAT_ID};
+use crate::chunks::skipped;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/chunks/data.tmpl");
+pub const DATA_PEEK_LENGTH_IN_BYTES: usize = 65536;
+const IEC_61937_PREAMBLE: &[u8] = &[0x72, 0xF8, 0x1F, 0x4E];
+const IEC_61937_BURST_HEADER_LENGTH_IN_BYTES: usize = 8;
+const IEC_61937_DATA_TYPE_MASK: u16 = 0x1F;
+const RAW_AC3_ENCAPSULATION: &str = "Raw AC-3 Frames";
+const IEC_61937_ENCAPSULATION: &str = "IEC 61937 Data Bursts (S/PDIF)";
+const NULL_DATA_TYPE: u16 = 0;
+const AC3_DATA_TYPE: u16 = 1;
+const PAUSE_DATA_TYPE: u16 = 3;
+const EAC3_DATA_TYPE: u16 = 21;
+const MAT_DATA_TYPE: u16 = 22;
+
+const IEC_61937_DATA_TYPES: [(u16, &str); 21] = [
+    (1, "AC-3"),
+    (3, "Pause"),
+    (4, "MPEG-1 Layer 1"),
+    (5, "MPEG-1 Layer 2/3 or MPEG-2 Without Extension"),
+    (6, "MPEG-2 With Extension"),
+    (7, "MPEG-2 AAC"),
+    (8, "MPEG-2 Layer 1 Low Sampling Frequency"),
+    (9, "MPEG-2 Layer 2 Low Sampling Frequency"),
+    (10, "MPEG-2 Layer 3 Low Sampling Frequency"),
+    (11, "DTS Type I"),
+    (12, "DTS Type II"),
+    (13, "DTS Type III"),
+    (14, "ATRAC"),
+    (15, "ATRAC 2/3"),
+    (16, "ATRAC-X"),
+    (17, "DTS Type IV"),
+    (18, "WMA Professional"),
+    (19, "MPEG-2 AAC Low Sampling Frequency"),
+    (20, "MPEG-4 AAC"),
+    (21, "E-AC-3"),
+    (22, "MAT (Dolby TrueHD)"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct DataBurst {
+    offset: usize,
+    data_type: u16,
+    payload_length: usize,
+    payload: Vec<u8>,
+}
+
+pub fn get_metadata(
+    chunk_id: String,
+    chunk_data: Vec<u8>,
+    format_id: Option<u16>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    match format_id {
+        Some(DOLBY_AC3_FORMAT_ID) if is_ac3_sync(&chunk_data) => {
+            match get_frame_metadata(&chunk_data) {
+                Ok(frame_metadata) => {
+                    get_data_output(RAW_AC3_ENCAPSULATION, None, frame_metadata.text)
+                }
+                Err(_) => skipped::get_metadata(chunk_id),
+            }
+        }
+        Some(DOLBY_AC3_SPDIF_FORMAT_ID | PCM_FORMAT_ID) => {
+            let Some(data_burst) = get_first_data_burst_from_bytes(&chunk_data) else {
+                return skipped::get_metadata(chunk_id);
+            };
+
+            let frame_details = match data_burst.data_type {
+                AC3_DATA_TYPE | EAC3_DATA_TYPE => get_frame_metadata(&data_burst.payload)
+                    .map(|frame_metadata| frame_metadata.text)
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+
+            get_data_output(IEC_61937_ENCAPSULATION, Some(data_burst), frame_details)
+        }
+        _ => skipped::get_metadata(chunk_id),
+    }
+}
+
+fn get_data_output(
+    encapsulation: &str,
+    data_burst: Option<DataBurst>,
+    frame_details: String,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let is_burst = data_burst.is_some();
+    let data_burst = data_burst.unwrap_or_default();
+
+    let output_values: Value = upon::value! {
+        encapsulation: encapsulation,
+        is_burst: is_burst,
+        data_type: get_data_type_name(data_burst.data_type),
+        burst_offset: data_burst.offset,
+        payload_length: data_burst.payload_length,
+        frame_details: frame_details.trim_end(),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_first_data_burst_from_bytes(chunk_data: &[u8]) -> Option<DataBurst> {
+    let mut offset = 0;
+
+    while offset + IEC_61937_BURST_HEADER_LENGTH_IN_BYTES <= chunk_data.len() {
+        if !chunk_data[offset..].starts_with(IEC_61937_PREAMBLE) {
+            offset += 2;
+            continue;
+        }
+
+        let burst_info = u16::from_le_bytes([chunk_data[offset + 4], chunk_data[offset + 5]]);
+        let length_code = u16::from_le_bytes([chunk_data[offset + 6], chunk_data[offset + 7]]);
+        let data_type = burst_info & IEC_61937_DATA_TYPE_MASK;
+
+        if data_type == NULL_DATA_TYPE || data_type == PAUSE_DATA_TYPE {
+            offset += IEC_61937_BURST_HEADER_LENGTH_IN_BYTES;
+            continue;
+        }
+
+        let payload_length = match data_type {
+            EAC3_DATA_TYPE | MAT_DATA_TYPE => length_code as usize,
+            _ => length_code as usize / 8,
+        };
+
+        let payload_start = offset + IEC_61937_BURST_HEADER_LENGTH_IN_BYTES;
+        let payload_end = chunk_data
+            .len()
+            .min(payload_start + payload_length.min(BSI_LENGTH_IN_BYTES));
+        let payload = chunk_data[payload_start..payload_end]
+            .chunks(2)
+            .flat_map(|word| word.iter().rev().copied())
+            .collect();
+
+        return Some(DataBurst {
+            offset,
+            data_type,
+            payload_length,
+            payload,
+        });
+    }
+
+    None
+}
+
+fn get_data_type_name(data_type: u16) -> String {
+    match IEC_61937_DATA_TYPES
+        .iter()
+        .find(|(type_id, _)| *type_id == data_type)
+    {
+        Some((_, name)) => format!("{} ({})", name, data_type),
+        None => format!("Unknown ({})", data_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_data_burst_from_pcm_bytes() {
+        let mut chunk_data: Vec<u8> = vec![0x00; 16];
+        chunk_data.extend(IEC_61937_PREAMBLE);
+        chunk_data.extend([0x01, 0x00, 0x00, 0x38]);
+        chunk_data.extend([0x77, 0x0B, 0x34, 0x12]);
+
+        let result = get_first_data_burst_from_bytes(&chunk_data).unwrap();
+        assert_eq!(result.offset, 16);
+        assert_eq!(result.data_type, AC3_DATA_TYPE);
+        assert_eq!(result.payload_length, 1792);
+        assert_eq!(result.payload, vec![0x0B, 0x77, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn return_skipped_output_when_no_bitstream_is_found() {
+        let result = get_metadata("data".to_string(), vec![0x00; 64], Some(PCM_FORMAT_ID)).unwrap();
+        assert_eq!(result.section, Section::Skipped);
+    }
+
+    #[test]
+    fn return_skipped_output_when_format_does_not_carry_a_bitstream() {
+        let mut chunk_data: Vec<u8> = IEC_61937_PREAMBLE.to_vec();
+        chunk_data.extend([0x01, 0x00, 0x00, 0x38]);
+        chunk_data.extend([0x77, 0x0B, 0x34, 0x12]);
+
+        let result = get_metadata("data".to_string(), chunk_data.clone(), Some(3)).unwrap();
+        assert_eq!(result.section, Section::Skipped);
+
+        let result = get_metadata("data".to_string(), chunk_data, None).unwrap();
+        assert_eq!(result.section, Section::Skipped);
+    }
+}
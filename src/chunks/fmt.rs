@@ -9,7 +9,7 @@ use upon::Value;
 
 const TEMPLATE_CONTENT: &str = include_str!("../templates/chunks/fmt.tmpl");
 const FORMAT_CHUNK_SIZE_IF_NO_EXTENSION: usize = 16;
-const PCM_FORMAT_ID: u16 = 1;
+pub const PCM_FORMAT_ID: u16 = 1;
 const PCM_FORMAT_NAME: &str = "PCM";
 const IEEE_FORMAT_FLOAT_ID: u16 = 3;
 const IEEE_FORMAT_FLOAT_NAME: &str = "IEEE float";
@@ -17,7 +17,29 @@ const ALAW_FORMAT_ID: u16 = 6;
 const ALAW_FORMAT_NAME: &str = "8-bit ITU-T G.711 A-law";
 const MULAW_FORMAT_ID: u16 = 7;
 const MULAW_FORMAT_NAME: &str = "8-bit ITU-T G.711 µ-law";
-const EXTENSIBLE_FORMAT_ID: u16 = 65279;
+const MICROSOFT_ADPCM_FORMAT_ID: u16 = 2;
+const MICROSOFT_ADPCM_FORMAT_NAME: &str = "Microsoft ADPCM";
+const WMA_VOICE_FORMAT_ID: u16 = 10;
+const WMA_VOICE_FORMAT_NAME: &str = "Windows Media Audio Voice";
+const IMA_ADPCM_FORMAT_ID: u16 = 17;
+const IMA_ADPCM_FORMAT_NAME: &str = "IMA ADPCM";
+const MPEG_FORMAT_ID: u16 = 80;
+const MPEG_FORMAT_NAME: &str = "MPEG Layer 1/2";
+const MPEG_LAYER_3_FORMAT_ID: u16 = 85;
+const MPEG_LAYER_3_FORMAT_NAME: &str = "MPEG Layer 3";
+const WMA_V1_FORMAT_ID: u16 = 352;
+const WMA_V1_FORMAT_NAME: &str = "Windows Media Audio v1";
+const WMA_V2_FORMAT_ID: u16 = 353;
+const WMA_V2_FORMAT_NAME: &str = "Windows Media Audio v2";
+const WMA_PRO_FORMAT_ID: u16 = 354;
+const WMA_PRO_FORMAT_NAME: &str = "Windows Media Audio Professional";
+const WMA_LOSSLESS_FORMAT_ID: u16 = 355;
+const WMA_LOSSLESS_FORMAT_NAME: &str = "Windows Media Audio Lossless";
+pub const DOLBY_AC3_SPDIF_FORMAT_ID: u16 = 146;
+const DOLBY_AC3_SPDIF_FORMAT_NAME: &str = "Dolby AC-3 over S/PDIF (IEC 61937)";
+pub const DOLBY_AC3_FORMAT_ID: u16 = 8192;
+const DOLBY_AC3_FORMAT_NAME: &str = "Dolby AC-3";
+const EXTENSIBLE_FORMAT_ID: u16 = 65534;
 const EXTENSIBLE_FORMAT_NAME: &str = "Determined by SubFormat";
 const UNKOWN_FORMAT: &str = "Unknown Format ID: ";
 const GUID_LENGTH_IN_BYTES: usize = 16;
@@ -99,12 +121,30 @@ pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Erro
     })
 }
 
-fn get_format_name_from_format_id(format_id: u16) -> String {
+pub fn get_format_id_from_bytes(chunk_data: &[u8]) -> Option<u16> {
+    Some(u16::from_le_bytes([
+        *chunk_data.first()?,
+        *chunk_data.get(1)?,
+    ]))
+}
+
+pub fn get_format_name_from_format_id(format_id: u16) -> String {
     match format_id {
         PCM_FORMAT_ID => PCM_FORMAT_NAME.to_string(),
         IEEE_FORMAT_FLOAT_ID => IEEE_FORMAT_FLOAT_NAME.to_string(),
         ALAW_FORMAT_ID => ALAW_FORMAT_NAME.to_string(),
         MULAW_FORMAT_ID => MULAW_FORMAT_NAME.to_string(),
+        MICROSOFT_ADPCM_FORMAT_ID => MICROSOFT_ADPCM_FORMAT_NAME.to_string(),
+        WMA_VOICE_FORMAT_ID => WMA_VOICE_FORMAT_NAME.to_string(),
+        IMA_ADPCM_FORMAT_ID => IMA_ADPCM_FORMAT_NAME.to_string(),
+        MPEG_FORMAT_ID => MPEG_FORMAT_NAME.to_string(),
+        MPEG_LAYER_3_FORMAT_ID => MPEG_LAYER_3_FORMAT_NAME.to_string(),
+        WMA_V1_FORMAT_ID => WMA_V1_FORMAT_NAME.to_string(),
+        WMA_V2_FORMAT_ID => WMA_V2_FORMAT_NAME.to_string(),
+        WMA_PRO_FORMAT_ID => WMA_PRO_FORMAT_NAME.to_string(),
+        WMA_LOSSLESS_FORMAT_ID => WMA_LOSSLESS_FORMAT_NAME.to_string(),
+        DOLBY_AC3_SPDIF_FORMAT_ID => DOLBY_AC3_SPDIF_FORMAT_NAME.to_string(),
+        DOLBY_AC3_FORMAT_ID => DOLBY_AC3_FORMAT_NAME.to_string(),
         EXTENSIBLE_FORMAT_ID => EXTENSIBLE_FORMAT_NAME.to_string(),
         _ => format!("{} {}", UNKOWN_FORMAT, format_id),
     }
@@ -125,7 +165,7 @@ fn format_guid(guid_bytes: [u8; GUID_LENGTH_IN_BYTES]) -> String {
     formated_guid.join("")
 }
 
-fn format_speaker_position(speaker_position_mask: u32) -> String {
+pub fn format_speaker_position(speaker_position_mask: u32) -> String {
     let mut positions: Vec<String> = Default::default();
 
     SPEAKER_POSITION_MASK_BIT_MEANING
@@ -147,8 +187,8 @@ fn format_speaker_position(speaker_position_mask: u32) -> String {
 #[cfg(test)]
 mod tests {
     use crate::chunks::fmt::{
-        format_guid, format_speaker_position, get_format_name_from_format_id, PCM_FORMAT_NAME,
-        UNKOWN_FORMAT,
+        format_guid, format_speaker_position, get_format_name_from_format_id,
+        EXTENSIBLE_FORMAT_NAME, PCM_FORMAT_NAME, UNKOWN_FORMAT,
     };
 
     #[test]
@@ -159,6 +199,14 @@ mod tests {
         assert_eq!(result, correct_result);
     }
 
+    #[test]
+    fn return_extensible_format_name_from_wave_format_extensible_id() {
+        let test_integer: u16 = 0xFFFE;
+        let correct_result: String = EXTENSIBLE_FORMAT_NAME.to_string();
+        let result = get_format_name_from_format_id(test_integer);
+        assert_eq!(result, correct_result);
+    }
+
     #[test]
     fn return_correct_format_name_from_unknown_format_id_integer() {
         let test_integer: u16 = 0;
@@ -1,46 +1,217 @@
+use crate::dj_tags::{serato, traktor};
 use crate::errors::LocalError;
+use crate::fileio::read_up_to_number_of_bytes_from_file;
+use crate::formating::{
+    format_bit_as_bool_string, format_bytes_as_string_of_bytes, set_key_value_pair_spacers,
+    KeyValuePair as Detail,
+};
 use crate::output::{OutputEntry, Section};
 use crate::template::get_file_chunk_output;
-use id3::Tag;
+use crate::trailing_tags::CommonFields;
+use id3::frame::{Content, TimestampFormat};
+use id3::{Frame, Tag, TagLike};
 use serde::Serialize;
 use std::error::Error;
+use std::fs::File;
+use std::io::Cursor;
 use upon::Value;
 
 const TEMPLATE_CONTENT: &str = include_str!("../templates/chunks/id3.tmpl");
+const EMPTY_TEMPLATE_CONTENT: &str = include_str!("../templates/chunks/empty.tmpl");
 
 const TIME_FIELD_TITLE: &str = "Time";
 const TIME_HOUR_MINUTE_DIVIDER_POSITION: usize = 2;
 
-#[derive(Debug, Clone, Default, Serialize)]
+const ID3_TAG_ID: &[u8] = b"ID3";
+const HEADER_LENGTH_IN_BYTES: usize = 10;
+const HEADER_PEEK_LENGTH_IN_BYTES: usize = 64;
+const UNSYNCHRONISATION_FLAG: u8 = 0x80;
+const EXTENDED_HEADER_FLAG: u8 = 0x40;
+const COMPRESSION_FLAG: u8 = 0x40;
+const EXPERIMENTAL_FLAG: u8 = 0x20;
+const FOOTER_PRESENT_FLAG: u8 = 0x10;
+const ID3V2_2_VERSION: u8 = 2;
+const ID3V2_3_VERSION: u8 = 3;
+const SUPPORTED_VERSIONS: [u8; 3] = [2, 3, 4];
+const MAX_SYNC_SAFE_BYTE_VALUE: u8 = 0x7F;
+const ID3V2_3_CRC_PRESENT_FLAG: u8 = 0x80;
+const ID3V2_4_UPDATE_FLAG: u8 = 0x40;
+const ID3V2_4_CRC_PRESENT_FLAG: u8 = 0x20;
+const ID3V2_4_RESTRICTIONS_FLAG: u8 = 0x10;
+const ID3V2_4_CRC_LENGTH_IN_BYTES: usize = 5;
+
+const BINARY_PREVIEW_LENGTH_IN_BYTES: usize = 16;
+const UNUSED_CHAPTER_OFFSET: u32 = 0xFFFFFFFF;
+const MULTIPLE_VALUE_SEPARATOR: char = '\0';
+
+const EVENT_TYPES: [(u8, &str); 25] = [
+    (0x00, "Padding"),
+    (0x01, "End of initial silence"),
+    (0x02, "Intro start"),
+    (0x03, "Main part start"),
+    (0x04, "Outro start"),
+    (0x05, "Outro end"),
+    (0x06, "Verse start"),
+    (0x07, "Refrain start"),
+    (0x08, "Interlude start"),
+    (0x09, "Theme start"),
+    (0x0A, "Variation start"),
+    (0x0B, "Key change"),
+    (0x0C, "Time change"),
+    (0x0D, "Momentary unwanted noise"),
+    (0x0E, "Sustained noise"),
+    (0x0F, "Sustained noise end"),
+    (0x10, "Intro end"),
+    (0x11, "Main part end"),
+    (0x12, "Verse end"),
+    (0x13, "Refrain end"),
+    (0x14, "Theme end"),
+    (0x15, "Profanity"),
+    (0x16, "Profanity end"),
+    (0xFD, "Audio end"),
+    (0xFE, "Audio file ends"),
+];
+
+const RVA2_CHANNEL_TYPES: [&str; 9] = [
+    "Other",
+    "Master volume",
+    "Front right",
+    "Front left",
+    "Back right",
+    "Back left",
+    "Front centre",
+    "Back centre",
+    "Subwoofer",
+];
+
+const MCDI_HEADER_LENGTH_IN_BYTES: usize = 4;
+const MCDI_TRACK_DESCRIPTOR_LENGTH_IN_BYTES: usize = 8;
+const MCDI_LEAD_OUT_TRACK_NUMBER: u8 = 0xAA;
+const RVA2_VOLUME_ADJUSTMENT_DIVISOR: f32 = 512.0;
+
+#[derive(Debug, Default, Serialize)]
 struct ID3Tag {
     id: String,
     spacer: String,
     content: String,
+    details: Vec<Detail>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Header {
+    version: String,
+    tag_size: usize,
+    unsynchronisation: String,
+    extended_header: String,
+    experimental: String,
+    is_compressed: String,
+    has_footer: String,
+    extended_header_details: Vec<Detail>,
 }
 
 pub fn get_metadata(file_path: &str) -> Result<OutputEntry, Box<dyn Error>> {
-    let mut id3_entries: Vec<ID3Tag> = Vec::new();
     let tag = Tag::read_from_path(file_path)
         .map_err(|e| LocalError::InvalidID3TagDataFound(e.to_string()))?;
-    let longest_tag_id = get_longest_tag_id(&tag)?;
+
+    let mut id3_file = File::open(file_path)?;
+    let header_bytes =
+        read_up_to_number_of_bytes_from_file(&mut id3_file, HEADER_PEEK_LENGTH_IN_BYTES)?;
+
+    get_metadata_from_tag(tag, get_header_from_bytes(&header_bytes), String::new())
+}
+
+pub fn get_metadata_from_bytes(tag_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    get_metadata_from_tag_data(tag_data, String::new())
+}
+
+pub fn get_metadata_from_chunk(
+    chunk_id: String,
+    chunk_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    get_metadata_from_tag_data(chunk_data, chunk_id)
+}
+
+pub fn is_id3_tag(tag_data: &[u8]) -> bool {
+    tag_data.len() >= HEADER_LENGTH_IN_BYTES
+        && tag_data.starts_with(ID3_TAG_ID)
+        && SUPPORTED_VERSIONS.contains(&tag_data[3])
+        && tag_data[6..HEADER_LENGTH_IN_BYTES]
+            .iter()
+            .all(|byte| *byte <= MAX_SYNC_SAFE_BYTE_VALUE)
+}
+
+pub fn get_common_fields_from_file(file_path: &str) -> Option<CommonFields> {
+    let tag = Tag::read_from_path(file_path).ok()?;
+
+    let year = tag
+        .year()
+        .or_else(|| tag.date_recorded().map(|timestamp| timestamp.year))
+        .map(|year| year.to_string());
+
+    let fields = [
+        ("Title", tag.title().map(|title| title.to_string())),
+        ("Artist", tag.artist().map(|artist| artist.to_string())),
+        ("Album", tag.album().map(|album| album.to_string())),
+        ("Year", year),
+        ("Track", tag.track().map(|track| track.to_string())),
+        ("Genre", tag.genre_parsed().map(|genre| genre.to_string())),
+    ];
+
+    Some(
+        fields
+            .into_iter()
+            .filter_map(|(name, value)| Some((name, value?.trim().to_string())))
+            .filter(|(_, value)| !value.is_empty())
+            .collect(),
+    )
+}
+
+fn get_metadata_from_tag_data(
+    tag_data: Vec<u8>,
+    source_chunk_id: String,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let header = get_header_from_bytes(&tag_data);
+    let tag = Tag::read_from2(Cursor::new(tag_data))
+        .map_err(|e| LocalError::InvalidID3TagDataFound(e.to_string()))?;
+
+    get_metadata_from_tag(tag, header, source_chunk_id)
+}
+
+fn get_metadata_from_tag(
+    tag: Tag,
+    header: Option<Header>,
+    source_chunk_id: String,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    if tag.frames().next().is_none() {
+        return get_empty_tag_metadata(source_chunk_id);
+    }
+
+    let mut id3_entries: Vec<ID3Tag> = Vec::new();
+    let longest_tag_id = get_longest_tag_id(&tag);
 
     tag.frames().for_each(|frame| {
-        let mut content: String = frame.content().to_string();
+        let (mut content, mut details) = get_frame_content_and_details(frame);
         let id = frame.name().to_string();
         if id == TIME_FIELD_TITLE {
             content.insert(TIME_HOUR_MINUTE_DIVIDER_POSITION, ':');
         }
 
         let spacer = " ".repeat(longest_tag_id - id.len());
+        details.retain(|detail| !detail.value.is_empty());
+        set_key_value_pair_spacers(&mut details);
 
         id3_entries.push(ID3Tag {
             id,
             spacer,
             content,
+            details,
         });
     });
 
     let output_values: Value = upon::value! {
+        source_chunk_id: source_chunk_id,
+        has_header: header.is_some(),
+        header: header.unwrap_or_default(),
         id3_tags: id3_entries,
     };
 
@@ -52,9 +223,496 @@ pub fn get_metadata(file_path: &str) -> Result<OutputEntry, Box<dyn Error>> {
     })
 }
 
-fn get_longest_tag_id(tags: &Tag) -> Result<usize, LocalError> {
-    match tags.frames().max_by_key(|tag| tag.name().len()) {
-        Some(tag) => Ok(tag.name().len()),
-        None => Err(LocalError::ErrorParsingID3TagIDs),
+fn get_empty_tag_metadata(source_chunk_id: String) -> Result<OutputEntry, Box<dyn Error>> {
+    let body = match source_chunk_id.is_empty() {
+        true => "ID3 (Empty Tag)".to_string(),
+        false => format!("ID3 (Empty Tag in '{}' Chunk)", source_chunk_id),
+    };
+
+    let output_values: Value = upon::value! {
+        body: body,
+    };
+
+    let formated_output = get_file_chunk_output(EMPTY_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Empty,
+        text: formated_output,
+    })
+}
+
+fn get_longest_tag_id(tags: &Tag) -> usize {
+    tags.frames()
+        .map(|tag| tag.name().len())
+        .max()
+        .unwrap_or_default()
+}
+
+fn get_header_from_bytes(header_bytes: &[u8]) -> Option<Header> {
+    if header_bytes.len() < HEADER_LENGTH_IN_BYTES || !header_bytes.starts_with(ID3_TAG_ID) {
+        return None;
+    }
+
+    let major_version = header_bytes[3];
+    let revision = header_bytes[4];
+    let flags = header_bytes[5];
+    let tag_size = get_sync_safe_integer(&header_bytes[6..HEADER_LENGTH_IN_BYTES]);
+
+    let is_version_2_2 = major_version == ID3V2_2_VERSION;
+    let has_extended_header = !is_version_2_2 && flags & EXTENDED_HEADER_FLAG != 0;
+
+    let extended_header_details = match has_extended_header {
+        true => get_extended_header_details(major_version, &header_bytes[HEADER_LENGTH_IN_BYTES..]),
+        false => vec![],
+    };
+
+    Some(Header {
+        version: format!("ID3v2.{}.{}", major_version, revision),
+        tag_size,
+        unsynchronisation: format_flag(flags, UNSYNCHRONISATION_FLAG),
+        extended_header: format_bit_as_bool_string(has_extended_header as u8),
+        experimental: format_flag(flags, EXPERIMENTAL_FLAG),
+        is_compressed: match is_version_2_2 {
+            true => format_flag(flags, COMPRESSION_FLAG),
+            false => String::new(),
+        },
+        has_footer: match major_version > ID3V2_3_VERSION {
+            true => format_flag(flags, FOOTER_PRESENT_FLAG),
+            false => String::new(),
+        },
+        extended_header_details,
+    })
+}
+
+fn get_extended_header_details(major_version: u8, extended_header_bytes: &[u8]) -> Vec<Detail> {
+    let mut details: Vec<Detail> = vec![];
+
+    if major_version == ID3V2_3_VERSION {
+        if extended_header_bytes.len() < 10 {
+            return details;
+        }
+
+        let size = u32::from_be_bytes(extended_header_bytes[0..4].try_into().unwrap_or_default());
+        let flags = extended_header_bytes[4];
+        let padding_size =
+            u32::from_be_bytes(extended_header_bytes[6..10].try_into().unwrap_or_default());
+
+        details.push(get_detail("Size", format!("{} bytes", size)));
+        details.push(get_detail(
+            "Padding Size",
+            format!("{} bytes", padding_size),
+        ));
+
+        if flags & ID3V2_3_CRC_PRESENT_FLAG != 0 && extended_header_bytes.len() >= 14 {
+            details.push(get_detail(
+                "CRC-32",
+                format!("0x{}", format_hex(&extended_header_bytes[10..14])),
+            ));
+        }
+    } else {
+        if extended_header_bytes.len() < 6 {
+            return details;
+        }
+
+        let size = get_sync_safe_integer(&extended_header_bytes[0..4]);
+        let flags = extended_header_bytes[5];
+        let mut flag_data_offset = 6;
+
+        details.push(get_detail("Size", format!("{} bytes", size)));
+        details.push(get_detail(
+            "Tag Is an Update",
+            format_flag(flags, ID3V2_4_UPDATE_FLAG),
+        ));
+        if flags & ID3V2_4_UPDATE_FLAG != 0 {
+            flag_data_offset += 1;
+        }
+
+        if flags & ID3V2_4_CRC_PRESENT_FLAG != 0 {
+            let crc_start = flag_data_offset + 1;
+            let crc_end = crc_start + ID3V2_4_CRC_LENGTH_IN_BYTES;
+            if let Some(crc_bytes) = extended_header_bytes.get(crc_start..crc_end) {
+                details.push(get_detail(
+                    "CRC-32",
+                    format!("0x{:08X}", get_sync_safe_integer(crc_bytes)),
+                ));
+            }
+            flag_data_offset = crc_end;
+        }
+
+        if flags & ID3V2_4_RESTRICTIONS_FLAG != 0 {
+            if let Some(restrictions) = extended_header_bytes.get(flag_data_offset + 1) {
+                details.push(get_detail(
+                    "Tag Restrictions",
+                    format!("0b{:08b}", restrictions),
+                ));
+            }
+        }
+    }
+
+    set_key_value_pair_spacers(&mut details);
+    details
+}
+
+fn get_frame_content_and_details(frame: &Frame) -> (String, Vec<Detail>) {
+    match frame.content() {
+        Content::Text(text) => (format_multiple_values(text), vec![]),
+        Content::ExtendedText(extended_text) => (
+            format_multiple_values(&extended_text.value),
+            vec![get_detail("Description", extended_text.description.clone())],
+        ),
+        Content::Link(link) => (link.clone(), vec![]),
+        Content::ExtendedLink(extended_link) => (
+            extended_link.link.clone(),
+            vec![get_detail("Description", extended_link.description.clone())],
+        ),
+        Content::Comment(comment) => (
+            comment.text.clone(),
+            vec![
+                get_detail("Language", comment.lang.clone()),
+                get_detail("Description", comment.description.clone()),
+            ],
+        ),
+        Content::Lyrics(lyrics) => (
+            lyrics.text.lines().collect::<Vec<&str>>().join(" / "),
+            vec![
+                get_detail("Language", lyrics.lang.clone()),
+                get_detail("Description", lyrics.description.clone()),
+            ],
+        ),
+        Content::SynchronisedLyrics(lyrics) => {
+            let mut details = vec![
+                get_detail("Language", lyrics.lang.clone()),
+                get_detail("Timestamp Format", lyrics.timestamp_format.to_string()),
+                get_detail("Content Type", lyrics.content_type.to_string()),
+                get_detail("Description", lyrics.description.clone()),
+            ];
+            details.extend(lyrics.content.iter().map(|(timestamp, text)| {
+                get_detail(
+                    &format_timestamp(*timestamp, lyrics.timestamp_format),
+                    text.trim().to_string(),
+                )
+            }));
+
+            (format!("{} entries", lyrics.content.len()), details)
+        }
+        Content::Picture(picture) => (
+            picture.picture_type.to_string(),
+            vec![
+                get_detail("MIME Type", picture.mime_type.clone()),
+                get_detail("Description", picture.description.clone()),
+                get_detail("Size", format!("{} bytes", picture.data.len())),
+            ],
+        ),
+        Content::EncapsulatedObject(object) => {
+            let mut details = vec![
+                get_detail("MIME Type", object.mime_type.clone()),
+                get_detail("Description", object.description.clone()),
+                get_detail("Size", format!("{} bytes", object.data.len())),
+            ];
+
+            if serato::is_serato_object(&object.description) {
+                details.extend(serato::get_details(&object.description, &object.data));
+            }
+
+            (object.filename.clone(), details)
+        }
+        Content::Popularimeter(popularimeter) => (
+            format!("{}/255", popularimeter.rating),
+            vec![
+                get_detail("User", popularimeter.user.clone()),
+                get_detail("Play Count", popularimeter.counter.to_string()),
+            ],
+        ),
+        Content::Private(private) if private.owner_identifier == traktor::PRIV_OWNER => (
+            private.owner_identifier.clone(),
+            traktor::get_details(&private.private_data),
+        ),
+        Content::Private(private) => (
+            private.owner_identifier.clone(),
+            vec![
+                get_detail("Size", format!("{} bytes", private.private_data.len())),
+                get_detail("Data", format_binary_preview(&private.private_data)),
+            ],
+        ),
+        Content::UniqueFileIdentifier(identifier) => (
+            identifier.owner_identifier.clone(),
+            vec![get_detail(
+                "Identifier",
+                format_identifier(&identifier.identifier),
+            )],
+        ),
+        Content::Chapter(chapter) => (
+            chapter.element_id.clone(),
+            vec![
+                get_detail("Start Time", format!("{} ms", chapter.start_time)),
+                get_detail("End Time", format!("{} ms", chapter.end_time)),
+                get_detail("Start Offset", format_chapter_offset(chapter.start_offset)),
+                get_detail("End Offset", format_chapter_offset(chapter.end_offset)),
+                get_detail("Frames", format_frame_list(&chapter.frames)),
+            ],
+        ),
+        Content::TableOfContents(table_of_contents) => (
+            table_of_contents.element_id.clone(),
+            vec![
+                get_detail(
+                    "Top Level",
+                    format_bit_as_bool_string(table_of_contents.top_level as u8),
+                ),
+                get_detail(
+                    "Ordered",
+                    format_bit_as_bool_string(table_of_contents.ordered as u8),
+                ),
+                get_detail("Elements", table_of_contents.elements.join(", ")),
+                get_detail("Frames", format_frame_list(&table_of_contents.frames)),
+            ],
+        ),
+        Content::MpegLocationLookupTable(table) => (
+            format!("{} references", table.references.len()),
+            vec![
+                get_detail("Frames Between", table.frames_between_reference.to_string()),
+                get_detail("Bytes Between", table.bytes_between_reference.to_string()),
+                get_detail(
+                    "Milliseconds Between",
+                    table.millis_between_reference.to_string(),
+                ),
+            ],
+        ),
+        Content::InvolvedPeopleList(people) => (
+            people
+                .items
+                .iter()
+                .map(|item| format!("{}: {}", item.involvement, item.involvee))
+                .collect::<Vec<String>>()
+                .join(" / "),
+            vec![],
+        ),
+        Content::Unknown(unknown) => match frame.id() {
+            "ETCO" | "ETC" => get_event_timing_codes(&unknown.data),
+            "MCDI" | "MCI" => get_music_cd_identifier(&unknown.data),
+            "RVA2" => get_relative_volume_adjustment(&unknown.data),
+            _ => (
+                format!("[Binary data: {} bytes]", unknown.data.len()),
+                vec![],
+            ),
+        },
+        content => (content.to_string(), vec![]),
+    }
+}
+
+fn get_event_timing_codes(frame_data: &[u8]) -> (String, Vec<Detail>) {
+    let Some((timestamp_format_id, events)) = frame_data.split_first() else {
+        return (String::new(), vec![]);
+    };
+
+    let timestamp_format = match timestamp_format_id {
+        1 => TimestampFormat::Mpeg,
+        _ => TimestampFormat::Ms,
+    };
+
+    let details: Vec<Detail> = events
+        .chunks_exact(5)
+        .map(|event| {
+            let timestamp = u32::from_be_bytes([event[1], event[2], event[3], event[4]]);
+            get_detail(
+                &format_timestamp(timestamp, timestamp_format),
+                format_event_type(event[0]),
+            )
+        })
+        .collect();
+
+    (format!("{} events", details.len()), details)
+}
+
+fn get_music_cd_identifier(frame_data: &[u8]) -> (String, Vec<Detail>) {
+    if frame_data.len() < MCDI_HEADER_LENGTH_IN_BYTES {
+        return (format!("[Binary data: {} bytes]", frame_data.len()), vec![]);
+    }
+
+    let first_track = frame_data[2];
+    let last_track = frame_data[3];
+
+    let details: Vec<Detail> = frame_data[MCDI_HEADER_LENGTH_IN_BYTES..]
+        .chunks_exact(MCDI_TRACK_DESCRIPTOR_LENGTH_IN_BYTES)
+        .map(|descriptor| {
+            let address =
+                u32::from_be_bytes([descriptor[4], descriptor[5], descriptor[6], descriptor[7]]);
+            let track = match descriptor[2] {
+                MCDI_LEAD_OUT_TRACK_NUMBER => "Lead-out".to_string(),
+                track_number => format!("Track {}", track_number),
+            };
+            get_detail(&track, format!("Sector {}", address))
+        })
+        .collect();
+
+    (format!("Tracks {} to {}", first_track, last_track), details)
+}
+
+fn get_relative_volume_adjustment(frame_data: &[u8]) -> (String, Vec<Detail>) {
+    let identification_length = frame_data
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(frame_data.len());
+    let identification = String::from_utf8_lossy(&frame_data[..identification_length]).to_string();
+
+    let mut details: Vec<Detail> = vec![];
+    let mut offset = identification_length + 1;
+
+    while offset + 4 <= frame_data.len() {
+        let channel_type = frame_data[offset];
+        let adjustment = i16::from_be_bytes([frame_data[offset + 1], frame_data[offset + 2]]);
+        let peak_bits = frame_data[offset + 3] as usize;
+        offset += 4 + peak_bits.div_ceil(8);
+
+        let channel = match RVA2_CHANNEL_TYPES.get(channel_type as usize) {
+            Some(channel) => channel.to_string(),
+            None => format!("Unknown ({})", channel_type),
+        };
+
+        details.push(get_detail(
+            &channel,
+            format!(
+                "{:+.2} dB",
+                adjustment as f32 / RVA2_VOLUME_ADJUSTMENT_DIVISOR
+            ),
+        ));
+    }
+
+    (identification, details)
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+fn get_sync_safe_integer(sync_safe_bytes: &[u8]) -> usize {
+    sync_safe_bytes
+        .iter()
+        .fold(0, |integer, byte| (integer << 7) | (*byte & 0x7F) as usize)
+}
+
+fn format_flag(flags: u8, flag_mask: u8) -> String {
+    format_bit_as_bool_string((flags & flag_mask != 0) as u8)
+}
+
+fn format_multiple_values(text: &str) -> String {
+    text.split(MULTIPLE_VALUE_SEPARATOR)
+        .collect::<Vec<&str>>()
+        .join(" / ")
+}
+
+fn format_timestamp(timestamp: u32, timestamp_format: TimestampFormat) -> String {
+    match timestamp_format {
+        TimestampFormat::Mpeg => format!("Frame {}", timestamp),
+        TimestampFormat::Ms => format!(
+            "{:02}:{:02}.{:03}",
+            timestamp / 60000,
+            timestamp / 1000 % 60,
+            timestamp % 1000
+        ),
+    }
+}
+
+fn format_event_type(event_type: u8) -> String {
+    match EVENT_TYPES.iter().find(|(id, _)| *id == event_type) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({:#04X})", event_type),
+    }
+}
+
+fn format_chapter_offset(offset: u32) -> String {
+    match offset {
+        UNUSED_CHAPTER_OFFSET => "Unused".to_string(),
+        offset => format!("{} bytes", offset),
+    }
+}
+
+fn format_frame_list(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .map(|frame| format!("{}: {}", frame.id(), get_frame_content_and_details(frame).0))
+        .collect::<Vec<String>>()
+        .join(" / ")
+}
+
+fn format_identifier(identifier: &[u8]) -> String {
+    match identifier.iter().all(|byte| byte.is_ascii_graphic()) {
+        true => String::from_utf8_lossy(identifier).to_string(),
+        false => format_binary_preview(identifier),
+    }
+}
+
+fn format_binary_preview(data: &[u8]) -> String {
+    let preview_length = data.len().min(BINARY_PREVIEW_LENGTH_IN_BYTES);
+    let preview = format_bytes_as_string_of_bytes(&data[..preview_length]);
+
+    match data.len() > preview_length {
+        true => format!("{} ...", preview),
+        false => preview,
+    }
+}
+
+fn format_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_header_from_id3v2_4_bytes_with_extended_header() {
+        let mut header_bytes = vec![b'I', b'D', b'3', 4, 0, 0xD0, 0, 0, 0x02, 0x01];
+        header_bytes.extend([0, 0, 0, 6, 1, 0x40]);
+
+        let result = get_header_from_bytes(&header_bytes).unwrap();
+        assert_eq!(result.version, "ID3v2.4.0");
+        assert_eq!(result.tag_size, 257);
+        assert_eq!(result.unsynchronisation, "True");
+        assert_eq!(result.extended_header, "True");
+        assert_eq!(result.has_footer, "True");
+        assert_eq!(result.extended_header_details[1].value, "True");
+        assert!(get_header_from_bytes(b"TAG").is_none());
+    }
+
+    #[test]
+    fn return_true_only_for_valid_id3_tag_headers() {
+        assert!(is_id3_tag(&[b'I', b'D', b'3', 3, 0, 0, 0, 0, 0x01, 0x7F]));
+        assert!(!is_id3_tag(&[b'I', b'D', b'3', 9, 0, 0, 0, 0, 0x01, 0x7F]));
+        assert!(!is_id3_tag(&[b'I', b'D', b'3', 3, 0, 0, 0, 0, 0x01, 0x80]));
+        assert!(!is_id3_tag(b"ID3"));
+    }
+
+    #[test]
+    fn return_correct_event_timing_codes_from_bytes() {
+        let frame_data = [2, 0x02, 0, 0, 0x05, 0xDC, 0x05, 0, 0, 0xEA, 0x60];
+        let (content, details) = get_event_timing_codes(&frame_data);
+        assert_eq!(content, "2 events");
+        assert_eq!(details[0].key, "00:01.500");
+        assert_eq!(details[0].value, "Intro start");
+        assert_eq!(details[1].key, "01:00.000");
+        assert_eq!(details[1].value, "Outro end");
+    }
+
+    #[test]
+    fn return_correct_relative_volume_adjustment_from_bytes() {
+        let frame_data = [b't', b'r', b'a', b'c', b'k', 0, 1, 0xFC, 0x00, 8, 0x7F];
+        let (content, details) = get_relative_volume_adjustment(&frame_data);
+        assert_eq!(content, "track");
+        assert_eq!(details[0].key, "Master volume");
+        assert_eq!(details[0].value, "-2.00 dB");
+    }
+
+    #[test]
+    fn return_empty_section_from_padding_only_tag() {
+        let mut tag_data = vec![b'I', b'D', b'3', 3, 0, 0, 0, 0, 0, 0x10];
+        tag_data.extend([0x00; 16]);
+
+        let result = get_metadata_from_tag_data(tag_data, "id3 ".to_string()).unwrap();
+        assert_eq!(result.section, Section::Empty);
+        assert!(result.text.contains("ID3 (Empty Tag in 'id3 ' Chunk)"));
     }
 }
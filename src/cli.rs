@@ -4,17 +4,18 @@ use std::process::exit;
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const EXIT_CODE_ERROR: i32 = 1;
 pub const EXIT_CODE_SUCCESS: i32 = 0;
-pub const USAGE_MESSAGE: &str = " usage: chunkdump [-hmv] [-o output_file] file";
+pub const USAGE_MESSAGE: &str = " usage: chunkdump [-dhmv] [-o output_file] file";
 
 #[derive(PartialEq, Debug)]
 pub struct Args {
     pub mandatory: bool,
+    pub deep_scan: bool,
     pub input_file_path: String,
     pub output_file_path: Option<String>,
 }
 
 #[derive(FromArgs)]
-/// Chunkdump - Extract Metadata From RIFF Wave, AIFF, Flac, Ogg Vorbis, MP3, M4A, CAF, SMF MIDI, and RIFF RMID Files
+/// Chunkdump - Extract Metadata From RIFF Wave, AIFF, Flac, Ogg Vorbis, Ogg Opus, Ogg FLAC, Ogg Speex, Matroska/WebM, ASF/WMA, DSF/DSDIFF, WavPack, Monkey's Audio, Sun AU, IFF 8SVX, Tracker Modules (MOD, S3M, XM, IT), AAC (ADTS/LATM), AC-3/E-AC-3, MP3, MP4/M4A/MOV/3GP, CAF, SMF MIDI, RIFF RMID, and DLS Files
 #[argh(help_triggers("-h", "--help", "help"))]
 pub struct CliArguments {
     /// print the version
@@ -25,6 +26,10 @@ pub struct CliArguments {
     #[argh(switch, short = 'm')]
     mandatory: bool,
 
+    /// scan every MP3 frame for an exact duration, bitrate statistics and stream errors
+    #[argh(switch, short = 'd')]
+    deep: bool,
+
     /// a file path to output the data to rather than stdout
     #[argh(option, short = 'o')]
     output_file: Option<String>,
@@ -47,6 +52,7 @@ pub fn process_cli_arguments(args: CliArguments) -> Args {
 
     Args {
         mandatory: args.mandatory,
+        deep_scan: args.deep,
         input_file_path: args.file.unwrap(),
         output_file_path: args.output_file,
     }
@@ -67,11 +73,13 @@ mod tests {
         let test_args = CliArguments {
             version: false,
             mandatory: true,
+            deep: false,
             file: Some(test_input_path.clone()),
             output_file: Some(test_output_path.clone()),
         };
         let correct_result = Args {
             mandatory: true,
+            deep_scan: false,
             input_file_path: test_input_path,
             output_file_path: Some(test_output_path),
         };
@@ -87,11 +95,13 @@ mod tests {
         let test_args = CliArguments {
             version: false,
             mandatory: false,
+            deep: true,
             file: Some(test_input_path.clone()),
             output_file: None,
         };
         let correct_result = Args {
             mandatory: false,
+            deep_scan: true,
             input_file_path: test_input_path,
             output_file_path: None,
         };
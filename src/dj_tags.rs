@@ -0,0 +1,78 @@
+pub mod serato;
+pub mod traktor;
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_BITS_PER_CHARACTER: u32 = 6;
+
+pub fn format_position(position_in_milliseconds: f64) -> String {
+    let position = position_in_milliseconds.max(0.0).round() as u64;
+
+    format!(
+        "{:02}:{:02}.{:03}",
+        position / 60000,
+        position / 1000 % 60,
+        position % 1000
+    )
+}
+
+pub fn format_colour(rgb: &[u8]) -> String {
+    rgb.iter().fold("#".to_string(), |colour, byte| {
+        format!("{}{:02X}", colour, byte)
+    })
+}
+
+fn decode_base64(encoded: &[u8]) -> Vec<u8> {
+    let mut decoded: Vec<u8> = vec![];
+    let mut buffer: u32 = 0;
+    let mut buffered_bits: u32 = 0;
+
+    for character in encoded {
+        let Some(value) = BASE64_ALPHABET
+            .iter()
+            .position(|alphabet_character| alphabet_character == character)
+        else {
+            continue;
+        };
+
+        buffer = (buffer << BASE64_BITS_PER_CHARACTER) | value as u32;
+        buffered_bits += BASE64_BITS_PER_CHARACTER;
+
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            decoded.push((buffer >> buffered_bits) as u8);
+            buffer &= (1 << buffered_bits) - 1;
+        }
+    }
+
+    decoded
+}
+
+fn take_null_terminated_string(data: &[u8]) -> (String, &[u8]) {
+    let string_length = data
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(data.len());
+    let remaining_start = (string_length + 1).min(data.len());
+
+    (
+        String::from_utf8_lossy(&data[..string_length]).to_string(),
+        &data[remaining_start..],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_bytes_from_base64_with_line_breaks_and_missing_padding() {
+        assert_eq!(decode_base64(b"U2Vy\nYXRv"), b"Serato");
+        assert_eq!(decode_base64(b"QUJDRA"), b"ABCD");
+        assert_eq!(decode_base64(b"QUJDRA=="), b"ABCD");
+    }
+
+    #[test]
+    fn return_correct_position_string_from_milliseconds() {
+        assert_eq!(format_position(83456.4), "01:23.456");
+    }
+}
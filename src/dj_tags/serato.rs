@@ -0,0 +1,340 @@
+use crate::dj_tags::{decode_base64, format_colour, format_position, take_null_terminated_string};
+use crate::formating::{
+    format_bit_as_bool_string, set_key_value_pair_spacers, KeyValuePair as Detail,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dj_tags/serato.tmpl");
+pub const FREEFORM_MEAN: &str = "com.serato.dj";
+
+const MARKERS_2_NAME: &str = "Serato Markers2";
+const MARKERS_NAME: &str = "Serato Markers_";
+const BEATGRID_NAME: &str = "Serato BeatGrid";
+const AUTOTAGS_NAME: &str = "Serato Autotags";
+const OVERVIEW_NAME: &str = "Serato Overview";
+const ANALYSIS_NAME: &str = "Serato Analysis";
+
+const VERSION_LENGTH_IN_BYTES: usize = 2;
+const ENTRY_LENGTH_FIELD_LENGTH_IN_BYTES: usize = 4;
+const BEATGRID_MARKER_LENGTH_IN_BYTES: usize = 8;
+const OVERVIEW_BLOCK_LENGTH_IN_BYTES: usize = 16;
+const MARKERS_ENTRY_COUNT_LENGTH_IN_BYTES: usize = 4;
+
+const COLOUR_ENTRY: &str = "COLOR";
+const CUE_ENTRY: &str = "CUE";
+const LOOP_ENTRY: &str = "LOOP";
+const BPM_LOCK_ENTRY: &str = "BPMLOCK";
+
+const CUE_NAME_OFFSET: usize = 12;
+const LOOP_LOCKED_OFFSET: usize = 18;
+const LOOP_NAME_OFFSET: usize = 19;
+
+const AUTOTAGS_FIELD_NAMES: [&str; 3] = ["BPM", "Auto Gain", "Gain"];
+
+pub fn is_serato_object(description: &str) -> bool {
+    [
+        MARKERS_2_NAME,
+        MARKERS_NAME,
+        BEATGRID_NAME,
+        AUTOTAGS_NAME,
+        OVERVIEW_NAME,
+        ANALYSIS_NAME,
+    ]
+    .contains(&description)
+}
+
+pub fn get_details(name: &str, data: &[u8]) -> Vec<Detail> {
+    match name {
+        MARKERS_2_NAME => get_markers_2_details(data),
+        MARKERS_NAME => get_markers_details(data),
+        BEATGRID_NAME => get_beatgrid_details(data),
+        AUTOTAGS_NAME => get_autotags_details(data),
+        OVERVIEW_NAME => get_overview_details(data),
+        ANALYSIS_NAME => get_version_details(data),
+        _ => vec![get_detail("Size", format!("{} bytes", data.len()))],
+    }
+}
+
+pub fn get_metadata_from_freeform_value(value: &str) -> Result<OutputEntry, Box<dyn Error>> {
+    let decoded_value = decode_base64(value.as_bytes());
+    let (_mime_type, remaining) = take_null_terminated_string(&decoded_value);
+    let remaining = remaining.strip_prefix(&[0x00]).unwrap_or(remaining);
+    let (name, data) = take_null_terminated_string(remaining);
+
+    let mut details = get_details(&name, data);
+    set_key_value_pair_spacers(&mut details);
+
+    let output_values: Value = upon::value! {
+        name: &name,
+        details: details,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_markers_2_details(data: &[u8]) -> Vec<Detail> {
+    let encoded_payload = data.get(VERSION_LENGTH_IN_BYTES..).unwrap_or_default();
+    let encoded_length = encoded_payload
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(encoded_payload.len());
+    let payload = decode_base64(&encoded_payload[..encoded_length]);
+
+    let mut details: Vec<Detail> = vec![];
+    let mut entries = payload.get(VERSION_LENGTH_IN_BYTES..).unwrap_or_default();
+
+    while !entries.is_empty() {
+        let (entry_name, remaining) = take_null_terminated_string(entries);
+        if entry_name.is_empty() || remaining.len() < ENTRY_LENGTH_FIELD_LENGTH_IN_BYTES {
+            break;
+        }
+
+        let entry_length =
+            u32::from_be_bytes([remaining[0], remaining[1], remaining[2], remaining[3]]) as usize;
+        let entry_end = (ENTRY_LENGTH_FIELD_LENGTH_IN_BYTES + entry_length).min(remaining.len());
+        let entry_data = &remaining[ENTRY_LENGTH_FIELD_LENGTH_IN_BYTES..entry_end];
+        entries = &remaining[entry_end..];
+
+        if let Some(detail) = get_markers_2_entry_detail(&entry_name, entry_data) {
+            details.push(detail);
+        }
+    }
+
+    details
+}
+
+fn get_markers_2_entry_detail(entry_name: &str, entry_data: &[u8]) -> Option<Detail> {
+    match entry_name {
+        COLOUR_ENTRY => Some(get_detail(
+            "Track Colour",
+            format_colour(entry_data.get(1..4)?),
+        )),
+        CUE_ENTRY => {
+            let index = entry_data.get(1)?;
+            let position = u32::from_be_bytes(entry_data.get(2..6)?.try_into().ok()?);
+            let colour = format_colour(entry_data.get(7..10)?);
+            let (name, _) = take_null_terminated_string(entry_data.get(CUE_NAME_OFFSET..)?);
+
+            Some(get_detail(
+                &format!("Hot Cue {}", index + 1),
+                format!("{} {} {}", format_position(position as f64), colour, name)
+                    .trim_end()
+                    .to_string(),
+            ))
+        }
+        LOOP_ENTRY => {
+            let index = entry_data.get(1)?;
+            let start = u32::from_be_bytes(entry_data.get(2..6)?.try_into().ok()?);
+            let end = u32::from_be_bytes(entry_data.get(6..10)?.try_into().ok()?);
+            let is_locked = *entry_data.get(LOOP_LOCKED_OFFSET)? != 0;
+            let (name, _) = take_null_terminated_string(entry_data.get(LOOP_NAME_OFFSET..)?);
+            let locked = match is_locked {
+                true => " (Locked)",
+                false => "",
+            };
+
+            Some(get_detail(
+                &format!("Saved Loop {}", index + 1),
+                format!(
+                    "{} - {} {}{}",
+                    format_position(start as f64),
+                    format_position(end as f64),
+                    name,
+                    locked
+                )
+                .trim_end()
+                .to_string(),
+            ))
+        }
+        BPM_LOCK_ENTRY => Some(get_detail(
+            "BPM Lock",
+            format_bit_as_bool_string(*entry_data.first()?),
+        )),
+        _ => Some(get_detail(
+            entry_name,
+            format!("{} bytes", entry_data.len()),
+        )),
+    }
+}
+
+fn get_markers_details(data: &[u8]) -> Vec<Detail> {
+    let count_end = VERSION_LENGTH_IN_BYTES + MARKERS_ENTRY_COUNT_LENGTH_IN_BYTES;
+    let Some(count_bytes) = data.get(VERSION_LENGTH_IN_BYTES..count_end) else {
+        return vec![];
+    };
+
+    let entry_count = u32::from_be_bytes([
+        count_bytes[0],
+        count_bytes[1],
+        count_bytes[2],
+        count_bytes[3],
+    ]);
+
+    vec![get_detail("Entries", entry_count.to_string())]
+}
+
+fn get_beatgrid_details(data: &[u8]) -> Vec<Detail> {
+    let count_end = VERSION_LENGTH_IN_BYTES + MARKERS_ENTRY_COUNT_LENGTH_IN_BYTES;
+    let Some(count_bytes) = data.get(VERSION_LENGTH_IN_BYTES..count_end) else {
+        return vec![];
+    };
+
+    let marker_count = u32::from_be_bytes([
+        count_bytes[0],
+        count_bytes[1],
+        count_bytes[2],
+        count_bytes[3],
+    ]) as usize;
+    let markers: Vec<&[u8]> = data[count_end..]
+        .chunks_exact(BEATGRID_MARKER_LENGTH_IN_BYTES)
+        .take(marker_count)
+        .collect();
+
+    let mut details: Vec<Detail> = vec![];
+
+    for (index, marker) in markers.iter().enumerate() {
+        let position = f32::from_be_bytes([marker[0], marker[1], marker[2], marker[3]]);
+        let position = format_position(position as f64 * 1000.0);
+
+        let value = match index + 1 == markers.len() {
+            true => {
+                let bpm = f32::from_be_bytes([marker[4], marker[5], marker[6], marker[7]]);
+                details.insert(0, get_detail("BPM", format!("{:.2}", bpm)));
+                format!("{} at {:.2} BPM", position, bpm)
+            }
+            false => {
+                let beats = u32::from_be_bytes([marker[4], marker[5], marker[6], marker[7]]);
+                format!("{} for {} beats", position, beats)
+            }
+        };
+
+        details.push(get_detail(&format!("Beatgrid Marker {}", index + 1), value));
+    }
+
+    details
+}
+
+fn get_autotags_details(data: &[u8]) -> Vec<Detail> {
+    let mut fields = data.get(VERSION_LENGTH_IN_BYTES..).unwrap_or_default();
+    let mut details: Vec<Detail> = vec![];
+
+    for field_name in AUTOTAGS_FIELD_NAMES {
+        let (value, remaining) = take_null_terminated_string(fields);
+        fields = remaining;
+
+        if !value.is_empty() {
+            details.push(get_detail(field_name, value));
+        }
+    }
+
+    details
+}
+
+fn get_overview_details(data: &[u8]) -> Vec<Detail> {
+    let block_count =
+        data.len().saturating_sub(VERSION_LENGTH_IN_BYTES) / OVERVIEW_BLOCK_LENGTH_IN_BYTES;
+
+    vec![get_detail("Waveform", format!("{} blocks", block_count))]
+}
+
+fn get_version_details(data: &[u8]) -> Vec<Detail> {
+    match data.get(..VERSION_LENGTH_IN_BYTES) {
+        Some(version) => vec![get_detail(
+            "Version",
+            format!("{}.{}", version[0], version[1]),
+        )],
+        None => vec![],
+    }
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode_base64(data: &[u8]) -> Vec<u8> {
+        let alphabet = crate::dj_tags::BASE64_ALPHABET;
+        data.chunks(3)
+            .flat_map(|chunk| {
+                let mut bytes = [0u8; 3];
+                bytes[..chunk.len()].copy_from_slice(chunk);
+                let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+                (0..=chunk.len())
+                    .map(move |index| alphabet[(value >> (18 - index * 6)) as usize & 0x3F])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn return_hot_cues_loops_and_colour_from_markers_2_data() {
+        let mut payload = vec![0x01, 0x01];
+        payload.extend(b"COLOR\0");
+        payload.extend(4u32.to_be_bytes());
+        payload.extend([0x00, 0xFF, 0x99, 0xFF]);
+        payload.extend(b"CUE\0");
+        payload.extend(17u32.to_be_bytes());
+        payload.extend([0x00, 0x00]);
+        payload.extend(83456u32.to_be_bytes());
+        payload.extend([0x00, 0xCC, 0x00, 0x00, 0x00, 0x00]);
+        payload.extend(b"Drop\0");
+        payload.extend(b"LOOP\0");
+        payload.extend(23u32.to_be_bytes());
+        payload.extend([0x00, 0x01]);
+        payload.extend(1000u32.to_be_bytes());
+        payload.extend(5000u32.to_be_bytes());
+        payload.extend([0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x27, 0xAA, 0xE1, 0x01]);
+        payload.extend(b"Vox\0");
+        payload.push(0x00);
+
+        let mut data = vec![0x01, 0x01];
+        data.extend(encode_base64(&payload));
+        data.push(0x00);
+
+        let result = get_details(MARKERS_2_NAME, &data);
+        assert_eq!(result[0].value, "#FF99FF");
+        assert_eq!(result[1].key, "Hot Cue 1");
+        assert_eq!(result[1].value, "01:23.456 #CC0000 Drop");
+        assert_eq!(result[2].key, "Saved Loop 2");
+        assert_eq!(result[2].value, "00:01.000 - 00:05.000 Vox (Locked)");
+    }
+
+    #[test]
+    fn return_bpm_and_markers_from_beatgrid_data() {
+        let mut data = vec![0x01, 0x00];
+        data.extend(2u32.to_be_bytes());
+        data.extend(0.5f32.to_be_bytes());
+        data.extend(64u32.to_be_bytes());
+        data.extend(30.5f32.to_be_bytes());
+        data.extend(128.0f32.to_be_bytes());
+        data.push(0x00);
+
+        let result = get_details(BEATGRID_NAME, &data);
+        assert_eq!(result[0].value, "128.00");
+        assert_eq!(result[1].value, "00:00.500 for 64 beats");
+        assert_eq!(result[2].value, "00:30.500 at 128.00 BPM");
+    }
+
+    #[test]
+    fn return_bpm_and_gain_from_autotags_data() {
+        let data = b"\x01\x01115.00\x00-3.257\x000.000\x00";
+        let result = get_details(AUTOTAGS_NAME, data);
+        assert_eq!(result[0].key, "BPM");
+        assert_eq!(result[0].value, "115.00");
+        assert_eq!(result[2].value, "0.000");
+    }
+}
@@ -0,0 +1,198 @@
+use crate::dj_tags::format_position;
+use crate::formating::KeyValuePair as Detail;
+
+pub const PRIV_OWNER: &str = "TRAKTOR4";
+
+const FRAME_ID_LENGTH_IN_BYTES: usize = 4;
+const FRAME_HEADER_LENGTH_IN_BYTES: usize = 12;
+const CUE_POINTS_FRAME_ID: &str = "CUEP";
+const NO_HOT_CUE: i32 = -1;
+
+const CUE_TYPES: [(u32, &str); 6] = [
+    (0, "Cue"),
+    (1, "Fade-in"),
+    (2, "Fade-out"),
+    (3, "Load"),
+    (4, "Grid"),
+    (5, "Loop"),
+];
+
+struct CueReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> CueReader<'a> {
+    fn take(&mut self, length: usize) -> Option<&'a [u8]> {
+        if self.data.len() < length {
+            return None;
+        }
+
+        let (taken, remaining) = self.data.split_at(length);
+        self.data = remaining;
+        Some(taken)
+    }
+
+    fn take_u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn take_i32(&mut self) -> Option<i32> {
+        Some(i32::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn take_f64(&mut self) -> Option<f64> {
+        Some(f64::from_le_bytes(self.take(8)?.try_into().ok()?))
+    }
+}
+
+pub fn get_details(data: &[u8]) -> Vec<Detail> {
+    let mut frame_ids: Vec<String> = vec![];
+    let mut cue_details: Vec<Detail> = vec![];
+
+    walk_frames(data, &mut frame_ids, &mut cue_details);
+
+    let mut details = vec![Detail {
+        key: "Frames".to_string(),
+        spacer: String::new(),
+        value: frame_ids.join(", "),
+    }];
+    details.extend(cue_details);
+
+    details
+}
+
+fn walk_frames(data: &[u8], frame_ids: &mut Vec<String>, cue_details: &mut Vec<Detail>) {
+    let mut frame_lists: Vec<&[u8]> = vec![data];
+
+    while let Some(remaining_data) = frame_lists.last_mut() {
+        let data: &[u8] = remaining_data;
+        if data.len() < FRAME_HEADER_LENGTH_IN_BYTES {
+            frame_lists.pop();
+            continue;
+        }
+
+        let frame_id: String = data[..FRAME_ID_LENGTH_IN_BYTES]
+            .iter()
+            .rev()
+            .map(|byte| *byte as char)
+            .collect();
+        let frame_size = u32::from_le_bytes([data[4], data[5], data[6], data[7]]) as usize;
+        let child_count = u32::from_le_bytes([data[8], data[9], data[10], data[11]]);
+
+        let frame_end = (FRAME_ID_LENGTH_IN_BYTES + 4 + frame_size).min(data.len());
+        let frame_data = &data[FRAME_HEADER_LENGTH_IN_BYTES.min(frame_end)..frame_end];
+        *remaining_data = &data[frame_end..];
+
+        frame_ids.push(frame_id.clone());
+
+        if child_count > 0 {
+            frame_lists.push(frame_data);
+        } else if frame_id == CUE_POINTS_FRAME_ID {
+            cue_details.extend(get_cue_details(frame_data).unwrap_or_default());
+        }
+    }
+}
+
+fn get_cue_details(data: &[u8]) -> Option<Vec<Detail>> {
+    let mut reader = CueReader { data };
+    let cue_count = reader.take_u32()?;
+    let mut details: Vec<Detail> = vec![];
+
+    for index in 0..cue_count {
+        reader.take_u32()?;
+        let name_length = reader.take_u32()? as usize;
+        let name_units: Vec<u16> = reader
+            .take(name_length * 2)?
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect();
+        let name = String::from_utf16_lossy(&name_units);
+        reader.take_u32()?;
+        let cue_type = reader.take_u32()?;
+        let start = reader.take_f64()?;
+        let length = reader.take_f64()?;
+        reader.take_u32()?;
+        let hot_cue = reader.take_i32()?;
+
+        let key = match hot_cue {
+            NO_HOT_CUE => format!("Cue {}", index + 1),
+            _ => format!("Hot Cue {}", hot_cue + 1),
+        };
+
+        let mut value = format!("{} {}", format_position(start), get_cue_type_name(cue_type));
+        if length > 0.0 {
+            value.push_str(&format!(" to {}", format_position(start + length)));
+        }
+        if !name.is_empty() {
+            value.push_str(&format!(" {}", name));
+        }
+
+        details.push(Detail {
+            key,
+            spacer: String::new(),
+            value,
+        });
+    }
+
+    Some(details)
+}
+
+fn get_cue_type_name(cue_type: u32) -> String {
+    match CUE_TYPES.iter().find(|(id, _)| *id == cue_type) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", cue_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_frame(id: &[u8; 4], child_count: u32, data: &[u8]) -> Vec<u8> {
+        let mut frame: Vec<u8> = id.iter().rev().copied().collect();
+        frame.extend((data.len() as u32 + 4).to_le_bytes());
+        frame.extend(child_count.to_le_bytes());
+        frame.extend(data);
+        frame
+    }
+
+    #[test]
+    fn return_frames_and_cue_points_from_valid_data() {
+        let mut cue_points = 1u32.to_le_bytes().to_vec();
+        cue_points.extend(1u32.to_le_bytes());
+        cue_points.extend(3u32.to_le_bytes());
+        cue_points.extend([b'I', 0x00, b'n', 0x00, b't', 0x00]);
+        cue_points.extend(0u32.to_le_bytes());
+        cue_points.extend(5u32.to_le_bytes());
+        cue_points.extend(1000.0f64.to_le_bytes());
+        cue_points.extend(4000.0f64.to_le_bytes());
+        cue_points.extend(0u32.to_le_bytes());
+        cue_points.extend(2i32.to_le_bytes());
+
+        let data_frame = get_frame(b"DATA", 1, &get_frame(b"CUEP", 0, &cue_points));
+        let data = get_frame(b"TRMD", 1, &data_frame);
+
+        let result = get_details(&data);
+        assert_eq!(result[0].value, "TRMD, DATA, CUEP");
+        assert_eq!(result[1].key, "Hot Cue 3");
+        assert_eq!(result[1].value, "00:01.000 Loop to 00:05.000 Int");
+    }
+
+    #[test]
+    fn return_frames_from_deeply_nested_data_without_recursing() {
+        let nesting_depth = 100_000;
+        let cue_points_frame = get_frame(b"CUEP", 0, &[]);
+        let mut data: Vec<u8> = vec![];
+        for level in 0..nesting_depth {
+            let children_length = (nesting_depth - level - 1) * FRAME_HEADER_LENGTH_IN_BYTES;
+            data.extend(b"ATAD");
+            data.extend(((children_length + cue_points_frame.len() + 4) as u32).to_le_bytes());
+            data.extend(1u32.to_le_bytes());
+        }
+        data.extend(cue_points_frame);
+
+        let result = get_details(&data);
+        assert!(result[0].value.starts_with("DATA, DATA"));
+        assert!(result[0].value.ends_with("DATA, CUEP"));
+    }
+}
@@ -0,0 +1,181 @@
+mod colh;
+mod dlid;
+mod lins;
+mod ptbl;
+mod vers;
+mod wsmp;
+mod wvpl;
+
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_number_of_bytes_as_string, Endian,
+};
+use crate::chunks::{
+    extra, list, CHUNK_ID_FIELD_LENGTH_IN_BYTES, CHUNK_SIZE_FIELD_LENGTH_IN_BYTES,
+};
+use crate::errors::LocalError;
+use crate::formating::add_one_if_byte_size_is_odd;
+use crate::output::OutputEntry;
+use std::error::Error;
+
+pub const DLS_FORM_TYPE_ID: &str = "DLS ";
+const LIST_TYPE_LENGTH_IN_BYTES: usize = 4;
+const COLLECTION_HEADER_CHUNK_ID: &str = "colh";
+const VERSION_CHUNK_ID: &str = "vers";
+const DLS_ID_CHUNK_ID: &str = "dlid";
+const POOL_TABLE_CHUNK_ID: &str = "ptbl";
+const LIST_CHUNK_ID: &str = "LIST";
+const INSTRUMENT_LIST_TYPE_ID: &str = "lins";
+const WAVE_POOL_LIST_TYPE_ID: &str = "wvpl";
+const INFO_NAME_CHUNK_ID: &str = "INAM";
+const MANDATORY_CHUNKS: [&str; 2] = [COLLECTION_HEADER_CHUNK_ID, VERSION_CHUNK_ID];
+
+#[derive(Debug, PartialEq)]
+pub struct DlsChunk {
+    pub id: String,
+    pub data: Vec<u8>,
+}
+
+pub fn get_metadata_from_dls_data(
+    mut dls_data: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+
+    for chunk in get_chunks_from_dls_data(&mut dls_data)? {
+        if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk.id.as_str()) {
+            continue;
+        }
+
+        output.push(get_dls_chunk_metadata(chunk)?);
+    }
+
+    Ok(output)
+}
+
+pub fn get_chunks_from_dls_data(dls_data: &mut Vec<u8>) -> Result<Vec<DlsChunk>, LocalError> {
+    let mut chunks: Vec<DlsChunk> = vec![];
+
+    while dls_data.len() >= CHUNK_ID_FIELD_LENGTH_IN_BYTES + CHUNK_SIZE_FIELD_LENGTH_IN_BYTES {
+        let id = take_first_number_of_bytes_as_string(dls_data, CHUNK_ID_FIELD_LENGTH_IN_BYTES)?;
+        let chunk_size = take_first_four_bytes_as_unsigned_integer(dls_data, Endian::Little)?;
+
+        let data_size = (chunk_size as usize).min(dls_data.len());
+        let data = take_first_number_of_bytes(dls_data, data_size)?;
+
+        let padding_size = (add_one_if_byte_size_is_odd(chunk_size) - chunk_size) as usize;
+        skip_over_bytes(dls_data, padding_size.min(dls_data.len()))?;
+
+        chunks.push(DlsChunk { id, data });
+    }
+
+    Ok(chunks)
+}
+
+pub fn get_list_type_and_chunks_from_list_data(
+    list_data: &mut Vec<u8>,
+) -> Result<(String, Vec<DlsChunk>), LocalError> {
+    let list_type = take_first_number_of_bytes_as_string(list_data, LIST_TYPE_LENGTH_IN_BYTES)?;
+    let chunks = get_chunks_from_dls_data(list_data)?;
+
+    Ok((list_type, chunks))
+}
+
+pub fn get_name_from_info_chunks(info_chunks: Vec<DlsChunk>) -> Result<String, LocalError> {
+    let name = match info_chunks
+        .into_iter()
+        .find(|chunk| chunk.id == INFO_NAME_CHUNK_ID)
+    {
+        Some(mut chunk) => {
+            let name_length = chunk.data.len();
+            take_first_number_of_bytes_as_string(&mut chunk.data, name_length)?
+        }
+        None => String::new(),
+    };
+
+    Ok(name.trim().to_string())
+}
+
+fn get_dls_chunk_metadata(chunk: DlsChunk) -> Result<OutputEntry, Box<dyn Error>> {
+    let result = match chunk.id.as_str() {
+        COLLECTION_HEADER_CHUNK_ID => colh::get_metadata(chunk.data)?,
+        VERSION_CHUNK_ID => vers::get_metadata(chunk.data)?,
+        DLS_ID_CHUNK_ID => dlid::get_metadata(chunk.data)?,
+        POOL_TABLE_CHUNK_ID => ptbl::get_metadata(chunk.data)?,
+        LIST_CHUNK_ID => get_list_chunk_metadata(chunk.data)?,
+        _ => extra::get_metadata(chunk.id, chunk.data)?,
+    };
+
+    Ok(result)
+}
+
+fn get_list_chunk_metadata(mut list_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let list_type_bytes: Vec<u8> = list_data
+        .iter()
+        .take(LIST_TYPE_LENGTH_IN_BYTES)
+        .copied()
+        .collect();
+
+    let result = match String::from_utf8_lossy(&list_type_bytes).as_ref() {
+        INSTRUMENT_LIST_TYPE_ID => {
+            let (_, instrument_chunks) = get_list_type_and_chunks_from_list_data(&mut list_data)?;
+            lins::get_metadata(instrument_chunks)?
+        }
+        WAVE_POOL_LIST_TYPE_ID => {
+            let (_, wave_chunks) = get_list_type_and_chunks_from_list_data(&mut list_data)?;
+            wvpl::get_metadata(wave_chunks)?
+        }
+        _ => list::get_metadata(list_data)?,
+    };
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_chunks_from_dls_data_including_odd_sized_chunks() {
+        let mut test_data: Vec<u8> = vec![
+            b'c', b'o', b'l', b'h', 0x04, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, // colh
+            b'I', b'N', b'A', b'M', 0x03, 0x00, 0x00, 0x00, b'P', b'n', b'o', 0x00, // padded
+            b'v', b'e', b'r', b's', 0x00, 0x00, 0x00, 0x00, // empty
+        ];
+        let correct_result = vec![
+            DlsChunk {
+                id: "colh".to_string(),
+                data: vec![0x02, 0x00, 0x00, 0x00],
+            },
+            DlsChunk {
+                id: "INAM".to_string(),
+                data: vec![b'P', b'n', b'o'],
+            },
+            DlsChunk {
+                id: "vers".to_string(),
+                data: vec![],
+            },
+        ];
+        let result = get_chunks_from_dls_data(&mut test_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_truncated_chunk_data_when_chunk_size_exceeds_the_remaining_bytes() {
+        let mut test_data: Vec<u8> =
+            vec![b'c', b'o', b'l', b'h', 0x10, 0x00, 0x00, 0x00, 0x02, 0x00];
+        let result = get_chunks_from_dls_data(&mut test_data).unwrap();
+        assert_eq!(result[0].data, vec![0x02, 0x00]);
+    }
+
+    #[test]
+    fn return_instrument_name_from_info_list_data() {
+        let mut test_data: Vec<u8> = vec![
+            b'I', b'N', b'F', b'O', b'I', b'C', b'O', b'P', 0x02, 0x00, 0x00, 0x00, b'X', 0x00,
+            b'I', b'N', b'A', b'M', 0x06, 0x00, 0x00, 0x00, b'P', b'i', b'a', b'n', b'o', 0x00,
+        ];
+        let (_, info_chunks) = get_list_type_and_chunks_from_list_data(&mut test_data).unwrap();
+        let result = get_name_from_info_chunks(info_chunks).unwrap();
+        assert_eq!(result, "Piano");
+    }
+}
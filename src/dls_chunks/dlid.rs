@@ -0,0 +1,27 @@
+use crate::byte_arrays::take_first_number_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+use uuid::Uuid;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dls_chunks/dlid.tmpl");
+const DLS_ID_LENGTH_IN_BYTES: usize = 16;
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let id_bytes = take_first_number_of_bytes(&mut chunk_data, DLS_ID_LENGTH_IN_BYTES)?;
+
+    let mut id_array: [u8; DLS_ID_LENGTH_IN_BYTES] = Default::default();
+    id_array.copy_from_slice(id_bytes.as_slice());
+
+    let output_values: Value = upon::value! {
+        dls_id: Uuid::from_bytes_le(id_array).hyphenated().to_string(),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
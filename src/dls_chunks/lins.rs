@@ -0,0 +1,269 @@
+use crate::byte_arrays::{
+    take_first_four_bytes_as_unsigned_integer, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::dls_chunks::wsmp::{get_wave_sample_from_bytes, WaveSample};
+use crate::dls_chunks::{
+    get_list_type_and_chunks_from_list_data, get_name_from_info_chunks, DlsChunk,
+};
+use crate::errors::LocalError;
+use crate::formating::format_midi_note_number_as_note_name;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dls_chunks/lins.tmpl");
+const LIST_CHUNK_ID: &str = "LIST";
+const INSTRUMENT_LIST_TYPE_ID: &str = "ins ";
+const INSTRUMENT_HEADER_CHUNK_ID: &str = "insh";
+const REGION_LIST_TYPE_ID: &str = "lrgn";
+const REGION_TYPE_IDS: [&str; 2] = ["rgn ", "rgn2"];
+const REGION_HEADER_CHUNK_ID: &str = "rgnh";
+const WAVE_SAMPLE_CHUNK_ID: &str = "wsmp";
+const WAVE_LINK_CHUNK_ID: &str = "wlnk";
+const ARTICULATION_LIST_TYPE_IDS: [&str; 2] = ["lart", "lar2"];
+const INFO_LIST_TYPE_ID: &str = "INFO";
+const DRUM_KIT_BANK_FLAG: u32 = 0x8000_0000;
+const REGION_HEADER_LENGTH_WITH_LAYER_IN_BYTES: usize = 14;
+const SELF_NON_EXCLUSIVE_OPTION_FLAG: u16 = 1;
+const MASTER_WAVE_LINK_OPTION_FLAG: u16 = 1;
+
+#[derive(Debug, Default, Serialize)]
+struct Instrument {
+    name: String,
+    bank_msb: u32,
+    bank_lsb: u32,
+    is_drum_kit: bool,
+    program: u32,
+    number_of_regions: u32,
+    articulation_blocks: u32,
+    regions: Vec<Region>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Region {
+    key_range: String,
+    velocity_range: String,
+    self_non_exclusive: bool,
+    key_group: u16,
+    layer: u16,
+    wave_pool_index: u32,
+    channel: u32,
+    phase_group: u16,
+    is_phase_master: bool,
+    articulation_blocks: u32,
+    wave_sample: WaveSample,
+}
+
+pub fn get_metadata(instrument_chunks: Vec<DlsChunk>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut instruments: Vec<Instrument> = vec![];
+
+    for mut chunk in instrument_chunks {
+        if chunk.id != LIST_CHUNK_ID {
+            continue;
+        }
+
+        let (list_type, sub_chunks) = get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+        if list_type == INSTRUMENT_LIST_TYPE_ID {
+            instruments.push(get_instrument_from_chunks(sub_chunks)?);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        number_of_instruments: instruments.len(),
+        instruments: instruments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_instrument_from_chunks(instrument_chunks: Vec<DlsChunk>) -> Result<Instrument, LocalError> {
+    let mut instrument: Instrument = Default::default();
+
+    for mut chunk in instrument_chunks {
+        match chunk.id.as_str() {
+            INSTRUMENT_HEADER_CHUNK_ID => {
+                set_instrument_header_fields(&mut instrument, chunk.data)?
+            }
+            LIST_CHUNK_ID => {
+                let (list_type, sub_chunks) =
+                    get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+
+                match list_type.as_str() {
+                    REGION_LIST_TYPE_ID => {
+                        instrument.regions = get_regions_from_chunks(sub_chunks)?
+                    }
+                    INFO_LIST_TYPE_ID => instrument.name = get_name_from_info_chunks(sub_chunks)?,
+                    list_type if ARTICULATION_LIST_TYPE_IDS.contains(&list_type) => {
+                        instrument.articulation_blocks = get_articulation_block_count(sub_chunks)?
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(instrument)
+}
+
+fn set_instrument_header_fields(
+    instrument: &mut Instrument,
+    mut chunk_data: Vec<u8>,
+) -> Result<(), LocalError> {
+    instrument.number_of_regions =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let bank = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    instrument.program =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    let (bank_msb, bank_lsb, is_drum_kit) = get_bank_select_from_bank_field(bank);
+    instrument.bank_msb = bank_msb;
+    instrument.bank_lsb = bank_lsb;
+    instrument.is_drum_kit = is_drum_kit;
+
+    Ok(())
+}
+
+fn get_bank_select_from_bank_field(bank: u32) -> (u32, u32, bool) {
+    let bank_msb = (bank >> 8) & 0x7F;
+    let bank_lsb = bank & 0x7F;
+    let is_drum_kit = bank & DRUM_KIT_BANK_FLAG > 0;
+
+    (bank_msb, bank_lsb, is_drum_kit)
+}
+
+fn get_regions_from_chunks(region_chunks: Vec<DlsChunk>) -> Result<Vec<Region>, LocalError> {
+    let mut regions: Vec<Region> = vec![];
+
+    for mut chunk in region_chunks {
+        if chunk.id != LIST_CHUNK_ID {
+            continue;
+        }
+
+        let (list_type, sub_chunks) = get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+        if REGION_TYPE_IDS.contains(&list_type.as_str()) {
+            regions.push(get_region_from_chunks(sub_chunks)?);
+        }
+    }
+
+    Ok(regions)
+}
+
+fn get_region_from_chunks(region_chunks: Vec<DlsChunk>) -> Result<Region, LocalError> {
+    let mut region: Region = Default::default();
+
+    for mut chunk in region_chunks {
+        match chunk.id.as_str() {
+            REGION_HEADER_CHUNK_ID => set_region_header_fields(&mut region, chunk.data)?,
+            WAVE_SAMPLE_CHUNK_ID => region.wave_sample = get_wave_sample_from_bytes(chunk.data)?,
+            WAVE_LINK_CHUNK_ID => set_wave_link_fields(&mut region, chunk.data)?,
+            LIST_CHUNK_ID => {
+                let (list_type, sub_chunks) =
+                    get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+                if ARTICULATION_LIST_TYPE_IDS.contains(&list_type.as_str()) {
+                    region.articulation_blocks = get_articulation_block_count(sub_chunks)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(region)
+}
+
+fn set_region_header_fields(
+    region: &mut Region,
+    mut chunk_data: Vec<u8>,
+) -> Result<(), LocalError> {
+    let has_layer_field = chunk_data.len() >= REGION_HEADER_LENGTH_WITH_LAYER_IN_BYTES;
+
+    let key_low = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let key_high = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let velocity_low = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let velocity_high = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let options = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    region.key_group = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    if has_layer_field {
+        region.layer = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    }
+
+    region.key_range = format!(
+        "{} - {}",
+        format_midi_note_number_as_note_name(key_low as u32),
+        format_midi_note_number_as_note_name(key_high as u32)
+    );
+    region.velocity_range = format!("{} - {}", velocity_low, velocity_high);
+    region.self_non_exclusive = options & SELF_NON_EXCLUSIVE_OPTION_FLAG > 0;
+
+    Ok(())
+}
+
+fn set_wave_link_fields(region: &mut Region, mut chunk_data: Vec<u8>) -> Result<(), LocalError> {
+    let options = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    region.phase_group = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    region.channel = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    region.wave_pool_index =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    region.is_phase_master = options & MASTER_WAVE_LINK_OPTION_FLAG > 0;
+
+    Ok(())
+}
+
+fn get_articulation_block_count(articulation_chunks: Vec<DlsChunk>) -> Result<u32, LocalError> {
+    let mut connection_blocks: u32 = 0;
+
+    for mut chunk in articulation_chunks {
+        let _structure_size =
+            take_first_four_bytes_as_unsigned_integer(&mut chunk.data, Endian::Little)?;
+        connection_blocks +=
+            take_first_four_bytes_as_unsigned_integer(&mut chunk.data, Endian::Little)?;
+    }
+
+    Ok(connection_blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_bank_select_values_from_melodic_bank_field() {
+        let correct_result = (121, 3, false);
+        let result = get_bank_select_from_bank_field(0x0000_7903);
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_drum_kit_flag_from_percussion_bank_field() {
+        let correct_result = (0, 0, true);
+        let result = get_bank_select_from_bank_field(DRUM_KIT_BANK_FLAG);
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_correct_region_header_fields_from_dls2_region_header() {
+        let mut region: Region = Default::default();
+        let test_bytes: Vec<u8> = vec![
+            0x24, 0x00, 0x30, 0x00, // Key range: 36 - 48
+            0x00, 0x00, 0x7F, 0x00, // Velocity range: 0 - 127
+            0x01, 0x00, // Options: self non-exclusive
+            0x02, 0x00, // Key group: 2
+            0x01, 0x00, // Layer: 1
+        ];
+        set_region_header_fields(&mut region, test_bytes).unwrap();
+
+        assert_eq!(region.key_range, "C1 - C2");
+        assert_eq!(region.velocity_range, "0 - 127");
+        assert!(region.self_non_exclusive);
+        assert_eq!(region.key_group, 2);
+        assert_eq!(region.layer, 1);
+    }
+}
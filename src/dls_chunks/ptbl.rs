@@ -0,0 +1,25 @@
+use crate::byte_arrays::{take_first_four_bytes_as_unsigned_integer, Endian};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dls_chunks/ptbl.tmpl");
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let _structure_size =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let number_of_cues =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    let output_values: Value = upon::value! {
+        number_of_cues: number_of_cues,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
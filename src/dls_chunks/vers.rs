@@ -0,0 +1,47 @@
+use crate::byte_arrays::{take_first_four_bytes_as_unsigned_integer, Endian};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dls_chunks/vers.tmpl");
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let version_most_significant =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let version_least_significant =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    let output_values: Value = upon::value! {
+        version: format_version(version_most_significant, version_least_significant),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn format_version(most_significant: u32, least_significant: u32) -> String {
+    format!(
+        "{}.{}.{}.{}",
+        most_significant >> 16,
+        most_significant & 0xFFFF,
+        least_significant >> 16,
+        least_significant & 0xFFFF
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correctly_formated_version_from_version_fields() {
+        let correct_result = "2.1.0.14".to_string();
+        let result = format_version(0x0002_0001, 0x0000_000E);
+        assert_eq!(result, correct_result);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_signed_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_two_bytes_as_signed_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::format_midi_note_number_as_note_name;
+use serde::Serialize;
+
+const WAVE_SAMPLE_FIXED_FIELDS_LENGTH_IN_BYTES: u32 = 20;
+const WAVE_SAMPLE_LOOP_FIXED_FIELDS_LENGTH_IN_BYTES: u32 = 16;
+const NO_TRUNCATION_OPTION_FLAG: u32 = 1;
+const NO_COMPRESSION_OPTION_FLAG: u32 = 2;
+const RELATIVE_GAIN_UNITS_PER_DECIBEL: f64 = 655360.0;
+const LOOP_TYPE_FORWARD: &str = "Forward";
+const LOOP_TYPE_RELEASE: &str = "Loop and Release";
+const LOOP_TYPE_UNKNOWN: &str = "Unknown";
+
+#[derive(Debug, Default, Serialize)]
+pub struct WaveSample {
+    pub unity_note: String,
+    pub fine_tune: i16,
+    pub attenuation: String,
+    pub no_truncation: bool,
+    pub no_compression: bool,
+    pub loops: Vec<WaveSampleLoop>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct WaveSampleLoop {
+    pub loop_type: String,
+    pub start: u32,
+    pub length: u32,
+}
+
+pub fn get_wave_sample_from_bytes(mut chunk_data: Vec<u8>) -> Result<WaveSample, LocalError> {
+    let structure_size =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let unity_note = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let fine_tune = take_first_two_bytes_as_signed_integer(&mut chunk_data, Endian::Little)?;
+    let attenuation = take_first_four_bytes_as_signed_integer(&mut chunk_data, Endian::Little)?;
+    let options = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let number_of_loops =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    skip_over_bytes(
+        &mut chunk_data,
+        structure_size.saturating_sub(WAVE_SAMPLE_FIXED_FIELDS_LENGTH_IN_BYTES) as usize,
+    )?;
+
+    let mut loops: Vec<WaveSampleLoop> = vec![];
+    for _ in 0..number_of_loops {
+        loops.push(get_wave_sample_loop_from_bytes(&mut chunk_data)?);
+    }
+
+    Ok(WaveSample {
+        unity_note: format_midi_note_number_as_note_name(unity_note as u32),
+        fine_tune,
+        attenuation: format!(
+            "{:.2}",
+            attenuation as f64 / RELATIVE_GAIN_UNITS_PER_DECIBEL
+        ),
+        no_truncation: options & NO_TRUNCATION_OPTION_FLAG > 0,
+        no_compression: options & NO_COMPRESSION_OPTION_FLAG > 0,
+        loops,
+    })
+}
+
+fn get_wave_sample_loop_from_bytes(chunk_data: &mut Vec<u8>) -> Result<WaveSampleLoop, LocalError> {
+    let loop_size = take_first_four_bytes_as_unsigned_integer(chunk_data, Endian::Little)?;
+    let loop_type = take_first_four_bytes_as_unsigned_integer(chunk_data, Endian::Little)?;
+    let start = take_first_four_bytes_as_unsigned_integer(chunk_data, Endian::Little)?;
+    let length = take_first_four_bytes_as_unsigned_integer(chunk_data, Endian::Little)?;
+
+    skip_over_bytes(
+        chunk_data,
+        loop_size.saturating_sub(WAVE_SAMPLE_LOOP_FIXED_FIELDS_LENGTH_IN_BYTES) as usize,
+    )?;
+
+    let loop_type = match loop_type {
+        0 => LOOP_TYPE_FORWARD,
+        1 => LOOP_TYPE_RELEASE,
+        _ => LOOP_TYPE_UNKNOWN,
+    };
+
+    Ok(WaveSampleLoop {
+        loop_type: loop_type.to_string(),
+        start,
+        length,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_wave_sample_from_valid_bytes_with_one_loop() {
+        let test_bytes: Vec<u8> = vec![
+            0x14, 0x00, 0x00, 0x00, // Structure size: 20
+            0x3C, 0x00, // Unity note: 60
+            0xFB, 0xFF, // Fine tune: -5
+            0x00, 0x00, 0xF6, 0xFF, // Attenuation: -655360
+            0x03, 0x00, 0x00, 0x00, // Options: no truncation & no compression
+            0x01, 0x00, 0x00, 0x00, // Number of loops: 1
+            0x10, 0x00, 0x00, 0x00, // Loop size: 16
+            0x00, 0x00, 0x00, 0x00, // Loop type: forward
+            0x20, 0x00, 0x00, 0x00, // Loop start: 32
+            0x40, 0x00, 0x00, 0x00, // Loop length: 64
+        ];
+        let result = get_wave_sample_from_bytes(test_bytes).unwrap();
+
+        assert_eq!(result.unity_note, "C3");
+        assert_eq!(result.fine_tune, -5);
+        assert_eq!(result.attenuation, "-1.00");
+        assert!(result.no_truncation);
+        assert!(result.no_compression);
+        assert_eq!(
+            result.loops,
+            vec![WaveSampleLoop {
+                loop_type: LOOP_TYPE_FORWARD.to_string(),
+                start: 32,
+                length: 64,
+            }]
+        );
+    }
+}
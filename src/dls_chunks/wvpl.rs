@@ -0,0 +1,103 @@
+use crate::byte_arrays::{
+    take_first_four_bytes_as_unsigned_integer, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::chunks::fmt::get_format_name_from_format_id;
+use crate::dls_chunks::wsmp::{get_wave_sample_from_bytes, WaveSample};
+use crate::dls_chunks::{
+    get_list_type_and_chunks_from_list_data, get_name_from_info_chunks, DlsChunk,
+};
+use crate::errors::LocalError;
+use crate::formating::format_file_size_as_string;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dls_chunks/wvpl.tmpl");
+const LIST_CHUNK_ID: &str = "LIST";
+const WAVE_LIST_TYPE_ID: &str = "wave";
+const FORMAT_CHUNK_ID: &str = "fmt ";
+const DATA_CHUNK_ID: &str = "data";
+const WAVE_SAMPLE_CHUNK_ID: &str = "wsmp";
+const INFO_LIST_TYPE_ID: &str = "INFO";
+
+#[derive(Debug, Default, Serialize)]
+struct Wave {
+    index: usize,
+    name: String,
+    format: String,
+    channels: u16,
+    sample_rate: f64,
+    bits_per_sample: u16,
+    data_size: String,
+    wave_sample: WaveSample,
+}
+
+pub fn get_metadata(wave_pool_chunks: Vec<DlsChunk>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut waves: Vec<Wave> = vec![];
+
+    for mut chunk in wave_pool_chunks {
+        if chunk.id != LIST_CHUNK_ID {
+            continue;
+        }
+
+        let (list_type, sub_chunks) = get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+        if list_type == WAVE_LIST_TYPE_ID {
+            let mut wave = get_wave_from_chunks(sub_chunks)?;
+            wave.index = waves.len();
+            waves.push(wave);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        number_of_waves: waves.len(),
+        waves: waves,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_wave_from_chunks(wave_chunks: Vec<DlsChunk>) -> Result<Wave, LocalError> {
+    let mut wave: Wave = Default::default();
+
+    for mut chunk in wave_chunks {
+        match chunk.id.as_str() {
+            FORMAT_CHUNK_ID => set_format_fields(&mut wave, chunk.data)?,
+            DATA_CHUNK_ID => wave.data_size = format_file_size_as_string(chunk.data.len() as u64),
+            WAVE_SAMPLE_CHUNK_ID => wave.wave_sample = get_wave_sample_from_bytes(chunk.data)?,
+            LIST_CHUNK_ID => {
+                let (list_type, sub_chunks) =
+                    get_list_type_and_chunks_from_list_data(&mut chunk.data)?;
+                if list_type == INFO_LIST_TYPE_ID {
+                    wave.name = get_name_from_info_chunks(sub_chunks)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(wave)
+}
+
+fn set_format_fields(wave: &mut Wave, mut chunk_data: Vec<u8>) -> Result<(), LocalError> {
+    let format_id = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    wave.channels = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let sample_rate = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let _average_data_rate =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let _data_block_size =
+        take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    wave.bits_per_sample =
+        take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    wave.format = get_format_name_from_format_id(format_id);
+    wave.sample_rate = sample_rate as f64 / 1000.0;
+
+    Ok(())
+}
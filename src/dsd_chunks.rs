@@ -0,0 +1,110 @@
+mod comt;
+mod diin;
+pub mod dsd;
+pub mod fmt;
+mod fver;
+mod prop;
+
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_eight_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_number_of_bytes_as_string, Endian,
+};
+use crate::chunks::{extra, id3, CHUNK_ID_FIELD_LENGTH_IN_BYTES};
+use crate::errors::LocalError;
+use crate::output::OutputEntry;
+use std::error::Error;
+
+pub const DSDIFF_CHUNK_SIZE_FIELD_LENGTH_IN_BYTES: usize = 8;
+pub const FORMAT_VERSION_CHUNK_ID: &str = "fver";
+pub const PROPERTY_CHUNK_ID: &str = "prop";
+pub const EDITED_MASTER_INFORMATION_CHUNK_ID: &str = "diin";
+pub const COMMENTS_CHUNK_ID: &str = "comt";
+pub const ID3_CHUNK_ID: &str = "id3 ";
+const DSD_SAMPLE_RATE_NAMES: [(u32, &str); 4] = [
+    (2_822_400, "DSD64"),
+    (5_644_800, "DSD128"),
+    (11_289_600, "DSD256"),
+    (22_579_200, "DSD512"),
+];
+
+#[derive(Debug, PartialEq)]
+pub struct DsdChunk {
+    pub id: String,
+    pub data: Vec<u8>,
+}
+
+pub fn get_dsdiff_chunk_metadata(
+    chunk_id: String,
+    chunk_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let result = match chunk_id.as_str() {
+        FORMAT_VERSION_CHUNK_ID => fver::get_metadata(chunk_data)?,
+        PROPERTY_CHUNK_ID => prop::get_metadata(chunk_data)?,
+        EDITED_MASTER_INFORMATION_CHUNK_ID => diin::get_metadata(chunk_data)?,
+        COMMENTS_CHUNK_ID => comt::get_metadata(chunk_data)?,
+        ID3_CHUNK_ID => id3::get_metadata_from_chunk(chunk_id, chunk_data)?,
+        _ => extra::get_metadata(chunk_id, chunk_data)?,
+    };
+
+    Ok(result)
+}
+
+pub fn get_chunks_from_dsdiff_data(dsdiff_data: &mut Vec<u8>) -> Result<Vec<DsdChunk>, LocalError> {
+    let mut chunks: Vec<DsdChunk> = vec![];
+
+    while dsdiff_data.len()
+        >= CHUNK_ID_FIELD_LENGTH_IN_BYTES + DSDIFF_CHUNK_SIZE_FIELD_LENGTH_IN_BYTES
+    {
+        let id = take_first_number_of_bytes_as_string(dsdiff_data, CHUNK_ID_FIELD_LENGTH_IN_BYTES)?;
+        let chunk_size = take_first_eight_bytes_as_unsigned_integer(dsdiff_data, Endian::Big)?;
+
+        let data_size = (chunk_size as usize).min(dsdiff_data.len());
+        let data = take_first_number_of_bytes(dsdiff_data, data_size)?;
+
+        let padding_size = data_size % 2;
+        skip_over_bytes(dsdiff_data, padding_size.min(dsdiff_data.len()))?;
+
+        chunks.push(DsdChunk { id, data });
+    }
+
+    Ok(chunks)
+}
+
+pub fn format_dsd_sample_rate(sample_rate: u32) -> String {
+    let sample_rate_in_mhz = sample_rate as f64 / 1_000_000.0;
+
+    match DSD_SAMPLE_RATE_NAMES
+        .iter()
+        .find(|(rate, _)| *rate == sample_rate)
+    {
+        Some((_, name)) => format!("{} MHz ({})", sample_rate_in_mhz, name),
+        None => format!("{} MHz", sample_rate_in_mhz),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_chunks_from_dsdiff_data_including_odd_sized_chunks() {
+        let mut test_data: Vec<u8> = vec![
+            b'F', b'S', b' ', b' ', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x2B,
+            0x11, 0x00, b'E', b'M', b'I', b'D', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            b'x', 0x00, b'L', b'S', b'C', b'O', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+            0x00, 0x00,
+        ];
+        let result = get_chunks_from_dsdiff_data(&mut test_data).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].id, "FS  ");
+        assert_eq!(result[0].data, vec![0x00, 0x2B, 0x11, 0x00]);
+        assert_eq!(result[1].data, vec![b'x']);
+        assert_eq!(result[2].id, "LSCO");
+    }
+
+    #[test]
+    fn return_correct_sample_rate_names_for_dsd_sample_rates() {
+        assert_eq!(format_dsd_sample_rate(2_822_400), "2.8224 MHz (DSD64)");
+        assert_eq!(format_dsd_sample_rate(3_072_000), "3.072 MHz");
+    }
+}
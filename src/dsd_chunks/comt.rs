@@ -0,0 +1,133 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes_as_string, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/comt.tmpl");
+const GENERAL_COMMENT_TYPE: u16 = 0;
+const CHANNEL_COMMENT_TYPE: u16 = 1;
+const SOUND_SOURCE_COMMENT_TYPE: u16 = 2;
+const FILE_HISTORY_COMMENT_TYPE: u16 = 3;
+const SOUND_SOURCES: [&str; 3] = ["DSD recording", "Analogue recording", "PCM recording"];
+const FILE_HISTORY_REFERENCES: [&str; 5] = [
+    "General",
+    "Operator Name",
+    "Create Machine",
+    "Time Zone",
+    "Revision",
+];
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Comment {
+    timestamp: String,
+    comment_type: String,
+    text: String,
+}
+
+pub fn get_metadata(chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let comments = get_comments_from_bytes(chunk_data)?;
+
+    let output_values: Value = upon::value! {
+        comments: comments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_comments_from_bytes(mut chunk_data: Vec<u8>) -> Result<Vec<Comment>, LocalError> {
+    let number_of_comments =
+        take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let mut comments: Vec<Comment> = vec![];
+
+    for _ in 0..number_of_comments {
+        let year = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+        let month = take_first_byte(&mut chunk_data)?;
+        let day = take_first_byte(&mut chunk_data)?;
+        let hour = take_first_byte(&mut chunk_data)?;
+        let minutes = take_first_byte(&mut chunk_data)?;
+        let comment_type = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+        let comment_reference =
+            take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+        let text_length = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+        let text = take_first_number_of_bytes_as_string(&mut chunk_data, text_length as usize)?;
+        let padding_size = ((text_length % 2) as usize).min(chunk_data.len());
+        skip_over_bytes(&mut chunk_data, padding_size)?;
+
+        comments.push(Comment {
+            timestamp: format!(
+                "{:04}-{:02}-{:02} {:02}:{:02}",
+                year, month, day, hour, minutes
+            ),
+            comment_type: format_comment_type(comment_type, comment_reference),
+            text: text.trim().to_string(),
+        });
+    }
+
+    Ok(comments)
+}
+
+fn format_comment_type(comment_type: u16, comment_reference: u16) -> String {
+    match comment_type {
+        GENERAL_COMMENT_TYPE => "General".to_string(),
+        CHANNEL_COMMENT_TYPE if comment_reference == 0 => "Channel (All)".to_string(),
+        CHANNEL_COMMENT_TYPE => format!("Channel ({})", comment_reference),
+        SOUND_SOURCE_COMMENT_TYPE => format!(
+            "Sound Source ({})",
+            get_reference_name(&SOUND_SOURCES, comment_reference)
+        ),
+        FILE_HISTORY_COMMENT_TYPE => format!(
+            "File History ({})",
+            get_reference_name(&FILE_HISTORY_REFERENCES, comment_reference)
+        ),
+        _ => format!("Unknown ({})", comment_type),
+    }
+}
+
+fn get_reference_name(reference_names: &[&str], comment_reference: u16) -> String {
+    reference_names
+        .get(comment_reference as usize)
+        .map(|name| name.to_string())
+        .unwrap_or(comment_reference.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_comments_from_valid_bytes_with_odd_length_text() {
+        let mut chunk_data: Vec<u8> = vec![0x00, 0x02];
+        chunk_data.extend([0x07, 0xE8, 0x05, 0x11, 0x0D, 0x2A, 0x00, 0x03, 0x00, 0x01]);
+        chunk_data.extend(3u32.to_be_bytes());
+        chunk_data.extend(b"Bob\0");
+        chunk_data.extend([0x07, 0xE8, 0x05, 0x11, 0x0D, 0x2A, 0x00, 0x00, 0x00, 0x00]);
+        chunk_data.extend(2u32.to_be_bytes());
+        chunk_data.extend(b"Hi");
+
+        let correct_result = vec![
+            Comment {
+                timestamp: "2024-05-17 13:42".to_string(),
+                comment_type: "File History (Operator Name)".to_string(),
+                text: "Bob".to_string(),
+            },
+            Comment {
+                timestamp: "2024-05-17 13:42".to_string(),
+                comment_type: "General".to_string(),
+                text: "Hi".to_string(),
+            },
+        ];
+        let result = get_comments_from_bytes(chunk_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+}
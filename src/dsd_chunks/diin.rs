@@ -0,0 +1,136 @@
+use crate::byte_arrays::{
+    take_first_byte, take_first_four_bytes_as_signed_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes_as_string,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::dsd_chunks::get_chunks_from_dsdiff_data;
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/diin.tmpl");
+const EDITED_MASTER_ID_CHUNK_ID: &str = "EMID";
+const MARKER_CHUNK_ID: &str = "MARK";
+const ARTIST_CHUNK_ID: &str = "DIAR";
+const TITLE_CHUNK_ID: &str = "DITI";
+const MARKER_TYPES: [(u16, &str); 5] = [
+    (0, "Track Start"),
+    (1, "Track Stop"),
+    (2, "Program Start"),
+    (3, "Obsolete"),
+    (4, "Index"),
+];
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Marker {
+    time: String,
+    offset: i32,
+    marker_type: String,
+    channel: String,
+    track_flags: u16,
+    text: String,
+}
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut edited_master_id = String::new();
+    let mut artist = String::new();
+    let mut title = String::new();
+    let mut markers: Vec<Marker> = vec![];
+
+    for chunk in get_chunks_from_dsdiff_data(&mut chunk_data)? {
+        let mut data = chunk.data;
+        let data_length = data.len();
+
+        match chunk.id.as_str() {
+            EDITED_MASTER_ID_CHUNK_ID => {
+                edited_master_id = take_first_number_of_bytes_as_string(&mut data, data_length)?
+            }
+            MARKER_CHUNK_ID => markers.push(get_marker_from_bytes(data)?),
+            ARTIST_CHUNK_ID => artist = get_counted_text_from_bytes(data)?,
+            TITLE_CHUNK_ID => title = get_counted_text_from_bytes(data)?,
+            _ => {}
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        edited_master_id: edited_master_id,
+        artist: artist,
+        title: title,
+        markers: markers,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_marker_from_bytes(mut marker_data: Vec<u8>) -> Result<Marker, LocalError> {
+    let hours = take_first_two_bytes_as_unsigned_integer(&mut marker_data, Endian::Big)?;
+    let minutes = take_first_byte(&mut marker_data)?;
+    let seconds = take_first_byte(&mut marker_data)?;
+    let samples = take_first_four_bytes_as_unsigned_integer(&mut marker_data, Endian::Big)?;
+    let offset = take_first_four_bytes_as_signed_integer(&mut marker_data, Endian::Big)?;
+    let marker_type = take_first_two_bytes_as_unsigned_integer(&mut marker_data, Endian::Big)?;
+    let channel = take_first_two_bytes_as_unsigned_integer(&mut marker_data, Endian::Big)?;
+    let track_flags = take_first_two_bytes_as_unsigned_integer(&mut marker_data, Endian::Big)?;
+    let text = get_counted_text_from_bytes(marker_data)?;
+
+    Ok(Marker {
+        time: format!("{}h:{}m:{}s & {} samples", hours, minutes, seconds, samples),
+        offset,
+        marker_type: get_marker_type_name(marker_type),
+        channel: match channel {
+            0 => "All".to_string(),
+            channel => channel.to_string(),
+        },
+        track_flags,
+        text,
+    })
+}
+
+fn get_counted_text_from_bytes(mut text_data: Vec<u8>) -> Result<String, LocalError> {
+    let text_length = take_first_four_bytes_as_unsigned_integer(&mut text_data, Endian::Big)?;
+    let available_text_length = (text_length as usize).min(text_data.len());
+    let text = take_first_number_of_bytes_as_string(&mut text_data, available_text_length)?;
+
+    Ok(text.trim().to_string())
+}
+
+fn get_marker_type_name(marker_type: u16) -> String {
+    MARKER_TYPES
+        .iter()
+        .find(|(id, _)| *id == marker_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", marker_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_marker_from_valid_bytes() {
+        let mut marker_data: Vec<u8> = vec![0x00, 0x01, 0x02, 0x03, 0x00, 0x00, 0x00, 0x04];
+        marker_data.extend((-5i32).to_be_bytes());
+        marker_data.extend([0x00, 0x00, 0x00, 0x00, 0x00, 0x01]);
+        marker_data.extend(3u32.to_be_bytes());
+        marker_data.extend(b"One");
+
+        let correct_result = Marker {
+            time: "1h:2m:3s & 4 samples".to_string(),
+            offset: -5,
+            marker_type: "Track Start".to_string(),
+            channel: "All".to_string(),
+            track_flags: 1,
+            text: "One".to_string(),
+        };
+        let result = get_marker_from_bytes(marker_data).unwrap();
+        assert_eq!(result, correct_result);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::byte_arrays::{take_first_eight_bytes_as_unsigned_integer, Endian};
+use crate::formating::format_file_size_as_string;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/dsd.tmpl");
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let total_file_size =
+        take_first_eight_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let metadata_pointer =
+        take_first_eight_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    let output_values: Value = upon::value! {
+        total_file_size: format_file_size_as_string(total_file_size),
+        metadata_pointer: metadata_pointer,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+pub fn get_metadata_pointer_from_bytes(chunk_data: &[u8]) -> u64 {
+    let mut pointer_bytes: Vec<u8> = chunk_data.iter().skip(8).take(8).copied().collect();
+    take_first_eight_bytes_as_unsigned_integer(&mut pointer_bytes, Endian::Little).unwrap_or(0)
+}
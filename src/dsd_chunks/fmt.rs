@@ -0,0 +1,87 @@
+use crate::byte_arrays::{
+    take_first_eight_bytes_as_unsigned_integer, take_first_four_bytes_as_unsigned_integer, Endian,
+};
+use crate::dsd_chunks::format_dsd_sample_rate;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/fmt.tmpl");
+const DSD_RAW_FORMAT_ID: u32 = 0;
+const CHANNEL_TYPES: [(u32, &str); 7] = [
+    (1, "Mono"),
+    (2, "Stereo"),
+    (3, "3 Channels"),
+    (4, "Quad"),
+    (5, "4 Channels"),
+    (6, "5 Channels"),
+    (7, "5.1 Channels"),
+];
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let format_version =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let format_id = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let channel_type = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let channel_count = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let sampling_frequency =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let bits_per_sample =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let sample_count = take_first_eight_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+    let block_size_per_channel =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Little)?;
+
+    let format = if format_id == DSD_RAW_FORMAT_ID {
+        "DSD Raw".to_string()
+    } else {
+        format!("Unknown ({})", format_id)
+    };
+
+    let output_values: Value = upon::value! {
+        format_version: format_version,
+        format: format,
+        channel_type: get_channel_type_name(channel_type),
+        channel_count: channel_count,
+        sampling_frequency: format_dsd_sample_rate(sampling_frequency),
+        bits_per_sample: bits_per_sample,
+        sample_count: sample_count,
+        block_size_per_channel: block_size_per_channel,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_channel_type_name(channel_type: u32) -> String {
+    CHANNEL_TYPES
+        .iter()
+        .find(|(id, _)| *id == channel_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", channel_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_fmt_chunk_details_from_valid_bytes() {
+        let mut chunk_data: Vec<u8> = vec![];
+        for value in [1u32, 0, 2, 2, 2_822_400, 1] {
+            chunk_data.extend(value.to_le_bytes());
+        }
+        chunk_data.extend(28_224_000u64.to_le_bytes());
+        chunk_data.extend(4096u32.to_le_bytes());
+        chunk_data.extend(0u32.to_le_bytes());
+
+        let correct_result_text = "------------------\nFmt Chunk Details:\n------------------\nFormat Version:        1\nFormat:                DSD Raw\nChannel Type:          Stereo\nChannel Count:         2\nSampling Frequency:    2.8224 MHz (DSD64)\nBits per Sample:       1\nSample Count:          28224000\nBlock Size:            4096 bytes per channel\n";
+        let result = get_metadata(chunk_data).unwrap();
+        assert_eq!(result.text, correct_result_text);
+    }
+}
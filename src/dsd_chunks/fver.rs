@@ -0,0 +1,26 @@
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/fver.tmpl");
+const VERSION_LENGTH_IN_BYTES: usize = 4;
+
+pub fn get_metadata(chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let version: Vec<String> = chunk_data
+        .iter()
+        .take(VERSION_LENGTH_IN_BYTES)
+        .map(|byte| byte.to_string())
+        .collect();
+
+    let output_values: Value = upon::value! {
+        version: version.join("."),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
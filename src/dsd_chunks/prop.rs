@@ -0,0 +1,181 @@
+use crate::byte_arrays::{
+    take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes_as_string, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::dsd_chunks::{format_dsd_sample_rate, get_chunks_from_dsdiff_data};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/dsd_chunks/prop.tmpl");
+const PROPERTY_TYPE_LENGTH_IN_BYTES: usize = 4;
+const SAMPLE_RATE_CHUNK_ID: &str = "FS";
+const CHANNELS_CHUNK_ID: &str = "CHNL";
+const COMPRESSION_TYPE_CHUNK_ID: &str = "CMPR";
+const ABSOLUTE_START_TIME_CHUNK_ID: &str = "ABSS";
+const LOUDSPEAKER_CONFIGURATION_CHUNK_ID: &str = "LSCO";
+const CHANNEL_ID_LENGTH_IN_BYTES: usize = 4;
+const COMPRESSION_TYPE_LENGTH_IN_BYTES: usize = 4;
+const LOUDSPEAKER_CONFIGURATIONS: [(u16, &str); 4] = [
+    (0, "2-channel stereo"),
+    (3, "5-channel (ITU-R BS.775-1)"),
+    (4, "6-channel (5.1, ITU-R BS.775-1 plus LFE)"),
+    (65535, "Undefined"),
+];
+
+#[derive(Debug, Default, Serialize)]
+struct SoundProperties {
+    property_type: String,
+    sample_rate: String,
+    number_of_channels: u16,
+    channel_ids: String,
+    compression_type: String,
+    compression_name: String,
+    absolute_start_time: String,
+    loudspeaker_configuration: String,
+}
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut properties = SoundProperties {
+        property_type: take_first_number_of_bytes_as_string(
+            &mut chunk_data,
+            PROPERTY_TYPE_LENGTH_IN_BYTES,
+        )?
+        .trim()
+        .to_string(),
+        ..Default::default()
+    };
+
+    for chunk in get_chunks_from_dsdiff_data(&mut chunk_data)? {
+        let mut data = chunk.data;
+
+        match chunk.id.trim() {
+            SAMPLE_RATE_CHUNK_ID => {
+                properties.sample_rate = format_dsd_sample_rate(
+                    take_first_four_bytes_as_unsigned_integer(&mut data, Endian::Big)?,
+                )
+            }
+            CHANNELS_CHUNK_ID => set_channel_fields(&mut properties, data)?,
+            COMPRESSION_TYPE_CHUNK_ID => set_compression_fields(&mut properties, data)?,
+            ABSOLUTE_START_TIME_CHUNK_ID => {
+                properties.absolute_start_time = format_absolute_start_time(data)?
+            }
+            LOUDSPEAKER_CONFIGURATION_CHUNK_ID => {
+                properties.loudspeaker_configuration = get_loudspeaker_configuration_name(
+                    take_first_two_bytes_as_unsigned_integer(&mut data, Endian::Big)?,
+                )
+            }
+            _ => {}
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        properties: properties,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn set_channel_fields(
+    properties: &mut SoundProperties,
+    mut chunk_data: Vec<u8>,
+) -> Result<(), LocalError> {
+    properties.number_of_channels =
+        take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+
+    let mut channel_ids: Vec<String> = vec![];
+    for _ in 0..properties.number_of_channels {
+        channel_ids.push(
+            take_first_number_of_bytes_as_string(&mut chunk_data, CHANNEL_ID_LENGTH_IN_BYTES)?
+                .trim()
+                .to_string(),
+        );
+    }
+    properties.channel_ids = channel_ids.join(", ");
+
+    Ok(())
+}
+
+fn set_compression_fields(
+    properties: &mut SoundProperties,
+    mut chunk_data: Vec<u8>,
+) -> Result<(), LocalError> {
+    properties.compression_type =
+        take_first_number_of_bytes_as_string(&mut chunk_data, COMPRESSION_TYPE_LENGTH_IN_BYTES)?
+            .trim()
+            .to_string();
+
+    let name_length = (take_first_byte(&mut chunk_data)? as usize).min(chunk_data.len());
+    properties.compression_name =
+        take_first_number_of_bytes_as_string(&mut chunk_data, name_length)?;
+
+    Ok(())
+}
+
+fn format_absolute_start_time(mut chunk_data: Vec<u8>) -> Result<String, LocalError> {
+    let hours = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let minutes = take_first_byte(&mut chunk_data)?;
+    let seconds = take_first_byte(&mut chunk_data)?;
+    let samples = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+
+    Ok(format!(
+        "{}h:{}m:{}s & {} samples",
+        hours, minutes, seconds, samples
+    ))
+}
+
+fn get_loudspeaker_configuration_name(configuration: u16) -> String {
+    LOUDSPEAKER_CONFIGURATIONS
+        .iter()
+        .find(|(id, _)| *id == configuration)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", configuration))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_sound_properties_from_valid_bytes() {
+        let mut chunk_data: Vec<u8> = b"SND ".to_vec();
+        chunk_data.extend(b"FS  ");
+        chunk_data.extend(4u64.to_be_bytes());
+        chunk_data.extend(2_822_400u32.to_be_bytes());
+        chunk_data.extend(b"CHNL");
+        chunk_data.extend(10u64.to_be_bytes());
+        chunk_data.extend([0x00, 0x02]);
+        chunk_data.extend(b"SLFTSRGT");
+        chunk_data.extend(b"CMPR");
+        chunk_data.extend(19u64.to_be_bytes());
+        chunk_data.extend(b"DSD ");
+        chunk_data.push(14);
+        chunk_data.extend(b"not compressed");
+        chunk_data.push(0x00);
+        chunk_data.extend(b"LSCO");
+        chunk_data.extend(2u64.to_be_bytes());
+        chunk_data.extend([0x00, 0x00]);
+
+        let result = get_metadata(chunk_data).unwrap();
+        assert!(result
+            .text
+            .contains("Sample Rate:           2.8224 MHz (DSD64)"));
+        assert!(result
+            .text
+            .contains("Channels:              2 (SLFT, SRGT)"));
+        assert!(result
+            .text
+            .contains("Compression:           DSD (not compressed)"));
+        assert!(result
+            .text
+            .contains("Loudspeaker Config:    2-channel stereo"));
+    }
+}
@@ -0,0 +1,248 @@
+pub mod attachments;
+pub mod chapters;
+pub mod header;
+pub mod info;
+pub mod tags;
+pub mod tracks;
+
+use crate::byte_arrays::{take_first_byte, take_first_number_of_bytes};
+use crate::errors::LocalError;
+use chrono::DateTime;
+
+pub const EBML_HEADER_ELEMENT_ID: u32 = 0x1A45DFA3;
+pub const SEGMENT_ELEMENT_ID: u32 = 0x18538067;
+pub const SEEK_HEAD_ELEMENT_ID: u32 = 0x114D9B74;
+pub const INFO_ELEMENT_ID: u32 = 0x1549A966;
+pub const TRACKS_ELEMENT_ID: u32 = 0x1654AE6B;
+pub const CLUSTER_ELEMENT_ID: u32 = 0x1F43B675;
+pub const CUES_ELEMENT_ID: u32 = 0x1C53BB6B;
+pub const ATTACHMENTS_ELEMENT_ID: u32 = 0x1941A469;
+pub const CHAPTERS_ELEMENT_ID: u32 = 0x1043A770;
+pub const TAGS_ELEMENT_ID: u32 = 0x1254C367;
+pub const VOID_ELEMENT_ID: u32 = 0xEC;
+const MAXIMUM_ELEMENT_ID_LENGTH_IN_BYTES: usize = 4;
+const MAXIMUM_ELEMENT_SIZE_LENGTH_IN_BYTES: usize = 8;
+const MATROSKA_EPOCH_OFFSET_IN_SECONDS: i64 = 978307200;
+const NANOSECONDS_PER_SECOND: i64 = 1_000_000_000;
+const NANOSECONDS_PER_MILLISECOND: u64 = 1_000_000;
+const BAD_TIMESTAMP_MESSAGE: &str = "Invalid Date";
+
+#[derive(Debug, PartialEq)]
+pub struct EbmlElement {
+    pub id: u32,
+    pub data: Vec<u8>,
+}
+
+pub fn get_variable_length_integer_length_from_first_byte(
+    first_byte: u8,
+    maximum_length_in_bytes: usize,
+) -> Result<usize, LocalError> {
+    let length = first_byte.leading_zeros() as usize + 1;
+
+    if length > maximum_length_in_bytes {
+        return Err(LocalError::InvalidEbmlVariableLengthInteger(first_byte));
+    }
+
+    Ok(length)
+}
+
+pub fn get_element_id_from_bytes(id_bytes: &[u8]) -> u32 {
+    id_bytes.iter().fold(0, |id, byte| (id << 8) | *byte as u32)
+}
+
+pub fn get_element_size_from_bytes(size_bytes: &[u8]) -> Option<u64> {
+    let length = size_bytes.len();
+    let value_bits = (7 * length) as u32;
+    let marker_mask: u64 = (1 << value_bits) - 1;
+
+    let size = size_bytes
+        .iter()
+        .fold(0, |size, byte| (size << 8) | *byte as u64)
+        & marker_mask;
+
+    if size == marker_mask {
+        return None;
+    }
+
+    Some(size)
+}
+
+pub fn get_element_id_length_from_first_byte(first_byte: u8) -> Result<usize, LocalError> {
+    get_variable_length_integer_length_from_first_byte(
+        first_byte,
+        MAXIMUM_ELEMENT_ID_LENGTH_IN_BYTES,
+    )
+}
+
+pub fn get_element_size_length_from_first_byte(first_byte: u8) -> Result<usize, LocalError> {
+    get_variable_length_integer_length_from_first_byte(
+        first_byte,
+        MAXIMUM_ELEMENT_SIZE_LENGTH_IN_BYTES,
+    )
+}
+
+pub fn get_child_elements_from_bytes(
+    mut element_data: Vec<u8>,
+) -> Result<Vec<EbmlElement>, LocalError> {
+    let mut elements: Vec<EbmlElement> = vec![];
+
+    while !element_data.is_empty() {
+        let first_id_byte = take_first_byte(&mut element_data)?;
+        let id_length = get_element_id_length_from_first_byte(first_id_byte)?;
+        let mut id_bytes = vec![first_id_byte];
+        id_bytes.extend(take_first_number_of_bytes(
+            &mut element_data,
+            id_length - 1,
+        )?);
+
+        let first_size_byte = take_first_byte(&mut element_data)?;
+        let size_length = get_element_size_length_from_first_byte(first_size_byte)?;
+        let mut size_bytes = vec![first_size_byte];
+        size_bytes.extend(take_first_number_of_bytes(
+            &mut element_data,
+            size_length - 1,
+        )?);
+
+        let data_length = match get_element_size_from_bytes(&size_bytes) {
+            Some(size) => (size as usize).min(element_data.len()),
+            None => element_data.len(),
+        };
+
+        elements.push(EbmlElement {
+            id: get_element_id_from_bytes(&id_bytes),
+            data: take_first_number_of_bytes(&mut element_data, data_length)?,
+        });
+    }
+
+    Ok(elements)
+}
+
+pub fn get_unsigned_integer_from_bytes(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(0, |value, byte| (value << 8) | *byte as u64)
+}
+
+pub fn format_uid(uid: u64) -> String {
+    format!("{:#X}", uid)
+}
+
+pub fn get_signed_integer_from_bytes(bytes: &[u8]) -> i64 {
+    if bytes.is_empty() {
+        return 0;
+    }
+
+    let unused_bits = 64 - (bytes.len().min(8) * 8) as u32;
+    ((get_unsigned_integer_from_bytes(bytes) << unused_bits) as i64) >> unused_bits
+}
+
+pub fn get_float_from_bytes(bytes: &[u8]) -> f64 {
+    match bytes.len() {
+        4 => f32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+        8 => f64::from_bits(get_unsigned_integer_from_bytes(bytes)),
+        _ => 0.0,
+    }
+}
+
+pub fn get_string_from_bytes(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+pub fn format_date_from_bytes(bytes: &[u8]) -> String {
+    let nanoseconds_since_2001 = get_signed_integer_from_bytes(bytes);
+    let seconds =
+        MATROSKA_EPOCH_OFFSET_IN_SECONDS + nanoseconds_since_2001 / NANOSECONDS_PER_SECOND;
+
+    match DateTime::from_timestamp(seconds, 0) {
+        Some(date) => date.to_string(),
+        None => BAD_TIMESTAMP_MESSAGE.to_string(),
+    }
+}
+
+pub fn format_nanoseconds_as_time(nanoseconds: u64) -> String {
+    let total_milliseconds = nanoseconds / NANOSECONDS_PER_MILLISECOND;
+    let milliseconds = total_milliseconds % 1000;
+    let total_seconds = total_milliseconds / 1000;
+
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+        milliseconds
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_variable_length_integer_lengths_from_first_bytes() {
+        assert_eq!(get_element_id_length_from_first_byte(0x1A).unwrap(), 4);
+        assert_eq!(get_element_id_length_from_first_byte(0xEC).unwrap(), 1);
+        assert_eq!(get_element_size_length_from_first_byte(0x01).unwrap(), 8);
+        assert_eq!(
+            get_element_id_length_from_first_byte(0x01),
+            Err(LocalError::InvalidEbmlVariableLengthInteger(0x01))
+        );
+    }
+
+    #[test]
+    fn return_correct_element_size_with_marker_bit_removed() {
+        assert_eq!(get_element_size_from_bytes(&[0x81]), Some(1));
+        assert_eq!(get_element_size_from_bytes(&[0x40, 0x02]), Some(2));
+        assert_eq!(get_element_size_from_bytes(&[0xFF]), None);
+        assert_eq!(
+            get_element_size_from_bytes(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            None
+        );
+    }
+
+    #[test]
+    fn return_correct_child_elements_from_valid_bytes() {
+        let test_bytes: Vec<u8> = vec![
+            0x42, 0x86, 0x81, 0x01, 0x42, 0x82, 0x84, b'w', b'e', b'b', b'm',
+        ];
+        let correct_result = vec![
+            EbmlElement {
+                id: 0x4286,
+                data: vec![0x01],
+            },
+            EbmlElement {
+                id: 0x4282,
+                data: b"webm".to_vec(),
+            },
+        ];
+
+        let result = get_child_elements_from_bytes(test_bytes).unwrap();
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_correct_signed_integer_from_negative_bytes() {
+        assert_eq!(get_signed_integer_from_bytes(&[0xFF, 0xFE]), -2);
+        assert_eq!(get_signed_integer_from_bytes(&[0x7F]), 127);
+    }
+
+    #[test]
+    fn return_correct_float_from_four_and_eight_byte_values() {
+        assert_eq!(get_float_from_bytes(&48000.0f32.to_be_bytes()), 48000.0);
+        assert_eq!(get_float_from_bytes(&44100.0f64.to_be_bytes()), 44100.0);
+    }
+
+    #[test]
+    fn return_correct_date_from_matroska_timestamp() {
+        assert_eq!(format_date_from_bytes(&[0; 8]), "2001-01-01 00:00:00 UTC");
+    }
+
+    #[test]
+    fn return_correct_time_string_from_nanoseconds() {
+        assert_eq!(
+            format_nanoseconds_as_time(3_723_456_000_000),
+            "01:02:03.456"
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use crate::ebml_elements::{
+    format_uid, get_child_elements_from_bytes, get_string_from_bytes,
+    get_unsigned_integer_from_bytes,
+};
+use crate::errors::LocalError;
+use crate::formating::format_file_size_as_string;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/attachments.tmpl");
+const ATTACHED_FILE_ELEMENT_ID: u32 = 0x61A7;
+const FILE_DESCRIPTION_ELEMENT_ID: u32 = 0x467E;
+const FILE_NAME_ELEMENT_ID: u32 = 0x466E;
+const FILE_MEDIA_TYPE_ELEMENT_ID: u32 = 0x4660;
+const FILE_DATA_ELEMENT_ID: u32 = 0x465C;
+const FILE_UID_ELEMENT_ID: u32 = 0x46AE;
+
+#[derive(Debug, Default, Serialize)]
+struct AttachedFile {
+    name: String,
+    description: String,
+    media_type: String,
+    size: String,
+    uid: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut attached_files: Vec<AttachedFile> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        if element.id == ATTACHED_FILE_ELEMENT_ID {
+            attached_files.push(get_attached_file_from_element_data(element.data)?);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        attached_files: attached_files,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_attached_file_from_element_data(element_data: Vec<u8>) -> Result<AttachedFile, LocalError> {
+    let mut attached_file: AttachedFile = Default::default();
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            FILE_NAME_ELEMENT_ID => attached_file.name = get_string_from_bytes(&element.data),
+            FILE_DESCRIPTION_ELEMENT_ID => {
+                attached_file.description = get_string_from_bytes(&element.data)
+            }
+            FILE_MEDIA_TYPE_ELEMENT_ID => {
+                attached_file.media_type = get_string_from_bytes(&element.data)
+            }
+            FILE_DATA_ELEMENT_ID => {
+                attached_file.size = format_file_size_as_string(element.data.len() as u64)
+            }
+            FILE_UID_ELEMENT_ID => {
+                attached_file.uid = format_uid(get_unsigned_integer_from_bytes(&element.data))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(attached_file)
+}
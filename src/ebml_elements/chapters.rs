@@ -0,0 +1,152 @@
+use crate::ebml_elements::{
+    format_nanoseconds_as_time, format_uid, get_child_elements_from_bytes, get_string_from_bytes,
+    get_unsigned_integer_from_bytes,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/chapters.tmpl");
+const EDITION_ENTRY_ELEMENT_ID: u32 = 0x45B9;
+const EDITION_UID_ELEMENT_ID: u32 = 0x45BC;
+const EDITION_FLAG_DEFAULT_ELEMENT_ID: u32 = 0x45DB;
+const EDITION_FLAG_ORDERED_ELEMENT_ID: u32 = 0x45DD;
+const CHAPTER_ATOM_ELEMENT_ID: u32 = 0xB6;
+const CHAPTER_UID_ELEMENT_ID: u32 = 0x73C4;
+const CHAPTER_TIME_START_ELEMENT_ID: u32 = 0x91;
+const CHAPTER_TIME_END_ELEMENT_ID: u32 = 0x92;
+const CHAPTER_FLAG_HIDDEN_ELEMENT_ID: u32 = 0x98;
+const CHAPTER_DISPLAY_ELEMENT_ID: u32 = 0x80;
+const CHAPTER_STRING_ELEMENT_ID: u32 = 0x85;
+const CHAPTER_LANGUAGE_ELEMENT_ID: u32 = 0x437C;
+const CHAPTER_ATOM_INDENT: &str = "  ";
+
+#[derive(Debug, Default, Serialize)]
+struct Edition {
+    uid: String,
+    is_default: bool,
+    is_ordered: bool,
+    chapters: Vec<Chapter>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Chapter {
+    indent: String,
+    uid: String,
+    start_time: String,
+    end_time: String,
+    is_hidden: bool,
+    title: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut editions: Vec<Edition> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        if element.id == EDITION_ENTRY_ELEMENT_ID {
+            editions.push(get_edition_from_element_data(element.data)?);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        editions: editions,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_edition_from_element_data(element_data: Vec<u8>) -> Result<Edition, LocalError> {
+    let mut edition: Edition = Default::default();
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            EDITION_UID_ELEMENT_ID => {
+                edition.uid = format_uid(get_unsigned_integer_from_bytes(&element.data))
+            }
+            EDITION_FLAG_DEFAULT_ELEMENT_ID => {
+                edition.is_default = get_unsigned_integer_from_bytes(&element.data) != 0
+            }
+            EDITION_FLAG_ORDERED_ELEMENT_ID => {
+                edition.is_ordered = get_unsigned_integer_from_bytes(&element.data) != 0
+            }
+            CHAPTER_ATOM_ELEMENT_ID => {
+                add_chapters_from_element_data(element.data, 1, &mut edition.chapters)?
+            }
+            _ => {}
+        }
+    }
+
+    Ok(edition)
+}
+
+fn add_chapters_from_element_data(
+    element_data: Vec<u8>,
+    depth: usize,
+    chapters: &mut Vec<Chapter>,
+) -> Result<(), LocalError> {
+    let mut chapter = Chapter {
+        indent: CHAPTER_ATOM_INDENT.repeat(depth),
+        ..Default::default()
+    };
+    let mut nested_chapters: Vec<Chapter> = vec![];
+    let mut titles: Vec<String> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            CHAPTER_UID_ELEMENT_ID => {
+                chapter.uid = format_uid(get_unsigned_integer_from_bytes(&element.data))
+            }
+            CHAPTER_TIME_START_ELEMENT_ID => {
+                chapter.start_time =
+                    format_nanoseconds_as_time(get_unsigned_integer_from_bytes(&element.data))
+            }
+            CHAPTER_TIME_END_ELEMENT_ID => {
+                chapter.end_time =
+                    format_nanoseconds_as_time(get_unsigned_integer_from_bytes(&element.data))
+            }
+            CHAPTER_FLAG_HIDDEN_ELEMENT_ID => {
+                chapter.is_hidden = get_unsigned_integer_from_bytes(&element.data) != 0
+            }
+            CHAPTER_DISPLAY_ELEMENT_ID => {
+                titles.push(get_chapter_title_from_element_data(element.data)?)
+            }
+            CHAPTER_ATOM_ELEMENT_ID => {
+                add_chapters_from_element_data(element.data, depth + 1, &mut nested_chapters)?
+            }
+            _ => {}
+        }
+    }
+
+    chapter.title = titles.join(" / ");
+    chapters.push(chapter);
+    chapters.extend(nested_chapters);
+
+    Ok(())
+}
+
+fn get_chapter_title_from_element_data(element_data: Vec<u8>) -> Result<String, LocalError> {
+    let mut title = String::new();
+    let mut languages: Vec<String> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            CHAPTER_STRING_ELEMENT_ID => title = get_string_from_bytes(&element.data),
+            CHAPTER_LANGUAGE_ELEMENT_ID => languages.push(get_string_from_bytes(&element.data)),
+            _ => {}
+        }
+    }
+
+    if languages.is_empty() {
+        return Ok(title);
+    }
+
+    Ok(format!("{} ({})", title, languages.join(", ")))
+}
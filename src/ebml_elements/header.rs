@@ -0,0 +1,70 @@
+use crate::ebml_elements::{
+    get_child_elements_from_bytes, get_string_from_bytes, get_unsigned_integer_from_bytes,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/header.tmpl");
+const EBML_VERSION_ELEMENT_ID: u32 = 0x4286;
+const EBML_READ_VERSION_ELEMENT_ID: u32 = 0x42F7;
+const EBML_MAX_ID_LENGTH_ELEMENT_ID: u32 = 0x42F2;
+const EBML_MAX_SIZE_LENGTH_ELEMENT_ID: u32 = 0x42F3;
+const DOC_TYPE_ELEMENT_ID: u32 = 0x4282;
+const DOC_TYPE_VERSION_ELEMENT_ID: u32 = 0x4287;
+const DOC_TYPE_READ_VERSION_ELEMENT_ID: u32 = 0x4285;
+
+#[derive(Debug, Default, Serialize)]
+struct EbmlHeader {
+    ebml_version: String,
+    ebml_read_version: String,
+    max_id_length: String,
+    max_size_length: String,
+    doc_type: String,
+    doc_type_version: String,
+    doc_type_read_version: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut header: EbmlHeader = Default::default();
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            EBML_VERSION_ELEMENT_ID => {
+                header.ebml_version = get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            EBML_READ_VERSION_ELEMENT_ID => {
+                header.ebml_read_version =
+                    get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            EBML_MAX_ID_LENGTH_ELEMENT_ID => {
+                header.max_id_length = get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            EBML_MAX_SIZE_LENGTH_ELEMENT_ID => {
+                header.max_size_length = get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            DOC_TYPE_ELEMENT_ID => header.doc_type = get_string_from_bytes(&element.data),
+            DOC_TYPE_VERSION_ELEMENT_ID => {
+                header.doc_type_version = get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            DOC_TYPE_READ_VERSION_ELEMENT_ID => {
+                header.doc_type_read_version =
+                    get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            _ => {}
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        header: header,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
@@ -0,0 +1,75 @@
+use crate::ebml_elements::{
+    format_date_from_bytes, format_nanoseconds_as_time, get_child_elements_from_bytes,
+    get_float_from_bytes, get_string_from_bytes, get_unsigned_integer_from_bytes,
+};
+use crate::formating::format_bytes_as_string_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/info.tmpl");
+const SEGMENT_UID_ELEMENT_ID: u32 = 0x73A4;
+const SEGMENT_FILENAME_ELEMENT_ID: u32 = 0x7384;
+const TIMESTAMP_SCALE_ELEMENT_ID: u32 = 0x2AD7B1;
+const DURATION_ELEMENT_ID: u32 = 0x4489;
+const DATE_UTC_ELEMENT_ID: u32 = 0x4461;
+const TITLE_ELEMENT_ID: u32 = 0x7BA9;
+const MUXING_APP_ELEMENT_ID: u32 = 0x4D80;
+const WRITING_APP_ELEMENT_ID: u32 = 0x5741;
+const DEFAULT_TIMESTAMP_SCALE_IN_NANOSECONDS: u64 = 1_000_000;
+
+#[derive(Debug, Default, Serialize)]
+struct SegmentInfo {
+    segment_uid: String,
+    segment_filename: String,
+    timestamp_scale: String,
+    duration: String,
+    date_utc: String,
+    title: String,
+    muxing_app: String,
+    writing_app: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut info: SegmentInfo = Default::default();
+    let mut timestamp_scale = DEFAULT_TIMESTAMP_SCALE_IN_NANOSECONDS;
+    let mut duration_in_ticks: Option<f64> = None;
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            SEGMENT_UID_ELEMENT_ID => {
+                info.segment_uid = format_bytes_as_string_of_bytes(&element.data)
+            }
+            SEGMENT_FILENAME_ELEMENT_ID => {
+                info.segment_filename = get_string_from_bytes(&element.data)
+            }
+            TIMESTAMP_SCALE_ELEMENT_ID => {
+                timestamp_scale = get_unsigned_integer_from_bytes(&element.data)
+            }
+            DURATION_ELEMENT_ID => duration_in_ticks = Some(get_float_from_bytes(&element.data)),
+            DATE_UTC_ELEMENT_ID => info.date_utc = format_date_from_bytes(&element.data),
+            TITLE_ELEMENT_ID => info.title = get_string_from_bytes(&element.data),
+            MUXING_APP_ELEMENT_ID => info.muxing_app = get_string_from_bytes(&element.data),
+            WRITING_APP_ELEMENT_ID => info.writing_app = get_string_from_bytes(&element.data),
+            _ => {}
+        }
+    }
+
+    if let Some(ticks) = duration_in_ticks {
+        info.duration = format_nanoseconds_as_time((ticks * timestamp_scale as f64) as u64);
+    }
+    info.timestamp_scale = timestamp_scale.to_string();
+
+    let output_values: Value = upon::value! {
+        info: info,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
@@ -0,0 +1,192 @@
+use crate::ebml_elements::{
+    format_uid, get_child_elements_from_bytes, get_string_from_bytes,
+    get_unsigned_integer_from_bytes,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/tags.tmpl");
+const TAG_ELEMENT_ID: u32 = 0x7373;
+const TARGETS_ELEMENT_ID: u32 = 0x63C0;
+const TARGET_TYPE_VALUE_ELEMENT_ID: u32 = 0x68CA;
+const TARGET_TYPE_ELEMENT_ID: u32 = 0x63CA;
+const TAG_TRACK_UID_ELEMENT_ID: u32 = 0x63C5;
+const TAG_EDITION_UID_ELEMENT_ID: u32 = 0x63C9;
+const TAG_CHAPTER_UID_ELEMENT_ID: u32 = 0x63C4;
+const TAG_ATTACHMENT_UID_ELEMENT_ID: u32 = 0x63C6;
+const SIMPLE_TAG_ELEMENT_ID: u32 = 0x67C8;
+const TAG_NAME_ELEMENT_ID: u32 = 0x45A3;
+const TAG_LANGUAGE_ELEMENT_ID: u32 = 0x447A;
+const TAG_STRING_ELEMENT_ID: u32 = 0x4487;
+const TAG_BINARY_ELEMENT_ID: u32 = 0x4485;
+const DEFAULT_TARGET_TYPE_VALUE: u64 = 50;
+const SIMPLE_TAG_INDENT: &str = "  ";
+
+#[derive(Debug, Default, Serialize)]
+struct Tag {
+    target: String,
+    simple_tags: Vec<SimpleTag>,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct SimpleTag {
+    indent: String,
+    name: String,
+    language: String,
+    value: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut tags: Vec<Tag> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        if element.id == TAG_ELEMENT_ID {
+            tags.push(get_tag_from_element_data(element.data)?);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        tags: tags,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_tag_from_element_data(element_data: Vec<u8>) -> Result<Tag, LocalError> {
+    let mut tag = Tag {
+        target: get_target_description(DEFAULT_TARGET_TYPE_VALUE, "", vec![]),
+        ..Default::default()
+    };
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            TARGETS_ELEMENT_ID => tag.target = get_target_from_element_data(element.data)?,
+            SIMPLE_TAG_ELEMENT_ID => {
+                add_simple_tags_from_element_data(element.data, 1, &mut tag.simple_tags)?
+            }
+            _ => {}
+        }
+    }
+
+    Ok(tag)
+}
+
+fn get_target_from_element_data(element_data: Vec<u8>) -> Result<String, LocalError> {
+    let mut target_type_value = DEFAULT_TARGET_TYPE_VALUE;
+    let mut target_type = String::new();
+    let mut target_uids: Vec<String> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        let uid = get_unsigned_integer_from_bytes(&element.data);
+        match element.id {
+            TARGET_TYPE_VALUE_ELEMENT_ID => target_type_value = uid,
+            TARGET_TYPE_ELEMENT_ID => target_type = get_string_from_bytes(&element.data),
+            TAG_TRACK_UID_ELEMENT_ID => target_uids.push(format!("Track UID {}", format_uid(uid))),
+            TAG_EDITION_UID_ELEMENT_ID => {
+                target_uids.push(format!("Edition UID {}", format_uid(uid)))
+            }
+            TAG_CHAPTER_UID_ELEMENT_ID => {
+                target_uids.push(format!("Chapter UID {}", format_uid(uid)))
+            }
+            TAG_ATTACHMENT_UID_ELEMENT_ID => {
+                target_uids.push(format!("Attachment UID {}", format_uid(uid)))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(get_target_description(
+        target_type_value,
+        &target_type,
+        target_uids,
+    ))
+}
+
+fn get_target_description(
+    target_type_value: u64,
+    target_type: &str,
+    target_uids: Vec<String>,
+) -> String {
+    let mut description = format!("Level {}", target_type_value);
+
+    if !target_type.is_empty() {
+        description = format!("{} {}", description, target_type);
+    }
+
+    if !target_uids.is_empty() {
+        description = format!("{} - {}", description, target_uids.join(", "));
+    }
+
+    description
+}
+
+fn add_simple_tags_from_element_data(
+    element_data: Vec<u8>,
+    depth: usize,
+    simple_tags: &mut Vec<SimpleTag>,
+) -> Result<(), LocalError> {
+    let mut simple_tag = SimpleTag {
+        indent: SIMPLE_TAG_INDENT.repeat(depth),
+        ..Default::default()
+    };
+    let mut nested_simple_tags: Vec<SimpleTag> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            TAG_NAME_ELEMENT_ID => simple_tag.name = get_string_from_bytes(&element.data),
+            TAG_LANGUAGE_ELEMENT_ID => simple_tag.language = get_string_from_bytes(&element.data),
+            TAG_STRING_ELEMENT_ID => simple_tag.value = get_string_from_bytes(&element.data),
+            TAG_BINARY_ELEMENT_ID => {
+                simple_tag.value = format!("[Binary Data: {} bytes]", element.data.len())
+            }
+            SIMPLE_TAG_ELEMENT_ID => {
+                add_simple_tags_from_element_data(element.data, depth + 1, &mut nested_simple_tags)?
+            }
+            _ => {}
+        }
+    }
+
+    simple_tags.push(simple_tag);
+    simple_tags.extend(nested_simple_tags);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_nested_simple_tags_in_order_with_increasing_indent() {
+        let test_bytes: Vec<u8> = vec![
+            0x45, 0xA3, 0x86, b'A', b'R', b'T', b'I', b'S', b'T', 0x44, 0x87, 0x82, b'M', b'e',
+            0x67, 0xC8, 0x8A, 0x45, 0xA3, 0x83, b'U', b'R', b'L', 0x44, 0x87, 0x81, b'x',
+        ];
+        let mut simple_tags: Vec<SimpleTag> = vec![];
+
+        add_simple_tags_from_element_data(test_bytes, 1, &mut simple_tags).unwrap();
+
+        assert_eq!(simple_tags.len(), 2);
+        assert_eq!(simple_tags[0].name, "ARTIST");
+        assert_eq!(simple_tags[0].value, "Me");
+        assert_eq!(simple_tags[0].indent, "  ");
+        assert_eq!(simple_tags[1].name, "URL");
+        assert_eq!(simple_tags[1].value, "x");
+        assert_eq!(simple_tags[1].indent, "    ");
+    }
+
+    #[test]
+    fn return_correct_target_description_with_type_and_uids() {
+        let result = get_target_description(30, "TRACK", vec!["Track UID 1".to_string()]);
+        assert_eq!(result, "Level 30 TRACK - Track UID 1");
+    }
+}
@@ -0,0 +1,279 @@
+use crate::chunks::fmt::get_format_name_from_format_id;
+use crate::ebml_elements::{
+    format_uid, get_child_elements_from_bytes, get_float_from_bytes, get_string_from_bytes,
+    get_unsigned_integer_from_bytes, EbmlElement,
+};
+use crate::errors::LocalError;
+use crate::formating::format_bytes_as_string_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ebml_elements/tracks.tmpl");
+const TRACK_ENTRY_ELEMENT_ID: u32 = 0xAE;
+const TRACK_NUMBER_ELEMENT_ID: u32 = 0xD7;
+const TRACK_UID_ELEMENT_ID: u32 = 0x73C5;
+const TRACK_TYPE_ELEMENT_ID: u32 = 0x83;
+const FLAG_DEFAULT_ELEMENT_ID: u32 = 0x88;
+const NAME_ELEMENT_ID: u32 = 0x536E;
+const LANGUAGE_ELEMENT_ID: u32 = 0x22B59C;
+const LANGUAGE_BCP47_ELEMENT_ID: u32 = 0x22B59D;
+const CODEC_ID_ELEMENT_ID: u32 = 0x86;
+const CODEC_PRIVATE_ELEMENT_ID: u32 = 0x63A2;
+const CODEC_NAME_ELEMENT_ID: u32 = 0x258688;
+const CODEC_DELAY_ELEMENT_ID: u32 = 0x56AA;
+const SEEK_PRE_ROLL_ELEMENT_ID: u32 = 0x56BB;
+const VIDEO_ELEMENT_ID: u32 = 0xE0;
+const PIXEL_WIDTH_ELEMENT_ID: u32 = 0xB0;
+const PIXEL_HEIGHT_ELEMENT_ID: u32 = 0xBA;
+const AUDIO_ELEMENT_ID: u32 = 0xE1;
+const SAMPLING_FREQUENCY_ELEMENT_ID: u32 = 0xB5;
+const OUTPUT_SAMPLING_FREQUENCY_ELEMENT_ID: u32 = 0x78B5;
+const CHANNELS_ELEMENT_ID: u32 = 0x9F;
+const BIT_DEPTH_ELEMENT_ID: u32 = 0x6264;
+const TRACK_TYPES: [(u64, &str); 8] = [
+    (1, "Video"),
+    (2, "Audio"),
+    (3, "Complex"),
+    (16, "Logo"),
+    (17, "Subtitle"),
+    (18, "Buttons"),
+    (32, "Control"),
+    (33, "Metadata"),
+];
+const UNKNOWN_TRACK_TYPE: &str = "Unknown";
+const CODEC_PRIVATE_SIGNATURES: [(&[u8], &str); 3] = [
+    (b"OpusHead", "Opus Identification Header"),
+    (b"fLaC", "FLAC Stream Info"),
+    (b"\x01vorbis", "Vorbis Headers"),
+];
+const ACM_CODEC_ID: &str = "A_MS/ACM";
+const XIPH_LACED_HEADERS_PACKET_COUNT: u8 = 2;
+const NUMBER_OF_CODEC_PRIVATE_BYTES_TO_DISPLAY: usize = 16;
+const NANOSECONDS_PER_MILLISECOND: f64 = 1_000_000.0;
+
+#[derive(Debug, Default, Serialize)]
+struct Track {
+    number: String,
+    uid: String,
+    track_type: String,
+    is_default: bool,
+    name: String,
+    language: String,
+    codec_id: String,
+    codec_name: String,
+    codec_private: String,
+    codec_delay: String,
+    seek_pre_roll: String,
+    pixel_width: String,
+    pixel_height: String,
+    sampling_frequency: f64,
+    output_sampling_frequency: f64,
+    channels: String,
+    bit_depth: String,
+}
+
+pub fn get_metadata(element_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut tracks: Vec<Track> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        if element.id == TRACK_ENTRY_ELEMENT_ID {
+            tracks.push(get_track_from_element_data(element.data)?);
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        tracks: tracks,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_track_from_element_data(element_data: Vec<u8>) -> Result<Track, LocalError> {
+    let mut track = Track {
+        is_default: true,
+        ..Default::default()
+    };
+    let mut codec_private: Vec<u8> = vec![];
+
+    for element in get_child_elements_from_bytes(element_data)? {
+        match element.id {
+            TRACK_NUMBER_ELEMENT_ID => {
+                track.number = get_unsigned_integer_from_bytes(&element.data).to_string()
+            }
+            TRACK_UID_ELEMENT_ID => {
+                track.uid = format_uid(get_unsigned_integer_from_bytes(&element.data))
+            }
+            TRACK_TYPE_ELEMENT_ID => {
+                track.track_type =
+                    get_track_type_name(get_unsigned_integer_from_bytes(&element.data))
+            }
+            FLAG_DEFAULT_ELEMENT_ID => {
+                track.is_default = get_unsigned_integer_from_bytes(&element.data) != 0
+            }
+            NAME_ELEMENT_ID => track.name = get_string_from_bytes(&element.data),
+            LANGUAGE_ELEMENT_ID | LANGUAGE_BCP47_ELEMENT_ID => {
+                track.language = get_string_from_bytes(&element.data)
+            }
+            CODEC_ID_ELEMENT_ID => track.codec_id = get_string_from_bytes(&element.data),
+            CODEC_NAME_ELEMENT_ID => track.codec_name = get_string_from_bytes(&element.data),
+            CODEC_PRIVATE_ELEMENT_ID => codec_private = element.data,
+            CODEC_DELAY_ELEMENT_ID => {
+                track.codec_delay = format_nanoseconds_as_milliseconds(&element.data)
+            }
+            SEEK_PRE_ROLL_ELEMENT_ID => {
+                track.seek_pre_roll = format_nanoseconds_as_milliseconds(&element.data)
+            }
+            VIDEO_ELEMENT_ID => set_video_fields(&mut track, element.data)?,
+            AUDIO_ELEMENT_ID => set_audio_fields(&mut track, element.data)?,
+            _ => {}
+        }
+    }
+
+    if !codec_private.is_empty() {
+        track.codec_private = get_codec_private_summary(&track.codec_id, &codec_private);
+    }
+
+    Ok(track)
+}
+
+fn set_video_fields(track: &mut Track, element_data: Vec<u8>) -> Result<(), LocalError> {
+    for EbmlElement { id, data } in get_child_elements_from_bytes(element_data)? {
+        match id {
+            PIXEL_WIDTH_ELEMENT_ID => {
+                track.pixel_width = get_unsigned_integer_from_bytes(&data).to_string()
+            }
+            PIXEL_HEIGHT_ELEMENT_ID => {
+                track.pixel_height = get_unsigned_integer_from_bytes(&data).to_string()
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn set_audio_fields(track: &mut Track, element_data: Vec<u8>) -> Result<(), LocalError> {
+    track.channels = "1".to_string();
+
+    for EbmlElement { id, data } in get_child_elements_from_bytes(element_data)? {
+        match id {
+            SAMPLING_FREQUENCY_ELEMENT_ID => {
+                track.sampling_frequency = get_float_from_bytes(&data) / 1000.0
+            }
+            OUTPUT_SAMPLING_FREQUENCY_ELEMENT_ID => {
+                track.output_sampling_frequency = get_float_from_bytes(&data) / 1000.0
+            }
+            CHANNELS_ELEMENT_ID => {
+                track.channels = get_unsigned_integer_from_bytes(&data).to_string()
+            }
+            BIT_DEPTH_ELEMENT_ID => {
+                track.bit_depth = get_unsigned_integer_from_bytes(&data).to_string()
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn get_track_type_name(track_type: u64) -> String {
+    TRACK_TYPES
+        .iter()
+        .find(|(id, _)| *id == track_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("{} ({})", UNKNOWN_TRACK_TYPE, track_type))
+}
+
+fn format_nanoseconds_as_milliseconds(bytes: &[u8]) -> String {
+    format!(
+        "{} ms",
+        get_unsigned_integer_from_bytes(bytes) as f64 / NANOSECONDS_PER_MILLISECOND
+    )
+}
+
+fn get_codec_private_summary(codec_id: &str, codec_private: &[u8]) -> String {
+    let size = codec_private.len();
+
+    if codec_id == ACM_CODEC_ID && size >= 2 {
+        let format_id = u16::from_le_bytes([codec_private[0], codec_private[1]]);
+        return format!(
+            "{} bytes - WAVEFORMATEX: {}",
+            size,
+            get_format_name_from_format_id(format_id)
+        );
+    }
+
+    if let Some((_, description)) = CODEC_PRIVATE_SIGNATURES
+        .iter()
+        .find(|(signature, _)| codec_private.starts_with(signature))
+    {
+        return format!("{} bytes - {}", size, description);
+    }
+
+    if codec_private.first() == Some(&XIPH_LACED_HEADERS_PACKET_COUNT)
+        && codec_private
+            .windows(7)
+            .any(|window| window == b"\x01vorbis")
+    {
+        return format!("{} bytes - Xiph Laced Vorbis Headers", size);
+    }
+
+    let preview_length = size.min(NUMBER_OF_CODEC_PRIVATE_BYTES_TO_DISPLAY);
+    format!(
+        "{} bytes - {}",
+        size,
+        format_bytes_as_string_of_bytes(&codec_private[..preview_length])
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_codec_private_summary_for_known_signatures() {
+        assert_eq!(
+            get_codec_private_summary("A_OPUS", b"OpusHead\x01\x02"),
+            "10 bytes - Opus Identification Header"
+        );
+        assert_eq!(
+            get_codec_private_summary("A_VORBIS", b"\x02\x1e\x10\x01vorbis"),
+            "10 bytes - Xiph Laced Vorbis Headers"
+        );
+        assert_eq!(
+            get_codec_private_summary("A_MS/ACM", &[0x01, 0x00, 0x02, 0x00]),
+            "4 bytes - WAVEFORMATEX: PCM"
+        );
+        assert_eq!(
+            get_codec_private_summary("A_AAC", &[0x12, 0x10]),
+            "2 bytes - 12 10"
+        );
+    }
+
+    #[test]
+    fn return_unknown_track_type_with_value_when_not_in_list() {
+        assert_eq!(get_track_type_name(2), "Audio");
+        assert_eq!(get_track_type_name(99), "Unknown (99)");
+    }
+
+    #[test]
+    fn return_track_uid_as_hex_when_it_exceeds_the_signed_integer_range() {
+        let mut track_entry = vec![0x73, 0xC5, 0x88];
+        track_entry.extend(0x9A3B6C2E11223344u64.to_be_bytes());
+        let mut element_data = vec![0xAE, 0x80 | track_entry.len() as u8];
+        element_data.extend(track_entry);
+
+        let result = get_metadata(element_data).unwrap();
+        assert!(result
+            .text
+            .contains("Track UID:             0x9A3B6C2E11223344"));
+    }
+}
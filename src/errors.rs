@@ -9,10 +9,10 @@ pub enum LocalError {
     #[error("Could not extract the filename from the supplied path")]
     InvalidFileName,
 
-    #[error("Unsupported file type: '{0}'.  Only Wave, AIFF, Flac, Ogg Vorbis, MP3, M4a, CAF and RMID or SWF MIDI files are supported")]
+    #[error("Unsupported file type: '{0}'.  Only Wave, AIFF, Flac, Ogg Vorbis, Ogg Opus, Ogg FLAC, Ogg Speex, Matroska/WebM, ASF/WMA, DSF/DSDIFF, WavPack, Monkey's Audio, Sun AU, IFF 8SVX, Tracker Modules (MOD, S3M, XM, IT), AAC (ADTS/LATM), AC-3/E-AC-3, MP3, MP4/M4A/MOV/3GP, CAF, DLS and RMID or SWF MIDI files are supported")]
     UnsupportedFileType(String),
 
-    #[error("Incorrect RIFF file type, file is not a valid RIFF WAVE or RMID file")]
+    #[error("Incorrect RIFF file type, file is not a valid RIFF WAVE, RMID or DLS file")]
     InvalidRiffTypeID,
 
     #[error("Could not read metadata from the file: {0}")]
@@ -39,12 +39,18 @@ pub enum LocalError {
     #[error("Provided Mac HFS Timestamp is too small. Not a valid date.")]
     HFSTimestampTooSmall,
 
-    #[error("Could not process the ID3 tag IDs")]
-    ErrorParsingID3TagIDs,
-
     #[error("Could not write out metadata.")]
     CouldNotWrteOutData,
 
+    #[error("Invalid Ogg page. The page does not start with the OggS capture pattern.")]
+    InvalidOggPageCapturePattern,
+
+    #[error("Invalid ASF file. Expected the Header Object but found object {0}.")]
+    InvalidAsfHeaderObject(String),
+
+    #[error("Invalid EBML variable length integer starting with byte {0:#04X}.")]
+    InvalidEbmlVariableLengthInteger(u8),
+
     #[error("Invalid Vorbis User Comment. No = character found.")]
     InvalidVorbisComment,
 
@@ -2,16 +2,28 @@ use crate::errors::LocalError;
 use crate::output::OutputEntry;
 use std::error::Error;
 
+pub mod aac;
+pub mod ac3;
 pub mod aiff;
+pub mod ape;
+pub mod asf;
+pub mod au;
 pub mod caf;
+pub mod dls;
+pub mod dsdiff;
+pub mod dsf;
 pub mod flac;
-pub mod m4a;
+pub mod matroska;
 pub mod midi;
 pub mod mp3;
+pub mod mp4;
 pub mod ogg;
 pub mod rmid;
 pub mod smf;
+pub mod svx;
+pub mod tracker_module;
 pub mod wave;
+pub mod wavpack;
 
 #[derive(Debug, PartialEq)]
 pub enum FileType {
@@ -22,8 +34,20 @@ pub enum FileType {
     Rmid,
     Ogg,
     Mp3(Mp3SubType),
-    M4a,
+    Mp4,
     Caf,
+    Dls,
+    Matroska,
+    Asf,
+    Dsf,
+    Dsdiff,
+    WavPack,
+    Ape,
+    Au,
+    Svx,
+    TrackerModule(TrackerModuleType),
+    Aac(AacSubType),
+    Ac3,
     Unsupported(String),
 }
 
@@ -33,10 +57,25 @@ pub enum Mp3SubType {
     NonId3,
 }
 
+#[derive(Debug, PartialEq)]
+pub enum AacSubType {
+    Adts,
+    Latm,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TrackerModuleType {
+    Mod,
+    S3m,
+    Xm,
+    It,
+}
+
 pub fn get_file_metadata(
     input_file_path: &str,
     file_type: FileType,
     mandatory_sections_only: bool,
+    deep_scan: bool,
 ) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
     let metadata: Vec<OutputEntry> = match file_type {
         FileType::Wave => wave::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
@@ -44,12 +83,39 @@ pub fn get_file_metadata(
         FileType::Aiff => aiff::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
         FileType::Smf => smf::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
         FileType::Rmid => rmid::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
-        FileType::Ogg => ogg::get_metadata_from_file(input_file_path)?,
-        FileType::Mp3(subtype) => {
-            mp3::get_metadata_from_file(input_file_path, subtype, mandatory_sections_only)?
-        }
-        FileType::M4a => m4a::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Ogg => ogg::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Mp3(subtype) => mp3::get_metadata_from_file(
+            input_file_path,
+            subtype,
+            mandatory_sections_only,
+            deep_scan,
+        )?,
+        FileType::Mp4 => mp4::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
         FileType::Caf => caf::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Dls => dls::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Matroska => {
+            matroska::get_metadata_from_file(input_file_path, mandatory_sections_only)?
+        }
+        FileType::Asf => asf::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Dsf => dsf::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Dsdiff => {
+            dsdiff::get_metadata_from_file(input_file_path, mandatory_sections_only)?
+        }
+        FileType::WavPack => {
+            wavpack::get_metadata_from_file(input_file_path, mandatory_sections_only)?
+        }
+        FileType::Ape => ape::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Au => au::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::Svx => svx::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
+        FileType::TrackerModule(module_type) => tracker_module::get_metadata_from_file(
+            input_file_path,
+            module_type,
+            mandatory_sections_only,
+        )?,
+        FileType::Aac(subtype) => {
+            aac::get_metadata_from_file(input_file_path, subtype, mandatory_sections_only)?
+        }
+        FileType::Ac3 => ac3::get_metadata_from_file(input_file_path, mandatory_sections_only)?,
         FileType::Unsupported(file_id) => {
             return Err(Box::new(LocalError::UnsupportedFileType(file_id)))
         }
@@ -0,0 +1,414 @@
+use crate::caf_chunks::desc::MPEG_4_AAC_OBJECT_TYPES;
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
+use crate::chunks::id3;
+use crate::file_types::mp3::skip_over_id3_data_in_file;
+use crate::file_types::AacSubType;
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/aac.tmpl");
+const ADTS_HEADER_TEMPLATE_CONTENT: &str =
+    include_str!("../templates/file_types/aac_adts_header.tmpl");
+const LATM_HEADER_TEMPLATE_CONTENT: &str =
+    include_str!("../templates/file_types/aac_latm_header.tmpl");
+const STREAM_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/aac_stream.tmpl");
+
+const ID3_FILE_ID: &[u8] = b"ID3";
+const ID3_FILE_ID_LENGTH_IN_BYTES: usize = 3;
+const ADTS_HEADER_LENGTH_IN_BYTES: usize = 7;
+const LOAS_HEADER_LENGTH_IN_BYTES: usize = 3;
+const SAMPLES_PER_FRAME: u64 = 1024;
+const VBR_BUFFER_FULLNESS: u16 = 0x7FF;
+pub const ESCAPE_OBJECT_TYPE: u8 = 31;
+pub const EXPLICIT_SAMPLE_RATE_INDEX: u8 = 15;
+
+const SAMPLE_RATES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+const CHANNEL_CONFIGURATIONS: [&str; 8] = [
+    "Defined in the Audio Specific Config",
+    "1 channel: front-center",
+    "2 channels: front-left, front-right",
+    "3 channels: front-center, front-left, front-right",
+    "4 channels: front-center, front-left, front-right, back-center",
+    "5 channels: front-center, front-left, front-right, back-left, back-right",
+    "6 channels: front-center, front-left, front-right, back-left, back-right, LFE",
+    "8 channels: front-center, front-left, front-right, side-left, side-right, back-left, back-right, LFE",
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct AdtsHeader {
+    is_mpeg_2: bool,
+    is_crc_protected: bool,
+    object_type: u8,
+    sample_rate_index: u8,
+    private_bit: bool,
+    channel_configuration: u8,
+    is_original: bool,
+    is_home: bool,
+    frame_length: u16,
+    buffer_fullness: u16,
+    number_of_raw_data_blocks: u8,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct LatmConfig {
+    audio_mux_version: u8,
+    number_of_sub_frames: u8,
+    number_of_programs: u8,
+    number_of_layers: u8,
+    object_type: u8,
+    sample_rate: u32,
+    channel_configuration: u8,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct StreamSummary {
+    number_of_frames: u64,
+    number_of_samples: u64,
+    number_of_bytes: u64,
+}
+
+pub fn is_adts_sync(header_bytes: &[u8]) -> bool {
+    header_bytes.len() >= 2 && header_bytes[0] == 0xFF && header_bytes[1] & 0xF6 == 0xF0
+}
+
+pub fn is_loas_sync(header_bytes: &[u8]) -> bool {
+    header_bytes.len() >= 2 && header_bytes[0] == 0x56 && header_bytes[1] & 0xE0 == 0xE0
+}
+
+pub fn get_metadata_from_file(
+    aac_file_path: &str,
+    subtype: AacSubType,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut aac_file = File::open(aac_file_path)?;
+    let file_metadata = get_file_metadata(aac_file_path, &aac_file, TEMPLATE_CONTENT)?;
+    let mut output = vec![file_metadata];
+
+    let file_id = read_bytes_from_file(&mut aac_file, ID3_FILE_ID_LENGTH_IN_BYTES)?;
+    aac_file.rewind()?;
+
+    if file_id == ID3_FILE_ID {
+        if !mandatory_sections_only {
+            output.push(id3::get_metadata(aac_file_path)?);
+        }
+        skip_over_id3_data_in_file(&mut aac_file)?;
+    }
+
+    match subtype {
+        AacSubType::Adts => output.extend(get_adts_metadata_from_file(&mut aac_file)?),
+        AacSubType::Latm => output.extend(get_latm_metadata_from_file(&mut aac_file)?),
+    }
+
+    Ok(output)
+}
+
+fn get_adts_metadata_from_file(aac_file: &mut File) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let header_bytes = read_bytes_from_file(aac_file, ADTS_HEADER_LENGTH_IN_BYTES)?;
+    aac_file.seek_relative(-(ADTS_HEADER_LENGTH_IN_BYTES as i64))?;
+
+    let adts_header = get_adts_header_from_bytes(&header_bytes);
+    let sample_rate = get_sample_rate_from_index(adts_header.sample_rate_index);
+    let stream_summary = get_adts_stream_summary_from_file(aac_file)?;
+
+    let buffer_fullness = match adts_header.buffer_fullness {
+        VBR_BUFFER_FULLNESS => "VBR".to_string(),
+        fullness => fullness.to_string(),
+    };
+
+    let output_values: Value = upon::value! {
+        mpeg_version: match adts_header.is_mpeg_2 {
+            true => "MPEG-2",
+            false => "MPEG-4",
+        },
+        object_type: get_object_type_name(adts_header.object_type),
+        sample_rate: sample_rate,
+        channel_configuration: get_channel_configuration_name(adts_header.channel_configuration),
+        crc_protected: adts_header.is_crc_protected,
+        private_bit: adts_header.private_bit,
+        original: adts_header.is_original,
+        home: adts_header.is_home,
+        frame_length: adts_header.frame_length,
+        buffer_fullness: buffer_fullness,
+        raw_data_blocks: adts_header.number_of_raw_data_blocks as u16 + 1,
+    };
+
+    let header_output = get_file_chunk_output(ADTS_HEADER_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(vec![
+        OutputEntry {
+            section: Section::Mandatory,
+            text: header_output,
+        },
+        get_stream_metadata(stream_summary, sample_rate)?,
+    ])
+}
+
+fn get_latm_metadata_from_file(aac_file: &mut File) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let loas_header = read_bytes_from_file(aac_file, LOAS_HEADER_LENGTH_IN_BYTES)?;
+    let first_frame_length = get_loas_frame_length(&loas_header);
+    let first_frame = read_bytes_from_file(aac_file, first_frame_length.min(8))?;
+    aac_file.seek_relative(-((LOAS_HEADER_LENGTH_IN_BYTES + first_frame.len()) as i64))?;
+
+    let latm_config = get_latm_config_from_bytes(&first_frame);
+    let stream_summary = get_loas_stream_summary_from_file(aac_file, &latm_config)?;
+    let is_config_decoded = latm_config.is_some();
+    let latm_config = latm_config.unwrap_or_default();
+
+    let output_values: Value = upon::value! {
+        is_config_decoded: is_config_decoded,
+        audio_mux_version: latm_config.audio_mux_version,
+        number_of_sub_frames: latm_config.number_of_sub_frames as u16 + 1,
+        number_of_programs: latm_config.number_of_programs as u16 + 1,
+        number_of_layers: latm_config.number_of_layers as u16 + 1,
+        object_type: get_object_type_name(latm_config.object_type),
+        sample_rate: latm_config.sample_rate,
+        channel_configuration: get_channel_configuration_name(latm_config.channel_configuration),
+        first_frame_length: first_frame_length,
+    };
+
+    let header_output = get_file_chunk_output(LATM_HEADER_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(vec![
+        OutputEntry {
+            section: Section::Mandatory,
+            text: header_output,
+        },
+        get_stream_metadata(stream_summary, latm_config.sample_rate)?,
+    ])
+}
+
+fn get_adts_header_from_bytes(header_bytes: &[u8]) -> AdtsHeader {
+    AdtsHeader {
+        is_mpeg_2: header_bytes[1] & 0x08 != 0,
+        is_crc_protected: header_bytes[1] & 0x01 == 0,
+        object_type: (header_bytes[2] >> 6) + 1,
+        sample_rate_index: (header_bytes[2] >> 2) & 0x0F,
+        private_bit: header_bytes[2] & 0x02 != 0,
+        channel_configuration: ((header_bytes[2] & 0x01) << 2) | (header_bytes[3] >> 6),
+        is_original: header_bytes[3] & 0x20 != 0,
+        is_home: header_bytes[3] & 0x10 != 0,
+        frame_length: get_adts_frame_length(header_bytes),
+        buffer_fullness: (((header_bytes[5] & 0x1F) as u16) << 6) | (header_bytes[6] >> 2) as u16,
+        number_of_raw_data_blocks: header_bytes[6] & 0x03,
+    }
+}
+
+fn get_adts_frame_length(header_bytes: &[u8]) -> u16 {
+    (((header_bytes[3] & 0x03) as u16) << 11)
+        | ((header_bytes[4] as u16) << 3)
+        | (header_bytes[5] >> 5) as u16
+}
+
+fn get_loas_frame_length(header_bytes: &[u8]) -> usize {
+    ((((header_bytes[1] & 0x1F) as u16) << 8) | header_bytes[2] as u16) as usize
+}
+
+fn get_latm_config_from_bytes(frame_bytes: &[u8]) -> Option<LatmConfig> {
+    let mut padded_bytes = [0u8; 8];
+    padded_bytes[..frame_bytes.len()].copy_from_slice(frame_bytes);
+    let bits = u64::from_be_bytes(padded_bytes);
+    let mut position = 0;
+
+    let mut take_bits = |count: u32| -> u64 {
+        let value = (bits << position) >> (64 - count);
+        position += count;
+        value
+    };
+
+    let use_same_stream_mux = take_bits(1);
+    if use_same_stream_mux == 1 {
+        return None;
+    }
+
+    let audio_mux_version = take_bits(1) as u8;
+    if audio_mux_version == 1 {
+        return None;
+    }
+
+    let _all_streams_same_time_framing = take_bits(1);
+    let number_of_sub_frames = take_bits(6) as u8;
+    let number_of_programs = take_bits(4) as u8;
+    let number_of_layers = take_bits(3) as u8;
+
+    let mut object_type = take_bits(5) as u8;
+    if object_type == ESCAPE_OBJECT_TYPE {
+        object_type = 32 + take_bits(6) as u8;
+    }
+
+    let sample_rate_index = take_bits(4) as u8;
+    let sample_rate = match sample_rate_index {
+        EXPLICIT_SAMPLE_RATE_INDEX => take_bits(24) as u32,
+        index => get_sample_rate_from_index(index),
+    };
+
+    let channel_configuration = take_bits(4) as u8;
+
+    Some(LatmConfig {
+        audio_mux_version,
+        number_of_sub_frames,
+        number_of_programs,
+        number_of_layers,
+        object_type,
+        sample_rate,
+        channel_configuration,
+    })
+}
+
+fn get_adts_stream_summary_from_file(aac_file: &mut File) -> Result<StreamSummary, Box<dyn Error>> {
+    let mut stream_summary = StreamSummary::default();
+
+    loop {
+        let header_bytes = match read_bytes_from_file(aac_file, ADTS_HEADER_LENGTH_IN_BYTES) {
+            Ok(bytes) => bytes,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        let frame_length = get_adts_frame_length(&header_bytes) as usize;
+        if !is_adts_sync(&header_bytes) || frame_length < ADTS_HEADER_LENGTH_IN_BYTES {
+            break;
+        }
+
+        stream_summary.number_of_frames += 1;
+        stream_summary.number_of_samples +=
+            ((header_bytes[6] & 0x03) as u64 + 1) * SAMPLES_PER_FRAME;
+        stream_summary.number_of_bytes += frame_length as u64;
+        skip_over_bytes_in_file(aac_file, frame_length - ADTS_HEADER_LENGTH_IN_BYTES)?;
+    }
+
+    Ok(stream_summary)
+}
+
+fn get_loas_stream_summary_from_file(
+    aac_file: &mut File,
+    latm_config: &Option<LatmConfig>,
+) -> Result<StreamSummary, Box<dyn Error>> {
+    let mut stream_summary = StreamSummary::default();
+    let samples_per_loas_frame = latm_config
+        .as_ref()
+        .map_or(1, |config| config.number_of_sub_frames as u64 + 1)
+        * SAMPLES_PER_FRAME;
+
+    loop {
+        let header_bytes = match read_bytes_from_file(aac_file, LOAS_HEADER_LENGTH_IN_BYTES) {
+            Ok(bytes) => bytes,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        if !is_loas_sync(&header_bytes) {
+            break;
+        }
+
+        let frame_length = get_loas_frame_length(&header_bytes);
+        stream_summary.number_of_frames += 1;
+        stream_summary.number_of_samples += samples_per_loas_frame;
+        stream_summary.number_of_bytes += (frame_length + LOAS_HEADER_LENGTH_IN_BYTES) as u64;
+        skip_over_bytes_in_file(aac_file, frame_length)?;
+    }
+
+    Ok(stream_summary)
+}
+
+fn get_stream_metadata(
+    stream_summary: StreamSummary,
+    sample_rate: u32,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut duration = String::new();
+    let mut average_bitrate = String::new();
+
+    if sample_rate > 0 && stream_summary.number_of_samples > 0 {
+        let duration_in_seconds = stream_summary.number_of_samples as f64 / sample_rate as f64;
+        duration = format!("{:.3} seconds", duration_in_seconds);
+        average_bitrate = format!(
+            "{:.0} kbps",
+            stream_summary.number_of_bytes as f64 * 8.0 / duration_in_seconds / 1000.0
+        );
+    }
+
+    let output_values: Value = upon::value! {
+        number_of_frames: stream_summary.number_of_frames,
+        duration: duration,
+        average_bitrate: average_bitrate,
+    };
+
+    let formated_output = get_file_chunk_output(STREAM_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+pub fn get_sample_rate_from_index(sample_rate_index: u8) -> u32 {
+    SAMPLE_RATES
+        .get(sample_rate_index as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+pub fn get_object_type_name(object_type: u8) -> String {
+    match MPEG_4_AAC_OBJECT_TYPES.get(object_type as usize) {
+        Some(name) => format!("{} ({})", name, object_type),
+        None => format!("Unknown ({})", object_type),
+    }
+}
+
+pub fn get_channel_configuration_name(channel_configuration: u8) -> String {
+    match CHANNEL_CONFIGURATIONS.get(channel_configuration as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Reserved ({})", channel_configuration),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_adts_header_from_bytes() {
+        let header_bytes = [0xFF, 0xF1, 0x50, 0x80, 0x02, 0x1F, 0xFC];
+        let correct_result = AdtsHeader {
+            is_mpeg_2: false,
+            is_crc_protected: false,
+            object_type: 2,
+            sample_rate_index: 4,
+            private_bit: false,
+            channel_configuration: 2,
+            is_original: false,
+            is_home: false,
+            frame_length: 16,
+            buffer_fullness: VBR_BUFFER_FULLNESS,
+            number_of_raw_data_blocks: 0,
+        };
+        assert!(is_adts_sync(&header_bytes));
+        assert_eq!(get_adts_header_from_bytes(&header_bytes), correct_result);
+    }
+
+    #[test]
+    fn return_correct_latm_config_from_first_frame_bytes() {
+        let frame_bytes = [0x20, 0x00, 0x11, 0x90];
+        let correct_result = LatmConfig {
+            object_type: 2,
+            sample_rate: 48000,
+            channel_configuration: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            get_latm_config_from_bytes(&frame_bytes),
+            Some(correct_result)
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::ac3_frames::{
+    get_frame_info_from_bytes, get_metadata as get_frame_metadata, BSI_LENGTH_IN_BYTES,
+    FRAME_INFO_LENGTH_IN_BYTES,
+};
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
+use crate::chunks::id3;
+use crate::file_types::mp3::skip_over_id3_data_in_file;
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ac3.tmpl");
+const STREAM_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ac3_stream.tmpl");
+
+const ID3_FILE_ID: &[u8] = b"ID3";
+const ID3_FILE_ID_LENGTH_IN_BYTES: usize = 3;
+
+#[derive(Debug, Default, PartialEq)]
+struct StreamSummary {
+    number_of_frames: u64,
+    number_of_samples: u64,
+    number_of_bytes: u64,
+    sample_rate: u32,
+    substreams: Vec<String>,
+    frame_headers: Vec<Vec<u8>>,
+}
+
+pub fn get_metadata_from_file(
+    ac3_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut ac3_file = File::open(ac3_file_path)?;
+    let file_metadata = get_file_metadata(ac3_file_path, &ac3_file, TEMPLATE_CONTENT)?;
+    let mut output = vec![file_metadata];
+
+    let file_id = read_bytes_from_file(&mut ac3_file, ID3_FILE_ID_LENGTH_IN_BYTES)?;
+    ac3_file.rewind()?;
+
+    if file_id == ID3_FILE_ID {
+        if !mandatory_sections_only {
+            output.push(id3::get_metadata(ac3_file_path)?);
+        }
+        skip_over_id3_data_in_file(&mut ac3_file)?;
+    }
+
+    let stream_summary = get_stream_summary_from_file(&mut ac3_file)?;
+
+    for frame_header in &stream_summary.frame_headers {
+        output.push(get_frame_metadata(frame_header)?);
+    }
+
+    output.push(get_stream_metadata(stream_summary)?);
+
+    Ok(output)
+}
+
+fn get_stream_summary_from_file(ac3_file: &mut File) -> Result<StreamSummary, Box<dyn Error>> {
+    let mut stream_summary = StreamSummary::default();
+
+    loop {
+        let header_bytes = match read_bytes_from_file(ac3_file, FRAME_INFO_LENGTH_IN_BYTES) {
+            Ok(bytes) => bytes,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        let Some(frame_info) = get_frame_info_from_bytes(&header_bytes) else {
+            break;
+        };
+
+        if frame_info.frame_length < FRAME_INFO_LENGTH_IN_BYTES {
+            break;
+        }
+
+        let stream_name = frame_info.get_stream_name();
+        if !stream_summary.substreams.contains(&stream_name) {
+            ac3_file.seek_relative(-(FRAME_INFO_LENGTH_IN_BYTES as i64))?;
+            let header_length = frame_info.frame_length.min(BSI_LENGTH_IN_BYTES);
+            stream_summary
+                .frame_headers
+                .push(read_bytes_from_file(ac3_file, header_length)?);
+            ac3_file.seek_relative(-((header_length - FRAME_INFO_LENGTH_IN_BYTES) as i64))?;
+            stream_summary.substreams.push(stream_name);
+        }
+
+        if frame_info.is_independent() && frame_info.substream_id == 0 {
+            stream_summary.number_of_samples += frame_info.samples_per_frame as u64;
+            stream_summary.sample_rate = frame_info.sample_rate;
+        }
+
+        stream_summary.number_of_frames += 1;
+        stream_summary.number_of_bytes += frame_info.frame_length as u64;
+        skip_over_bytes_in_file(
+            ac3_file,
+            frame_info.frame_length - FRAME_INFO_LENGTH_IN_BYTES,
+        )?;
+    }
+
+    if stream_summary.frame_headers.is_empty() {
+        return Err("No AC-3 or E-AC-3 sync frame was found in the file".into());
+    }
+
+    Ok(stream_summary)
+}
+
+fn get_stream_metadata(stream_summary: StreamSummary) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut duration = String::new();
+    let mut average_bitrate = String::new();
+
+    if stream_summary.sample_rate > 0 && stream_summary.number_of_samples > 0 {
+        let duration_in_seconds =
+            stream_summary.number_of_samples as f64 / stream_summary.sample_rate as f64;
+        duration = format!("{:.3} seconds", duration_in_seconds);
+        average_bitrate = format!(
+            "{:.0} kbps",
+            stream_summary.number_of_bytes as f64 * 8.0 / duration_in_seconds / 1000.0
+        );
+    }
+
+    let output_values: Value = upon::value! {
+        number_of_frames: stream_summary.number_of_frames,
+        duration: duration,
+        average_bitrate: average_bitrate,
+        substreams: stream_summary.substreams.join(", "),
+    };
+
+    let formated_output = get_file_chunk_output(STREAM_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
@@ -21,12 +21,8 @@ pub fn get_metadata_from_file(
 
     let file_metadata = get_file_metadata(file_path, &aiff_file, TEMPLATE_CONTENT)?;
     let form_metadata = get_form_metadata_from_file(&mut aiff_file)?;
-    let chunk_metadata = get_metadata_from_chunks(
-        &mut aiff_file,
-        file_path,
-        mandatory_sections_only,
-        Endian::Big,
-    )?;
+    let chunk_metadata =
+        get_metadata_from_chunks(&mut aiff_file, mandatory_sections_only, Endian::Big)?;
 
     let mut output = vec![file_metadata, form_metadata];
     output.extend(chunk_metadata);
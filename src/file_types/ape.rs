@@ -0,0 +1,311 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::formating::format_bytes_as_string_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::get_metadata_from_trailing_tags;
+use std::error::Error;
+use std::fs::File;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ape.tmpl");
+const HEADER_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ape_header.tmpl");
+
+const FILE_ID_LENGTH_IN_BYTES: usize = 4;
+const FILE_ID_AND_VERSION_LENGTH_IN_BYTES: usize = 6;
+const DESCRIPTOR_LENGTH_IN_BYTES: usize = 52;
+const HEADER_LENGTH_IN_BYTES: usize = 24;
+const OLD_HEADER_LENGTH_IN_BYTES: usize = 32;
+const DESCRIPTOR_PADDING_LENGTH_IN_BYTES: usize = 2;
+const HEADER_AND_SEEK_TABLE_LENGTH_FIELDS_LENGTH_IN_BYTES: usize = 8;
+const FRAME_DATA_LENGTH_FIELDS_LENGTH_IN_BYTES: usize = 8;
+const MD5_LENGTH_IN_BYTES: usize = 16;
+const FIRST_VERSION_WITH_DESCRIPTOR: u16 = 3980;
+
+const EIGHT_BIT_FORMAT_FLAG: u16 = 0x01;
+const TWENTY_FOUR_BIT_FORMAT_FLAG: u16 = 0x08;
+const EXTRA_HIGH_COMPRESSION_LEVEL: u16 = 4000;
+
+const COMPRESSION_LEVELS: [(u16, &str); 5] = [
+    (1000, "Fast"),
+    (2000, "Normal"),
+    (3000, "High"),
+    (4000, "Extra High"),
+    (5000, "Insane"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct ApeHeader {
+    version: u16,
+    compression_level: u16,
+    format_flags: u16,
+    blocks_per_frame: u32,
+    final_frame_blocks: u32,
+    total_frames: u32,
+    bits_per_sample: u16,
+    channels: u16,
+    sample_rate: u32,
+    wav_header_length: u32,
+    terminating_data_length: u32,
+    md5: Vec<u8>,
+}
+
+pub fn get_metadata_from_file(
+    ape_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut ape_file = File::open(ape_file_path)?;
+    let file_metadata = get_file_metadata(ape_file_path, &ape_file, TEMPLATE_CONTENT)?;
+    let ape_header = get_ape_header_from_file(&mut ape_file)?;
+
+    let mut output = vec![file_metadata, get_header_metadata(ape_header)?];
+
+    if !mandatory_sections_only {
+        output.extend(get_metadata_from_trailing_tags(&mut ape_file)?);
+    }
+
+    Ok(output)
+}
+
+fn get_ape_header_from_file(ape_file: &mut File) -> Result<ApeHeader, Box<dyn Error>> {
+    let mut id_and_version = read_bytes_from_file(ape_file, FILE_ID_AND_VERSION_LENGTH_IN_BYTES)?;
+    skip_over_bytes(&mut id_and_version, FILE_ID_LENGTH_IN_BYTES)?;
+    let version = take_first_two_bytes_as_unsigned_integer(&mut id_and_version, Endian::Little)?;
+
+    if version < FIRST_VERSION_WITH_DESCRIPTOR {
+        let header_bytes = read_bytes_from_file(
+            ape_file,
+            OLD_HEADER_LENGTH_IN_BYTES - FILE_ID_AND_VERSION_LENGTH_IN_BYTES,
+        )?;
+        return get_ape_header_from_old_header_bytes(version, header_bytes);
+    }
+
+    let descriptor_bytes = read_bytes_from_file(
+        ape_file,
+        DESCRIPTOR_LENGTH_IN_BYTES - FILE_ID_AND_VERSION_LENGTH_IN_BYTES,
+    )?;
+    let (descriptor_length, mut ape_header) =
+        get_ape_header_from_descriptor_bytes(version, descriptor_bytes)?;
+
+    let extra_descriptor_bytes =
+        (descriptor_length as usize).saturating_sub(DESCRIPTOR_LENGTH_IN_BYTES);
+    skip_over_bytes_in_file(ape_file, extra_descriptor_bytes)?;
+
+    let header_bytes = read_bytes_from_file(ape_file, HEADER_LENGTH_IN_BYTES)?;
+    add_header_values_from_bytes(&mut ape_header, header_bytes)?;
+
+    Ok(ape_header)
+}
+
+fn get_ape_header_from_descriptor_bytes(
+    version: u16,
+    mut descriptor_bytes: Vec<u8>,
+) -> Result<(u32, ApeHeader), Box<dyn Error>> {
+    skip_over_bytes(&mut descriptor_bytes, DESCRIPTOR_PADDING_LENGTH_IN_BYTES)?;
+    let descriptor_length =
+        take_first_four_bytes_as_unsigned_integer(&mut descriptor_bytes, Endian::Little)?;
+    skip_over_bytes(
+        &mut descriptor_bytes,
+        HEADER_AND_SEEK_TABLE_LENGTH_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let wav_header_length =
+        take_first_four_bytes_as_unsigned_integer(&mut descriptor_bytes, Endian::Little)?;
+    skip_over_bytes(
+        &mut descriptor_bytes,
+        FRAME_DATA_LENGTH_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let terminating_data_length =
+        take_first_four_bytes_as_unsigned_integer(&mut descriptor_bytes, Endian::Little)?;
+    let md5 = take_first_number_of_bytes(&mut descriptor_bytes, MD5_LENGTH_IN_BYTES)?;
+
+    let ape_header = ApeHeader {
+        version,
+        wav_header_length,
+        terminating_data_length,
+        md5,
+        ..Default::default()
+    };
+
+    Ok((descriptor_length, ape_header))
+}
+
+fn add_header_values_from_bytes(
+    ape_header: &mut ApeHeader,
+    mut header_bytes: Vec<u8>,
+) -> Result<(), Box<dyn Error>> {
+    ape_header.compression_level =
+        take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.format_flags =
+        take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.blocks_per_frame =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.final_frame_blocks =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.total_frames =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.bits_per_sample =
+        take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.channels =
+        take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    ape_header.sample_rate =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+
+    Ok(())
+}
+
+fn get_ape_header_from_old_header_bytes(
+    version: u16,
+    mut header_bytes: Vec<u8>,
+) -> Result<ApeHeader, Box<dyn Error>> {
+    let compression_level =
+        take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let format_flags = take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let channels = take_first_two_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let sample_rate = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let wav_header_length =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let terminating_data_length =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let total_frames =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    let final_frame_blocks =
+        take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+
+    Ok(ApeHeader {
+        version,
+        compression_level,
+        format_flags,
+        blocks_per_frame: get_old_blocks_per_frame(version, compression_level),
+        final_frame_blocks,
+        total_frames,
+        bits_per_sample: get_old_bits_per_sample(format_flags),
+        channels,
+        sample_rate,
+        wav_header_length,
+        terminating_data_length,
+        md5: vec![],
+    })
+}
+
+fn get_old_blocks_per_frame(version: u16, compression_level: u16) -> u32 {
+    if version >= 3950 {
+        return 73728 * 4;
+    }
+
+    if version >= 3900 || (version >= 3800 && compression_level == EXTRA_HIGH_COMPRESSION_LEVEL) {
+        return 73728;
+    }
+
+    9216
+}
+
+fn get_old_bits_per_sample(format_flags: u16) -> u16 {
+    if format_flags & EIGHT_BIT_FORMAT_FLAG != 0 {
+        return 8;
+    }
+
+    if format_flags & TWENTY_FOUR_BIT_FORMAT_FLAG != 0 {
+        return 24;
+    }
+
+    16
+}
+
+fn get_total_blocks(ape_header: &ApeHeader) -> u64 {
+    if ape_header.total_frames == 0 {
+        return 0;
+    }
+
+    (ape_header.total_frames as u64 - 1) * ape_header.blocks_per_frame as u64
+        + ape_header.final_frame_blocks as u64
+}
+
+fn get_header_metadata(ape_header: ApeHeader) -> Result<OutputEntry, Box<dyn Error>> {
+    let total_blocks = get_total_blocks(&ape_header);
+
+    let mut duration = String::new();
+    if ape_header.sample_rate > 0 {
+        duration = format!(
+            "{:.3} seconds",
+            total_blocks as f64 / ape_header.sample_rate as f64
+        );
+    }
+
+    let compression_level = COMPRESSION_LEVELS
+        .iter()
+        .find(|(level, _)| *level == ape_header.compression_level)
+        .map(|(level, name)| format!("{} ({})", name, level))
+        .unwrap_or(format!("Unknown ({})", ape_header.compression_level));
+
+    let output_values: Value = upon::value! {
+        version: format!("{:.2}", ape_header.version as f64 / 1000.0),
+        compression_level: compression_level,
+        blocks_per_frame: ape_header.blocks_per_frame,
+        final_frame_blocks: ape_header.final_frame_blocks,
+        total_frames: ape_header.total_frames,
+        total_samples: total_blocks,
+        duration: duration,
+        bits_per_sample: ape_header.bits_per_sample,
+        channels: ape_header.channels,
+        sample_rate: ape_header.sample_rate,
+        wav_header_length: ape_header.wav_header_length,
+        terminating_data_length: ape_header.terminating_data_length,
+        md5: format_bytes_as_string_of_bytes(&ape_header.md5).replace(' ', ""),
+    };
+
+    let formated_output = get_file_chunk_output(HEADER_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_total_blocks_from_frame_counts() {
+        let ape_header = ApeHeader {
+            blocks_per_frame: 73728,
+            final_frame_blocks: 100,
+            total_frames: 3,
+            ..Default::default()
+        };
+        assert_eq!(get_total_blocks(&ape_header), 147556);
+    }
+
+    #[test]
+    fn return_correct_header_from_old_header_bytes() {
+        let mut header_bytes = vec![];
+        header_bytes.extend(2000u16.to_le_bytes());
+        header_bytes.extend(TWENTY_FOUR_BIT_FORMAT_FLAG.to_le_bytes());
+        header_bytes.extend(2u16.to_le_bytes());
+        header_bytes.extend(44100u32.to_le_bytes());
+        header_bytes.extend(44u32.to_le_bytes());
+        header_bytes.extend(0u32.to_le_bytes());
+        header_bytes.extend(5u32.to_le_bytes());
+        header_bytes.extend(1000u32.to_le_bytes());
+
+        let result = get_ape_header_from_old_header_bytes(3930, header_bytes).unwrap();
+        let correct_result = ApeHeader {
+            version: 3930,
+            compression_level: 2000,
+            format_flags: TWENTY_FOUR_BIT_FORMAT_FLAG,
+            blocks_per_frame: 73728,
+            final_frame_blocks: 1000,
+            total_frames: 5,
+            bits_per_sample: 24,
+            channels: 2,
+            sample_rate: 44100,
+            wav_header_length: 44,
+            terminating_data_length: 0,
+            md5: vec![],
+        };
+        assert_eq!(result, correct_result);
+    }
+}
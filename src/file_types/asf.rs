@@ -0,0 +1,178 @@
+use crate::asf_objects::{
+    codec_list, content_description, extended_content_description, file_properties,
+    get_child_objects_from_bytes, get_guid_from_bytes, get_object_name_from_guid,
+    is_known_object_guid, metadata, stream_properties, unknown, AsfObject, CODEC_LIST_OBJECT_GUID,
+    CONTENT_DESCRIPTION_OBJECT_GUID, EXTENDED_CONTENT_DESCRIPTION_OBJECT_GUID,
+    FILE_PROPERTIES_OBJECT_GUID, GUID_LENGTH_IN_BYTES, HEADER_EXTENSION_OBJECT_GUID,
+    HEADER_OBJECT_GUID, METADATA_LIBRARY_OBJECT_GUID, METADATA_OBJECT_GUID,
+    OBJECT_HEADER_LENGTH_IN_BYTES, STREAM_PROPERTIES_OBJECT_GUID,
+};
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_eight_bytes_as_unsigned_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
+use crate::chunks::skipped;
+use crate::errors::LocalError;
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/asf.tmpl");
+const HEADER_OBJECT_FIELDS_LENGTH_IN_BYTES: usize = 6;
+const HEADER_EXTENSION_RESERVED_FIELDS_LENGTH_IN_BYTES: usize = 18;
+const MANDATORY_OBJECTS: [&str; 2] = [FILE_PROPERTIES_OBJECT_GUID, STREAM_PROPERTIES_OBJECT_GUID];
+
+pub fn get_metadata_from_file(
+    asf_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut asf_file = File::open(asf_file_path)?;
+    let file_metadata = get_file_metadata(asf_file_path, &asf_file, TEMPLATE_CONTENT)?;
+
+    let header_objects = get_header_objects_from_file(&mut asf_file)?;
+    let mut skipped_object_names: Vec<String> = vec![];
+    let mut output = vec![file_metadata];
+
+    output.extend(get_metadata_from_objects(
+        header_objects,
+        mandatory_sections_only,
+        &mut skipped_object_names,
+    )?);
+
+    if !mandatory_sections_only {
+        add_top_level_object_names_from_file(&mut asf_file, &mut skipped_object_names)?;
+
+        for object_name in skipped_object_names {
+            output.push(skipped::get_metadata(object_name)?);
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_header_objects_from_file(asf_file: &mut File) -> Result<Vec<AsfObject>, Box<dyn Error>> {
+    let mut header_bytes = read_bytes_from_file(asf_file, OBJECT_HEADER_LENGTH_IN_BYTES)?;
+    let header_guid = get_guid_from_bytes(&header_bytes);
+
+    if header_guid != HEADER_OBJECT_GUID {
+        return Err(Box::new(LocalError::InvalidAsfHeaderObject(header_guid)));
+    }
+
+    skip_over_bytes(&mut header_bytes, GUID_LENGTH_IN_BYTES)?;
+    let header_size =
+        take_first_eight_bytes_as_unsigned_integer(&mut header_bytes, Endian::Little)?;
+    skip_over_bytes_in_file(asf_file, HEADER_OBJECT_FIELDS_LENGTH_IN_BYTES)?;
+
+    let remaining_file_length = asf_file.metadata()?.len() - asf_file.stream_position()?;
+    let header_data_length = header_size
+        .saturating_sub(
+            (OBJECT_HEADER_LENGTH_IN_BYTES + HEADER_OBJECT_FIELDS_LENGTH_IN_BYTES) as u64,
+        )
+        .min(remaining_file_length) as usize;
+    let header_data = read_bytes_from_file(asf_file, header_data_length)?;
+
+    Ok(get_child_objects_from_bytes(header_data)?)
+}
+
+fn get_metadata_from_objects(
+    objects: Vec<AsfObject>,
+    mandatory_sections_only: bool,
+    skipped_object_names: &mut Vec<String>,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+
+    for object in objects {
+        if object.guid == HEADER_EXTENSION_OBJECT_GUID {
+            let extension_objects = get_header_extension_objects_from_bytes(object.data)?;
+            output.extend(get_metadata_from_objects(
+                extension_objects,
+                mandatory_sections_only,
+                skipped_object_names,
+            )?);
+            continue;
+        }
+
+        if mandatory_sections_only && !MANDATORY_OBJECTS.contains(&object.guid.as_str()) {
+            continue;
+        }
+
+        let object_metadata = match object.guid.as_str() {
+            FILE_PROPERTIES_OBJECT_GUID => file_properties::get_metadata(object.data)?,
+            STREAM_PROPERTIES_OBJECT_GUID => stream_properties::get_metadata(object.data)?,
+            CONTENT_DESCRIPTION_OBJECT_GUID => content_description::get_metadata(object.data)?,
+            EXTENDED_CONTENT_DESCRIPTION_OBJECT_GUID => {
+                extended_content_description::get_metadata(object.data)?
+            }
+            METADATA_OBJECT_GUID | METADATA_LIBRARY_OBJECT_GUID => {
+                metadata::get_metadata(&get_object_name_from_guid(&object.guid), object.data)?
+            }
+            CODEC_LIST_OBJECT_GUID => codec_list::get_metadata(object.data)?,
+            guid if is_known_object_guid(guid) => {
+                add_skipped_object_name(skipped_object_names, get_object_name_from_guid(guid));
+                continue;
+            }
+            guid => unknown::get_metadata(guid, object.data)?,
+        };
+
+        output.push(object_metadata);
+    }
+
+    Ok(output)
+}
+
+fn get_header_extension_objects_from_bytes(
+    mut object_data: Vec<u8>,
+) -> Result<Vec<AsfObject>, LocalError> {
+    skip_over_bytes(
+        &mut object_data,
+        HEADER_EXTENSION_RESERVED_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let extension_data_size =
+        take_first_four_bytes_as_unsigned_integer(&mut object_data, Endian::Little)?;
+    let extension_data_length = (extension_data_size as usize).min(object_data.len());
+    let extension_data = take_first_number_of_bytes(&mut object_data, extension_data_length)?;
+
+    get_child_objects_from_bytes(extension_data)
+}
+
+fn add_top_level_object_names_from_file(
+    asf_file: &mut File,
+    skipped_object_names: &mut Vec<String>,
+) -> Result<(), Box<dyn Error>> {
+    loop {
+        let mut object_header = match read_bytes_from_file(asf_file, OBJECT_HEADER_LENGTH_IN_BYTES)
+        {
+            Ok(bytes) => bytes,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        let guid = get_guid_from_bytes(&object_header);
+        skip_over_bytes(&mut object_header, GUID_LENGTH_IN_BYTES)?;
+        let object_size =
+            take_first_eight_bytes_as_unsigned_integer(&mut object_header, Endian::Little)?;
+
+        if (object_size as usize) < OBJECT_HEADER_LENGTH_IN_BYTES {
+            break;
+        }
+
+        add_skipped_object_name(skipped_object_names, get_object_name_from_guid(&guid));
+        skip_over_bytes_in_file(
+            asf_file,
+            object_size as usize - OBJECT_HEADER_LENGTH_IN_BYTES,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn add_skipped_object_name(skipped_object_names: &mut Vec<String>, object_name: String) {
+    if !skipped_object_names.contains(&object_name) {
+        skipped_object_names.push(object_name);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes_as_string, Endian,
+};
+use crate::fileio::{
+    get_file_metadata, read_bytes_from_file, read_up_to_number_of_bytes_from_file,
+};
+use crate::formating::format_file_size_as_string;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/au.tmpl");
+const HEADER_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/au_header.tmpl");
+
+const HEADER_LENGTH_IN_BYTES: usize = 24;
+const MAGIC_NUMBER_LENGTH_IN_BYTES: usize = 4;
+const UNKNOWN_DATA_SIZE: u32 = 0xFFFFFFFF;
+const UNKNOWN_DATA_SIZE_NAME: &str = "Unknown";
+
+const MULAW_ENCODING_ID: u32 = 1;
+const MULAW_ENCODING_NAME: &str = "8-bit ITU-T G.711 µ-law";
+const LINEAR_8_BIT_ENCODING_ID: u32 = 2;
+const LINEAR_8_BIT_ENCODING_NAME: &str = "8-bit linear PCM";
+const LINEAR_16_BIT_ENCODING_ID: u32 = 3;
+const LINEAR_16_BIT_ENCODING_NAME: &str = "16-bit linear PCM";
+const LINEAR_24_BIT_ENCODING_ID: u32 = 4;
+const LINEAR_24_BIT_ENCODING_NAME: &str = "24-bit linear PCM";
+const LINEAR_32_BIT_ENCODING_ID: u32 = 5;
+const LINEAR_32_BIT_ENCODING_NAME: &str = "32-bit linear PCM";
+const FLOAT_ENCODING_ID: u32 = 6;
+const FLOAT_ENCODING_NAME: &str = "32-bit IEEE float";
+const DOUBLE_ENCODING_ID: u32 = 7;
+const DOUBLE_ENCODING_NAME: &str = "64-bit IEEE double";
+const FRAGMENTED_ENCODING_ID: u32 = 8;
+const FRAGMENTED_ENCODING_NAME: &str = "Fragmented sample data";
+const DSP_PROGRAM_ENCODING_ID: u32 = 10;
+const DSP_PROGRAM_ENCODING_NAME: &str = "DSP program";
+const FIXED_8_BIT_ENCODING_ID: u32 = 11;
+const FIXED_8_BIT_ENCODING_NAME: &str = "8-bit fixed point";
+const FIXED_16_BIT_ENCODING_ID: u32 = 12;
+const FIXED_16_BIT_ENCODING_NAME: &str = "16-bit fixed point";
+const FIXED_24_BIT_ENCODING_ID: u32 = 13;
+const FIXED_24_BIT_ENCODING_NAME: &str = "24-bit fixed point";
+const FIXED_32_BIT_ENCODING_ID: u32 = 14;
+const FIXED_32_BIT_ENCODING_NAME: &str = "32-bit fixed point";
+const EMPHASIS_ENCODING_ID: u32 = 18;
+const EMPHASIS_ENCODING_NAME: &str = "16-bit linear PCM with emphasis";
+const COMPRESSED_ENCODING_ID: u32 = 19;
+const COMPRESSED_ENCODING_NAME: &str = "16-bit linear PCM compressed";
+const EMPHASIS_COMPRESSED_ENCODING_ID: u32 = 20;
+const EMPHASIS_COMPRESSED_ENCODING_NAME: &str = "16-bit linear PCM with emphasis and compression";
+const DSP_COMMANDS_ENCODING_ID: u32 = 21;
+const DSP_COMMANDS_ENCODING_NAME: &str = "Music Kit DSP commands";
+const G721_ENCODING_ID: u32 = 23;
+const G721_ENCODING_NAME: &str = "4-bit ITU-T G.721 ADPCM";
+const G722_ENCODING_ID: u32 = 24;
+const G722_ENCODING_NAME: &str = "ITU-T G.722 SB-ADPCM";
+const G723_3_BIT_ENCODING_ID: u32 = 25;
+const G723_3_BIT_ENCODING_NAME: &str = "3-bit ITU-T G.723 ADPCM";
+const G723_5_BIT_ENCODING_ID: u32 = 26;
+const G723_5_BIT_ENCODING_NAME: &str = "5-bit ITU-T G.723 ADPCM";
+const ALAW_ENCODING_ID: u32 = 27;
+const ALAW_ENCODING_NAME: &str = "8-bit ITU-T G.711 A-law";
+const UNKNOWN_ENCODING: &str = "Unknown Encoding ID:";
+
+pub fn get_metadata_from_file(
+    au_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut au_file = File::open(au_file_path)?;
+    let file_metadata = get_file_metadata(au_file_path, &au_file, TEMPLATE_CONTENT)?;
+
+    let mut header_bytes = read_bytes_from_file(&mut au_file, HEADER_LENGTH_IN_BYTES)?;
+    let data_offset = get_data_offset_from_header_bytes(&header_bytes)?;
+
+    let remaining_file_length = au_file.metadata()?.len() as usize - HEADER_LENGTH_IN_BYTES;
+    let annotation_length = (data_offset as usize)
+        .saturating_sub(HEADER_LENGTH_IN_BYTES)
+        .min(remaining_file_length);
+    let mut annotation = String::new();
+    if !mandatory_sections_only && annotation_length > 0 {
+        header_bytes.extend(read_up_to_number_of_bytes_from_file(
+            &mut au_file,
+            annotation_length,
+        )?);
+        annotation = get_annotation_from_bytes(&header_bytes[HEADER_LENGTH_IN_BYTES..])?;
+    }
+
+    header_bytes.truncate(HEADER_LENGTH_IN_BYTES);
+    let header_metadata = get_header_metadata(header_bytes, annotation)?;
+
+    Ok(vec![file_metadata, header_metadata])
+}
+
+fn get_data_offset_from_header_bytes(header_bytes: &[u8]) -> Result<u32, Box<dyn Error>> {
+    let mut data_offset_bytes = header_bytes.to_vec();
+    skip_over_bytes(&mut data_offset_bytes, MAGIC_NUMBER_LENGTH_IN_BYTES)?;
+    Ok(take_first_four_bytes_as_unsigned_integer(
+        &mut data_offset_bytes,
+        Endian::Big,
+    )?)
+}
+
+fn get_annotation_from_bytes(annotation_bytes: &[u8]) -> Result<String, Box<dyn Error>> {
+    let annotation_length = annotation_bytes
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(annotation_bytes.len());
+    let mut annotation_bytes = annotation_bytes.to_vec();
+
+    Ok(
+        take_first_number_of_bytes_as_string(&mut annotation_bytes, annotation_length)?
+            .trim()
+            .to_string(),
+    )
+}
+
+fn get_header_metadata(
+    mut header_bytes: Vec<u8>,
+    annotation: String,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes(&mut header_bytes, MAGIC_NUMBER_LENGTH_IN_BYTES)?;
+    let data_offset = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Big)?;
+    let data_size = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Big)?;
+    let encoding = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Big)?;
+    let sample_rate = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Big)?;
+    let channels = take_first_four_bytes_as_unsigned_integer(&mut header_bytes, Endian::Big)?;
+
+    let output_values: Value = upon::value! {
+        data_offset: data_offset,
+        data_size: format_data_size(data_size),
+        encoding: get_encoding_name_from_encoding_id(encoding),
+        sample_rate: sample_rate,
+        channels: channels,
+        annotation: annotation,
+    };
+
+    let formated_output = get_file_chunk_output(HEADER_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn format_data_size(data_size: u32) -> String {
+    if data_size == UNKNOWN_DATA_SIZE {
+        return UNKNOWN_DATA_SIZE_NAME.to_string();
+    }
+
+    format_file_size_as_string(data_size as u64)
+}
+
+fn get_encoding_name_from_encoding_id(encoding_id: u32) -> String {
+    match encoding_id {
+        MULAW_ENCODING_ID => MULAW_ENCODING_NAME.to_string(),
+        LINEAR_8_BIT_ENCODING_ID => LINEAR_8_BIT_ENCODING_NAME.to_string(),
+        LINEAR_16_BIT_ENCODING_ID => LINEAR_16_BIT_ENCODING_NAME.to_string(),
+        LINEAR_24_BIT_ENCODING_ID => LINEAR_24_BIT_ENCODING_NAME.to_string(),
+        LINEAR_32_BIT_ENCODING_ID => LINEAR_32_BIT_ENCODING_NAME.to_string(),
+        FLOAT_ENCODING_ID => FLOAT_ENCODING_NAME.to_string(),
+        DOUBLE_ENCODING_ID => DOUBLE_ENCODING_NAME.to_string(),
+        FRAGMENTED_ENCODING_ID => FRAGMENTED_ENCODING_NAME.to_string(),
+        DSP_PROGRAM_ENCODING_ID => DSP_PROGRAM_ENCODING_NAME.to_string(),
+        FIXED_8_BIT_ENCODING_ID => FIXED_8_BIT_ENCODING_NAME.to_string(),
+        FIXED_16_BIT_ENCODING_ID => FIXED_16_BIT_ENCODING_NAME.to_string(),
+        FIXED_24_BIT_ENCODING_ID => FIXED_24_BIT_ENCODING_NAME.to_string(),
+        FIXED_32_BIT_ENCODING_ID => FIXED_32_BIT_ENCODING_NAME.to_string(),
+        EMPHASIS_ENCODING_ID => EMPHASIS_ENCODING_NAME.to_string(),
+        COMPRESSED_ENCODING_ID => COMPRESSED_ENCODING_NAME.to_string(),
+        EMPHASIS_COMPRESSED_ENCODING_ID => EMPHASIS_COMPRESSED_ENCODING_NAME.to_string(),
+        DSP_COMMANDS_ENCODING_ID => DSP_COMMANDS_ENCODING_NAME.to_string(),
+        G721_ENCODING_ID => G721_ENCODING_NAME.to_string(),
+        G722_ENCODING_ID => G722_ENCODING_NAME.to_string(),
+        G723_3_BIT_ENCODING_ID => G723_3_BIT_ENCODING_NAME.to_string(),
+        G723_5_BIT_ENCODING_ID => G723_5_BIT_ENCODING_NAME.to_string(),
+        ALAW_ENCODING_ID => ALAW_ENCODING_NAME.to_string(),
+        _ => format!("{} {}", UNKNOWN_ENCODING, encoding_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_unknown_for_unknown_data_size() {
+        assert_eq!(format_data_size(UNKNOWN_DATA_SIZE), "Unknown");
+    }
+
+    #[test]
+    fn return_annotation_up_to_first_null_byte() {
+        let annotation_bytes = b"Recorded on line 4\0\0\0junk".to_vec();
+        let result = get_annotation_from_bytes(&annotation_bytes).unwrap();
+        assert_eq!(result, "Recorded on line 4");
+    }
+}
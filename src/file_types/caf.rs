@@ -21,8 +21,7 @@ pub fn get_metadata_from_file(
     let file_metadata = get_file_metadata(file_path, &caf_file, TEMPLATE_CONTENT)?;
     let header_metadata = get_file_header_metadata(&mut caf_file)?;
 
-    let chunk_metadata =
-        get_metadata_from_caf_chunks(&mut caf_file, file_path, mandatory_sections_only)?;
+    let chunk_metadata = get_metadata_from_caf_chunks(&mut caf_file, mandatory_sections_only)?;
 
     let mut output = vec![file_metadata, header_metadata];
     output.extend(chunk_metadata);
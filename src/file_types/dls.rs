@@ -0,0 +1,28 @@
+use crate::dls_chunks::get_metadata_from_dls_data;
+use crate::fileio::{get_file_metadata, skip_over_bytes_in_file};
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/dls.tmpl");
+const DLS_HEADER_FIELDS_LENGTH_IN_BYTES: usize = 12;
+
+pub fn get_metadata_from_file(
+    dls_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut dls_file = File::open(dls_file_path)?;
+    skip_over_bytes_in_file(&mut dls_file, DLS_HEADER_FIELDS_LENGTH_IN_BYTES)?;
+
+    let file_metadata = get_file_metadata(dls_file_path, &dls_file, TEMPLATE_CONTENT)?;
+
+    let mut dls_data: Vec<u8> = Vec::new();
+    dls_file.read_to_end(&mut dls_data)?;
+    let chunk_metadata = get_metadata_from_dls_data(dls_data, mandatory_sections_only)?;
+
+    let mut output = vec![file_metadata];
+    output.extend(chunk_metadata);
+
+    Ok(output)
+}
@@ -0,0 +1,63 @@
+use crate::byte_arrays::Endian;
+use crate::chunks::skipped;
+use crate::dsd_chunks::{get_dsdiff_chunk_metadata, FORMAT_VERSION_CHUNK_ID, PROPERTY_CHUNK_ID};
+use crate::fileio::{
+    get_file_metadata, read_64bit_chunk_size_from_file, read_bytes_from_file,
+    read_chunk_id_from_file, skip_over_bytes_in_file,
+};
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/dsdiff.tmpl");
+const FORM_HEADER_LENGTH_IN_BYTES: usize = 16;
+const MANDATORY_CHUNKS: [&str; 2] = [FORMAT_VERSION_CHUNK_ID, PROPERTY_CHUNK_ID];
+const CHUNKS_NOT_TO_EXTRACT_DATA_FROM: [&str; 3] = ["dsd ", "dst ", "dsti"];
+
+pub fn get_metadata_from_file(
+    dsdiff_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut dsdiff_file = File::open(dsdiff_file_path)?;
+    let file_metadata = get_file_metadata(dsdiff_file_path, &dsdiff_file, TEMPLATE_CONTENT)?;
+    skip_over_bytes_in_file(&mut dsdiff_file, FORM_HEADER_LENGTH_IN_BYTES)?;
+
+    let mut output = vec![file_metadata];
+    let file_length = dsdiff_file.metadata()?.len();
+
+    loop {
+        let chunk_id = read_chunk_id_from_file(&mut dsdiff_file)?;
+
+        if chunk_id.is_empty() {
+            break;
+        }
+
+        let chunk_size = read_64bit_chunk_size_from_file(&mut dsdiff_file, Endian::Big)?;
+
+        if chunk_size > file_length - dsdiff_file.stream_position()? {
+            output.push(skipped::get_metadata(format!("{} (Truncated)", chunk_id))?);
+            break;
+        }
+
+        let chunk_size = chunk_size as usize;
+        let padded_chunk_size = chunk_size + chunk_size % 2;
+
+        if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
+            skip_over_bytes_in_file(&mut dsdiff_file, padded_chunk_size)?;
+            continue;
+        }
+
+        if CHUNKS_NOT_TO_EXTRACT_DATA_FROM.contains(&chunk_id.as_str()) {
+            skip_over_bytes_in_file(&mut dsdiff_file, padded_chunk_size)?;
+            output.push(skipped::get_metadata(chunk_id)?);
+            continue;
+        }
+
+        let chunk_data = read_bytes_from_file(&mut dsdiff_file, chunk_size)?;
+        skip_over_bytes_in_file(&mut dsdiff_file, padded_chunk_size - chunk_size)?;
+        output.push(get_dsdiff_chunk_metadata(chunk_id, chunk_data)?);
+    }
+
+    Ok(output)
+}
@@ -0,0 +1,106 @@
+use crate::byte_arrays::Endian;
+use crate::chunks::{extra, id3, skipped, CHUNK_ID_FIELD_LENGTH_IN_BYTES};
+use crate::dsd_chunks::{dsd, fmt, DSDIFF_CHUNK_SIZE_FIELD_LENGTH_IN_BYTES};
+use crate::fileio::{
+    get_file_metadata, read_64bit_chunk_size_from_file, read_bytes_from_file,
+    read_chunk_id_from_file,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/dsf.tmpl");
+const EMPTY_TEMPLATE_CONTENT: &str = include_str!("../templates/chunks/empty.tmpl");
+const DSD_CHUNK_ID: &str = "dsd ";
+const FMT_CHUNK_ID: &str = "fmt ";
+const DATA_CHUNK_ID: &str = "data";
+const CHUNK_HEADER_LENGTH_IN_BYTES: usize =
+    CHUNK_ID_FIELD_LENGTH_IN_BYTES + DSDIFF_CHUNK_SIZE_FIELD_LENGTH_IN_BYTES;
+
+pub fn get_metadata_from_file(
+    dsf_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut dsf_file = File::open(dsf_file_path)?;
+    let file_metadata = get_file_metadata(dsf_file_path, &dsf_file, TEMPLATE_CONTENT)?;
+
+    let mut output = vec![file_metadata];
+    let mut metadata_pointer: u64 = 0;
+    let file_length = dsf_file.metadata()?.len();
+
+    loop {
+        let chunk_id = read_chunk_id_from_file(&mut dsf_file)?;
+
+        if chunk_id.is_empty() {
+            break;
+        }
+
+        let chunk_size = read_64bit_chunk_size_from_file(&mut dsf_file, Endian::Little)?;
+        let data_size = (chunk_size as usize).saturating_sub(CHUNK_HEADER_LENGTH_IN_BYTES);
+
+        if chunk_id == DATA_CHUNK_ID {
+            if !mandatory_sections_only {
+                output.push(skipped::get_metadata(chunk_id)?);
+            }
+            break;
+        }
+
+        if data_size as u64 > file_length - dsf_file.stream_position()? {
+            output.push(skipped::get_metadata(format!("{} (Truncated)", chunk_id))?);
+            break;
+        }
+
+        let chunk_data = read_bytes_from_file(&mut dsf_file, data_size)?;
+
+        match chunk_id.as_str() {
+            DSD_CHUNK_ID => {
+                metadata_pointer = dsd::get_metadata_pointer_from_bytes(&chunk_data);
+                output.push(dsd::get_metadata(chunk_data)?);
+            }
+            FMT_CHUNK_ID => output.push(fmt::get_metadata(chunk_data)?),
+            _ if mandatory_sections_only => {}
+            _ => output.push(extra::get_metadata(chunk_id, chunk_data)?),
+        }
+    }
+
+    if metadata_pointer > 0 && !mandatory_sections_only {
+        let id3_metadata = match metadata_pointer < dsf_file.metadata()?.len() {
+            true => get_id3_metadata_from_file(&mut dsf_file, metadata_pointer).ok(),
+            false => None,
+        };
+
+        match id3_metadata {
+            Some(id3_metadata) => output.push(id3_metadata),
+            None => output.push(get_unreadable_id3_metadata(metadata_pointer)?),
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_id3_metadata_from_file(
+    dsf_file: &mut File,
+    metadata_pointer: u64,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    dsf_file.seek(SeekFrom::Start(metadata_pointer))?;
+    let mut id3_data: Vec<u8> = Vec::new();
+    dsf_file.read_to_end(&mut id3_data)?;
+
+    id3::get_metadata_from_bytes(id3_data)
+}
+
+fn get_unreadable_id3_metadata(metadata_pointer: u64) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        body: format!("ID3 (Unreadable Tag at Offset {})", metadata_pointer),
+    };
+
+    let formated_output = get_file_chunk_output(EMPTY_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Empty,
+        text: formated_output,
+    })
+}
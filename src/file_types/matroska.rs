@@ -0,0 +1,208 @@
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
+use crate::chunks::skipped;
+use crate::ebml_elements::{
+    attachments, chapters, get_element_id_from_bytes, get_element_id_length_from_first_byte,
+    get_element_size_from_bytes, get_element_size_length_from_first_byte, header, info, tags,
+    tracks, ATTACHMENTS_ELEMENT_ID, CHAPTERS_ELEMENT_ID, CLUSTER_ELEMENT_ID, CUES_ELEMENT_ID,
+    EBML_HEADER_ELEMENT_ID, INFO_ELEMENT_ID, SEEK_HEAD_ELEMENT_ID, SEGMENT_ELEMENT_ID,
+    TAGS_ELEMENT_ID, TRACKS_ELEMENT_ID, VOID_ELEMENT_ID,
+};
+use crate::fileio::{
+    get_file_metadata, read_byte_from_file, read_bytes_from_file, skip_over_bytes_in_file,
+};
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/matroska.tmpl");
+const MANDATORY_ELEMENTS: [u32; 3] = [EBML_HEADER_ELEMENT_ID, INFO_ELEMENT_ID, TRACKS_ELEMENT_ID];
+const ELEMENT_NAMES: [(u32, &str); 10] = [
+    (EBML_HEADER_ELEMENT_ID, "EBML Header"),
+    (INFO_ELEMENT_ID, "Info"),
+    (TRACKS_ELEMENT_ID, "Tracks"),
+    (TAGS_ELEMENT_ID, "Tags"),
+    (CHAPTERS_ELEMENT_ID, "Chapters"),
+    (ATTACHMENTS_ELEMENT_ID, "Attachments"),
+    (SEEK_HEAD_ELEMENT_ID, "SeekHead"),
+    (CLUSTER_ELEMENT_ID, "Cluster"),
+    (CUES_ELEMENT_ID, "Cues"),
+    (VOID_ELEMENT_ID, "Void"),
+];
+
+const TOP_LEVEL_ELEMENT_IDS: [u32; 10] = [
+    EBML_HEADER_ELEMENT_ID,
+    SEGMENT_ELEMENT_ID,
+    SEEK_HEAD_ELEMENT_ID,
+    INFO_ELEMENT_ID,
+    TRACKS_ELEMENT_ID,
+    CLUSTER_ELEMENT_ID,
+    CUES_ELEMENT_ID,
+    ATTACHMENTS_ELEMENT_ID,
+    CHAPTERS_ELEMENT_ID,
+    TAGS_ELEMENT_ID,
+];
+
+struct ElementHeader {
+    id: u32,
+    size: Option<u64>,
+}
+
+pub fn get_metadata_from_file(
+    matroska_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut matroska_file = File::open(matroska_file_path)?;
+    let file_metadata = get_file_metadata(matroska_file_path, &matroska_file, TEMPLATE_CONTENT)?;
+    let element_metadata =
+        get_metadata_from_top_level_elements(&mut matroska_file, mandatory_sections_only)?;
+
+    let mut output = vec![file_metadata];
+    output.extend(element_metadata);
+
+    Ok(output)
+}
+
+fn get_metadata_from_top_level_elements(
+    matroska_file: &mut File,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+    let mut skipped_element_ids: Vec<u32> = vec![];
+    let file_length = matroska_file.metadata()?.len();
+    let mut segment_end: Option<u64> = None;
+    let mut is_inside_unknown_size_element = false;
+    let mut truncated_element_id: Option<u32> = None;
+
+    loop {
+        let position = matroska_file.stream_position()?;
+        if position >= file_length || segment_end.is_some_and(|end| position >= end) {
+            break;
+        }
+
+        let Some(element_header) = read_element_header_from_file(matroska_file)? else {
+            break;
+        };
+
+        // Unknown-size elements (e.g. live Clusters) end at the next top-level element.
+        if is_inside_unknown_size_element && !TOP_LEVEL_ELEMENT_IDS.contains(&element_header.id) {
+            if let Some(element_size) = element_header.size {
+                skip_over_bytes_in_file(matroska_file, element_size as usize)?;
+            }
+            continue;
+        }
+        is_inside_unknown_size_element = false;
+
+        if element_header.id == SEGMENT_ELEMENT_ID {
+            segment_end = element_header
+                .size
+                .map(|size| matroska_file.stream_position().unwrap_or_default() + size);
+            continue;
+        }
+
+        let Some(element_size) = element_header.size else {
+            add_skipped_element_id(&mut skipped_element_ids, element_header.id);
+            is_inside_unknown_size_element = true;
+            continue;
+        };
+
+        if !is_decoded_element(element_header.id)
+            || (mandatory_sections_only && !MANDATORY_ELEMENTS.contains(&element_header.id))
+        {
+            add_skipped_element_id(&mut skipped_element_ids, element_header.id);
+            skip_over_bytes_in_file(matroska_file, element_size as usize)?;
+            continue;
+        }
+
+        if element_size > file_length - matroska_file.stream_position()? {
+            truncated_element_id = Some(element_header.id);
+            break;
+        }
+
+        let element_data = read_bytes_from_file(matroska_file, element_size as usize)?;
+        output.push(get_element_metadata(element_header.id, element_data)?);
+    }
+
+    if !mandatory_sections_only {
+        for element_id in skipped_element_ids {
+            output.push(skipped::get_metadata(get_element_name(element_id))?);
+        }
+    }
+
+    if let Some(element_id) = truncated_element_id {
+        output.push(skipped::get_metadata(format!(
+            "{} (Truncated)",
+            get_element_name(element_id)
+        ))?);
+    }
+
+    Ok(output)
+}
+
+fn read_element_header_from_file(
+    matroska_file: &mut File,
+) -> Result<Option<ElementHeader>, Box<dyn Error>> {
+    let first_id_byte = match read_byte_from_file(matroska_file) {
+        Ok(byte) => byte,
+        Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+            return Ok(None);
+        }
+        Err(error) => return Err(error),
+    };
+    let id_length = get_element_id_length_from_first_byte(first_id_byte)?;
+    let mut id_bytes = vec![first_id_byte];
+    id_bytes.extend(read_bytes_from_file(matroska_file, id_length - 1)?);
+
+    let first_size_byte = read_byte_from_file(matroska_file)?;
+    let size_length = get_element_size_length_from_first_byte(first_size_byte)?;
+    let mut size_bytes = vec![first_size_byte];
+    size_bytes.extend(read_bytes_from_file(matroska_file, size_length - 1)?);
+
+    Ok(Some(ElementHeader {
+        id: get_element_id_from_bytes(&id_bytes),
+        size: get_element_size_from_bytes(&size_bytes),
+    }))
+}
+
+fn is_decoded_element(element_id: u32) -> bool {
+    matches!(
+        element_id,
+        EBML_HEADER_ELEMENT_ID
+            | INFO_ELEMENT_ID
+            | TRACKS_ELEMENT_ID
+            | TAGS_ELEMENT_ID
+            | CHAPTERS_ELEMENT_ID
+            | ATTACHMENTS_ELEMENT_ID
+    )
+}
+
+fn get_element_metadata(
+    element_id: u32,
+    element_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let result = match element_id {
+        EBML_HEADER_ELEMENT_ID => header::get_metadata(element_data)?,
+        INFO_ELEMENT_ID => info::get_metadata(element_data)?,
+        TRACKS_ELEMENT_ID => tracks::get_metadata(element_data)?,
+        TAGS_ELEMENT_ID => tags::get_metadata(element_data)?,
+        CHAPTERS_ELEMENT_ID => chapters::get_metadata(element_data)?,
+        ATTACHMENTS_ELEMENT_ID => attachments::get_metadata(element_data)?,
+        _ => skipped::get_metadata(get_element_name(element_id))?,
+    };
+
+    Ok(result)
+}
+
+fn add_skipped_element_id(skipped_element_ids: &mut Vec<u32>, element_id: u32) {
+    if !skipped_element_ids.contains(&element_id) {
+        skipped_element_ids.push(element_id);
+    }
+}
+
+fn get_element_name(element_id: u32) -> String {
+    ELEMENT_NAMES
+        .iter()
+        .find(|(id, _)| *id == element_id)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown Element {:#X}", element_id))
+}
@@ -1,19 +1,39 @@
-use crate::chunks::id3::get_metadata;
+use crate::chunks::id3::{get_common_fields_from_file, get_metadata};
 use crate::errors::LocalError;
 use crate::file_types::Mp3SubType;
-use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::fileio::{
+    get_file_metadata, read_bytes_from_file, read_up_to_number_of_bytes_from_file,
+};
 use crate::formating::format_bit_as_bool_string;
+use crate::mp3_frames::get_metadata_from_first_frame;
+use crate::mp3_frames::scan::get_metadata_from_file as get_frame_scan_metadata_from_file;
 use crate::output::{OutputEntry, Section};
 use crate::template::get_file_chunk_output;
+use crate::trailing_tags::{comparison, read_trailing_tags_from_file};
 use std::error::Error;
 use std::fs::File;
+use std::io::{Seek, SeekFrom};
 use upon::Value;
 
 const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp3.tmpl");
 const HEADER_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp3_header.tmpl");
-const ID3_HEADER_BYTES_BEFORE_ID3_SIZE_BYTES: usize = 6;
-const ID3_HEADER_SIZE_LENGTH_IN_BYTES: usize = 4;
-const MP3_HEADER_LENGTH_IN_BYTES: usize = 4;
+const NO_SYNC_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp3_no_sync.tmpl");
+const ID3_FILE_IDENTIFIER: &[u8] = "ID3".as_bytes();
+const ID3_HEADER_LENGTH_IN_BYTES: usize = 10;
+const ID3_FOOTER_LENGTH_IN_BYTES: usize = 10;
+const ID3_HEADER_FLAGS_OFFSET: usize = 5;
+const ID3_HEADER_SIZE_OFFSET: usize = 6;
+const ID3_FOOTER_PRESENT_FLAG: u8 = 0x10;
+pub const SYNC_SEARCH_LENGTH_IN_BYTES: usize = 65536;
+pub const MP3_HEADER_LENGTH_IN_BYTES: usize = 4;
+const FIRST_FRAME_PEEK_LENGTH_IN_BYTES: usize = 256;
+const FRAME_SYNC_BYTE: u8 = 0xFF;
+const FRAME_SYNC_SECOND_BYTE_MASK: u8 = 0xE0;
+const FREE_FORMAT_BITRATE_INDEX: u8 = 0;
+pub const MAXIMUM_FREE_FORMAT_FRAME_LENGTH_IN_BYTES: usize = 8192;
+const FREE_FORMAT_FRAME_LENGTH_TOLERANCE_IN_BYTES: usize = 4;
+const RESERVED_SAMPLE_RATE_INDEX: u8 = 3;
+pub const CRC_LENGTH_IN_BYTES: usize = 2;
 
 const MAX_SYNC_SAFE_INTEGER_BYTE_VALUE: u8 = 0x7F;
 
@@ -25,10 +45,14 @@ const MPEG_AUDIO_VERSION_IDS: [&str; 4] = [
 ];
 
 const VERSION_RESERVED_VALUE: u8 = 1;
+const VERSION_1_VALUE: u8 = 3;
 
 const MPEG_LAYER_DESCRIPTION: [&str; 4] = ["Reserved", "Layer III", "Layer II", "Layer I"];
 
 const LAYER_RESERVED_VALUE: u8 = 0;
+const LAYER_I_VALUE: u8 = 3;
+const LAYER_II_VALUE: u8 = 2;
+const SINGLE_CHANNEL_MODE: u8 = 3;
 
 const MPEG_BITRATE_INDEX: [[[u16; 3]; 2]; 15] = [
     [[0, 0, 0], [0, 0, 0]],
@@ -36,7 +60,7 @@ const MPEG_BITRATE_INDEX: [[[u16; 3]; 2]; 15] = [
     [[64, 48, 40], [48, 16, 16]],
     [[96, 56, 48], [56, 24, 24]],
     [[128, 64, 56], [64, 32, 32]],
-    [[60, 80, 64], [80, 40, 40]],
+    [[160, 80, 64], [80, 40, 40]],
     [[192, 96, 80], [96, 48, 48]],
     [[224, 112, 96], [112, 56, 56]],
     [[256, 128, 112], [128, 64, 64]],
@@ -73,31 +97,229 @@ const MPEG_MODE_EXTENSION_LAYERS_III: [(&str, &str); 4] =
 
 const MPEG_EMPHASIS: [&str; 4] = ["None", "50/15 ms", "Reserved", "CCIT J.17"];
 
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct FrameHeader {
+    pub version: u8,
+    pub layer: u8,
+    pub is_protected: bool,
+    pub bitrate: u16,
+    pub sample_rate: u32,
+    pub is_padded: bool,
+    pub channel_mode: u8,
+}
+
+impl FrameHeader {
+    pub fn is_free_format(&self) -> bool {
+        self.bitrate == 0
+    }
+
+    pub fn get_free_format_bitrate(&self, frame_length: usize) -> u16 {
+        (frame_length as u64 * 8 * self.sample_rate as u64 / self.samples_per_frame() as u64 / 1000)
+            as u16
+    }
+
+    pub fn padding_length(&self) -> usize {
+        match (self.is_padded, self.layer) {
+            (false, _) => 0,
+            (true, LAYER_I_VALUE) => 4,
+            (true, _) => 1,
+        }
+    }
+
+    pub fn is_layer_iii(&self) -> bool {
+        self.layer != LAYER_I_VALUE && self.layer != LAYER_II_VALUE
+    }
+
+    pub fn samples_per_frame(&self) -> u32 {
+        match self.layer {
+            LAYER_I_VALUE => 384,
+            LAYER_II_VALUE => 1152,
+            _ if self.version == VERSION_1_VALUE => 1152,
+            _ => 576,
+        }
+    }
+
+    pub fn frame_length(&self) -> usize {
+        if self.bitrate == 0 || self.sample_rate == 0 {
+            return 0;
+        }
+
+        let bitrate = self.bitrate as usize * 1000;
+        let sample_rate = self.sample_rate as usize;
+
+        match self.layer {
+            LAYER_I_VALUE => (12 * bitrate / sample_rate + self.is_padded as usize) * 4,
+            _ => {
+                self.samples_per_frame() as usize / 8 * bitrate / sample_rate
+                    + self.is_padded as usize
+            }
+        }
+    }
+
+    pub fn side_information_length(&self) -> usize {
+        match (
+            self.version == VERSION_1_VALUE,
+            self.channel_mode == SINGLE_CHANNEL_MODE,
+        ) {
+            (true, false) => 32,
+            (true, true) | (false, false) => 17,
+            (false, true) => 9,
+        }
+    }
+
+    pub fn side_information_offset(&self) -> usize {
+        let crc_length = match self.is_protected {
+            true => CRC_LENGTH_IN_BYTES,
+            false => 0,
+        };
+
+        MP3_HEADER_LENGTH_IN_BYTES + crc_length + self.side_information_length()
+    }
+}
+
+pub fn get_frame_header_from_bytes(header_bytes: &[u8]) -> Option<FrameHeader> {
+    if header_bytes.len() < MP3_HEADER_LENGTH_IN_BYTES
+        || header_bytes[0] != FRAME_SYNC_BYTE
+        || header_bytes[1] & FRAME_SYNC_SECOND_BYTE_MASK != FRAME_SYNC_SECOND_BYTE_MASK
+    {
+        return None;
+    }
+
+    let version = (header_bytes[1] >> 3) & 3;
+    let layer = (header_bytes[1] >> 1) & 3;
+    let bitrate_index = header_bytes[2] >> 4;
+    let sample_rate_index = (header_bytes[2] >> 2) & 3;
+
+    if version == VERSION_RESERVED_VALUE
+        || layer == LAYER_RESERVED_VALUE
+        || sample_rate_index == RESERVED_SAMPLE_RATE_INDEX
+    {
+        return None;
+    }
+
+    let bitrate = match bitrate_index {
+        FREE_FORMAT_BITRATE_INDEX => 0,
+        index => get_bitrate_from_index(index, version, layer).ok()?,
+    };
+
+    Some(FrameHeader {
+        version,
+        layer,
+        is_protected: header_bytes[1] & 1 == 0,
+        bitrate,
+        sample_rate: MPEG_SAMPLE_RATE_INDEX[sample_rate_index as usize][version as usize] as u32,
+        is_padded: (header_bytes[2] >> 1) & 1 == 1,
+        channel_mode: header_bytes[3] >> 6,
+    })
+}
+
 pub fn get_metadata_from_file(
     file_path: &str,
     subtype: Mp3SubType,
     mandatory_sections_only: bool,
+    deep_scan: bool,
 ) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
     let mut mp3_file = File::open(file_path)?;
     let file_metadata = get_file_metadata(file_path, &mp3_file, TEMPLATE_CONTENT)?;
     let mut output = vec![file_metadata];
 
-    if subtype == Mp3SubType::ID3 {
-        if !mandatory_sections_only {
-            let id3_metadata = get_metadata(file_path)?;
-            output.push(id3_metadata);
+    if subtype == Mp3SubType::ID3 && !mandatory_sections_only {
+        let id3_metadata = get_metadata(file_path)?;
+        output.push(id3_metadata);
+    }
+
+    skip_over_id3_data_in_file(&mut mp3_file)?;
+    let search_start = mp3_file.stream_position()?;
+    let search_bytes =
+        read_up_to_number_of_bytes_from_file(&mut mp3_file, SYNC_SEARCH_LENGTH_IN_BYTES)?;
+    let trailing_tags = read_trailing_tags_from_file(&mut mp3_file)?;
+
+    match find_first_frame_in_bytes(&search_bytes) {
+        Some(frame_offset) => {
+            let stream_start = search_start + frame_offset as u64;
+            output.extend(get_stream_metadata_from_file(
+                &mut mp3_file,
+                stream_start,
+                trailing_tags.start.saturating_sub(stream_start),
+                deep_scan,
+            )?);
+        }
+        None => output.push(get_no_sync_metadata(search_start, search_bytes.len())?),
+    }
+
+    if !mandatory_sections_only {
+        output.extend(trailing_tags.get_metadata()?);
+
+        let mut tag_fields = vec![];
+        if subtype == Mp3SubType::ID3 {
+            if let Some(id3v2_fields) = get_common_fields_from_file(file_path) {
+                tag_fields.push(("ID3v2", id3v2_fields));
+            }
+        }
+        tag_fields.extend(trailing_tags.get_common_fields()?);
+
+        if let Some(comparison_metadata) = comparison::get_metadata(tag_fields)? {
+            output.push(comparison_metadata);
         }
-        skip_over_id3_data_in_file(&mut mp3_file)?;
     }
 
-    let mp3_header_bytes = read_bytes_from_file(&mut mp3_file, MP3_HEADER_LENGTH_IN_BYTES)?;
-    let header_metadata = get_header_metadata(mp3_header_bytes)?;
-    output.push(header_metadata);
+    Ok(output)
+}
+
+fn get_stream_metadata_from_file(
+    mp3_file: &mut File,
+    stream_start: u64,
+    stream_length: u64,
+    deep_scan: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    mp3_file.seek(SeekFrom::Start(stream_start))?;
+
+    let mp3_header_bytes = read_bytes_from_file(mp3_file, MP3_HEADER_LENGTH_IN_BYTES)?;
+    let mut first_frame_bytes = mp3_header_bytes.clone();
+    first_frame_bytes.extend(read_up_to_number_of_bytes_from_file(
+        mp3_file,
+        FIRST_FRAME_PEEK_LENGTH_IN_BYTES - MP3_HEADER_LENGTH_IN_BYTES,
+    )?);
+
+    let mut output = vec![get_header_metadata(mp3_header_bytes, stream_start)?];
+
+    if let Some(frame_header) = get_frame_header_from_bytes(&first_frame_bytes) {
+        output.extend(get_metadata_from_first_frame(
+            &first_frame_bytes,
+            &frame_header,
+            stream_length,
+        )?);
+    }
+
+    if deep_scan {
+        mp3_file.seek(SeekFrom::Start(stream_start))?;
+        output.push(get_frame_scan_metadata_from_file(mp3_file)?);
+    }
 
     Ok(output)
 }
 
-fn get_header_metadata(header_bytes: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+fn get_no_sync_metadata(
+    search_start: u64,
+    search_length: usize,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        search_start: search_start,
+        search_length: search_length,
+    };
+
+    let formated_output = get_file_chunk_output(NO_SYNC_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_header_metadata(
+    header_bytes: Vec<u8>,
+    frame_offset: u64,
+) -> Result<OutputEntry, Box<dyn Error>> {
     let version = (header_bytes[1] >> 3) & 3;
     let layer = (header_bytes[1] >> 1) & 3;
     let protected = header_bytes[1] & 1;
@@ -113,6 +335,7 @@ fn get_header_metadata(header_bytes: Vec<u8>) -> Result<OutputEntry, Box<dyn Err
     let mode_extension_values = get_mode_extension_from_index(mode_extension, layer);
 
     let output_values: Value = upon::value! {
+        frame_offset: frame_offset,
         version: MPEG_AUDIO_VERSION_IDS[version as usize],
         layer: MPEG_LAYER_DESCRIPTION[layer as usize],
         protected: format_bit_as_bool_string(protected),
@@ -136,19 +359,124 @@ fn get_header_metadata(header_bytes: Vec<u8>) -> Result<OutputEntry, Box<dyn Err
     })
 }
 
-fn skip_over_id3_data_in_file(mp3_file: &mut File) -> Result<(), Box<dyn Error>> {
-    skip_over_bytes_in_file(mp3_file, ID3_HEADER_BYTES_BEFORE_ID3_SIZE_BYTES)?;
+pub fn skip_over_id3_data_in_file(mp3_file: &mut File) -> Result<(), Box<dyn Error>> {
+    loop {
+        let tag_start = mp3_file.stream_position()?;
+        let id3_header_bytes =
+            read_up_to_number_of_bytes_from_file(mp3_file, ID3_HEADER_LENGTH_IN_BYTES)?;
+
+        match get_id3_tag_length_from_header_bytes(&id3_header_bytes) {
+            Some(id3_tag_length) => {
+                mp3_file.seek(SeekFrom::Start(tag_start + id3_tag_length as u64))?;
+            }
+            None => {
+                mp3_file.seek(SeekFrom::Start(tag_start))?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn get_id3_tag_length_from_header_bytes(header_bytes: &[u8]) -> Option<usize> {
+    if header_bytes.len() < ID3_HEADER_LENGTH_IN_BYTES
+        || !header_bytes.starts_with(ID3_FILE_IDENTIFIER)
+    {
+        return None;
+    }
+
+    let size_bytes = header_bytes[ID3_HEADER_SIZE_OFFSET..ID3_HEADER_LENGTH_IN_BYTES].to_vec();
+    let tag_size = u32_integer_from_sync_safe_integer(size_bytes).ok()? as usize;
+
+    let footer_length = match header_bytes[ID3_HEADER_FLAGS_OFFSET] & ID3_FOOTER_PRESENT_FLAG {
+        0 => 0,
+        _ => ID3_FOOTER_LENGTH_IN_BYTES,
+    };
+
+    Some(ID3_HEADER_LENGTH_IN_BYTES + tag_size + footer_length)
+}
+
+pub fn find_first_frame_in_bytes(search_bytes: &[u8]) -> Option<usize> {
+    let mut offset = 0;
+
+    while offset + MP3_HEADER_LENGTH_IN_BYTES <= search_bytes.len() {
+        if let Some(id3_tag_length) = get_id3_tag_length_from_header_bytes(&search_bytes[offset..])
+        {
+            offset += id3_tag_length;
+            continue;
+        }
+
+        if is_start_of_consecutive_frames(&search_bytes[offset..]) {
+            return Some(offset);
+        }
+
+        offset += 1;
+    }
+
+    None
+}
+
+fn is_start_of_consecutive_frames(frame_bytes: &[u8]) -> bool {
+    let Some(frame_header) = get_frame_header_from_bytes(frame_bytes) else {
+        return false;
+    };
+
+    if frame_header.is_free_format() {
+        return is_start_of_consecutive_free_format_frames(frame_bytes, &frame_header);
+    }
+
+    let frame_length = frame_header.frame_length();
+    if frame_length == 0 || frame_length >= frame_bytes.len() {
+        return false;
+    }
+
+    match get_frame_header_from_bytes(&frame_bytes[frame_length..]) {
+        Some(next_frame_header) => {
+            next_frame_header.version == frame_header.version
+                && next_frame_header.layer == frame_header.layer
+                && next_frame_header.sample_rate == frame_header.sample_rate
+        }
+        None => false,
+    }
+}
 
-    let id3_chunk_size_bytes = read_bytes_from_file(mp3_file, ID3_HEADER_SIZE_LENGTH_IN_BYTES)?;
-    let id3_chunk_size = u32_integer_from_sync_safe_integer(id3_chunk_size_bytes)?;
-    let remaining_ide3_bytes = id3_chunk_size as usize;
+// Free-format frame lengths only show up as the distance to the next sync, so a third
+// frame at the same spacing is required before trusting them.
+fn is_start_of_consecutive_free_format_frames(
+    frame_bytes: &[u8],
+    frame_header: &FrameHeader,
+) -> bool {
+    let Some(frame_length) = find_free_format_frame_length(frame_bytes, frame_header) else {
+        return false;
+    };
 
-    skip_over_bytes_in_file(mp3_file, remaining_ide3_bytes)?;
+    find_free_format_frame_length(&frame_bytes[frame_length..], frame_header).is_some_and(
+        |next_frame_length| {
+            next_frame_length.abs_diff(frame_length) <= FREE_FORMAT_FRAME_LENGTH_TOLERANCE_IN_BYTES
+        },
+    )
+}
 
-    Ok(())
+pub fn find_free_format_frame_length(
+    frame_bytes: &[u8],
+    frame_header: &FrameHeader,
+) -> Option<usize> {
+    let search_end = frame_bytes
+        .len()
+        .min(MAXIMUM_FREE_FORMAT_FRAME_LENGTH_IN_BYTES);
+
+    (MP3_HEADER_LENGTH_IN_BYTES..search_end).find(|offset| {
+        get_frame_header_from_bytes(&frame_bytes[*offset..]).is_some_and(|next_frame_header| {
+            next_frame_header.is_free_format()
+                && next_frame_header.version == frame_header.version
+                && next_frame_header.layer == frame_header.layer
+                && next_frame_header.sample_rate == frame_header.sample_rate
+        })
+    })
 }
 
-fn u32_integer_from_sync_safe_integer(sync_safe_integer_bytes: Vec<u8>) -> Result<u32, LocalError> {
+pub fn u32_integer_from_sync_safe_integer(
+    sync_safe_integer_bytes: Vec<u8>,
+) -> Result<u32, LocalError> {
     if sync_safe_integer_bytes.iter().max().unwrap_or(&0xFF) > &MAX_SYNC_SAFE_INTEGER_BYTE_VALUE {
         return Err(LocalError::MP3SyncSafeIntegerOverflow(
             MAX_SYNC_SAFE_INTEGER_BYTE_VALUE,
@@ -270,6 +598,60 @@ mod tests {
         assert_eq!(result, expected_error);
     }
 
+    #[test]
+    fn return_id3_tag_length_including_the_footer_when_the_footer_flag_is_set() {
+        let header_bytes = [b'I', b'D', b'3', 4, 0, 0, 0, 0, 0x01, 0x00];
+        assert_eq!(
+            get_id3_tag_length_from_header_bytes(&header_bytes),
+            Some(138)
+        );
+
+        let footer_header_bytes = [b'I', b'D', b'3', 4, 0, 0x10, 0, 0, 0x01, 0x00];
+        assert_eq!(
+            get_id3_tag_length_from_header_bytes(&footer_header_bytes),
+            Some(148)
+        );
+    }
+
+    #[test]
+    fn find_first_frame_after_leading_junk_and_id3_tags() {
+        let mut frame = vec![0xFF, 0xF3, 0x44, 0xC4];
+        frame.resize(96, 0);
+
+        let mut search_bytes = vec![b'I', b'D', b'3', 3, 0, 0, 0, 0, 0, 2, 0, 0];
+        search_bytes.extend([0x00, 0xFF, 0xFB, 0x90, 0x00, 0x12, 0x34]);
+        let first_frame_offset = search_bytes.len();
+        search_bytes.extend(frame.repeat(3));
+
+        assert_eq!(
+            find_first_frame_in_bytes(&search_bytes),
+            Some(first_frame_offset)
+        );
+    }
+
+    #[test]
+    fn return_none_when_no_consecutive_frames_are_found() {
+        let mut search_bytes = vec![0xFF, 0xFB, 0x90, 0x00];
+        search_bytes.resize(1000, 0);
+        assert_eq!(find_first_frame_in_bytes(&search_bytes), None);
+    }
+
+    #[test]
+    fn find_free_format_frames_by_the_distance_to_the_next_sync() {
+        let mut frame = vec![0xFF, 0xFB, 0x00, 0x40];
+        frame.resize(300, 0);
+        let frame_header = get_frame_header_from_bytes(&frame).unwrap();
+
+        assert!(frame_header.is_free_format());
+        assert_eq!(
+            find_free_format_frame_length(&frame.repeat(2), &frame_header),
+            Some(300)
+        );
+        assert_eq!(frame_header.get_free_format_bitrate(300), 91);
+        assert_eq!(find_first_frame_in_bytes(&frame.repeat(3)), Some(0));
+        assert_eq!(find_first_frame_in_bytes(&frame.repeat(2)), None);
+    }
+
     #[test]
     fn return_correct_sample_rate_string_from_valid_raw_sample_rate_with_whole_khz() {
         let test_sample_rate = 2;
@@ -0,0 +1,496 @@
+use crate::byte_arrays::{
+    take_first_eight_bytes_as_unsigned_integer, take_first_four_bytes_as_unsigned_integer, Endian,
+};
+use crate::dj_tags::serato;
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as TrackSummary};
+use crate::mp4_atoms::headers::{
+    get_brand_name, get_brands_from_bytes, get_handler_from_bytes, get_handler_type_name,
+    get_media_header_from_bytes, get_movie_header_from_bytes, get_sample_sizes_from_bytes,
+    get_track_header_from_bytes, Handler, MediaHeader, TrackHeader,
+};
+use crate::mp4_atoms::ilst::{IlstItem, FREEFORM_ATOM_ID};
+use crate::mp4_atoms::sample_entries::{
+    format_codec_name, get_audio_sample_entry_from_bytes, get_video_sample_entry_from_bytes,
+    is_uncompressed_audio_sample_entry, is_video_sample_entry,
+};
+use crate::mp4_atoms::{
+    find_atom, format_duration, get_atom_from_bytes, get_atom_id_from_bytes, ilst, sample_entries,
+    tree, Mp4Atom, ATOM_HEADER_LENGTH_IN_BYTES, EXTENDED_SIZE_LENGTH_IN_BYTES,
+    EXTENDED_SIZE_MARKER, FREE_ATOM_ID, ILST_ATOM_ID, MDAT_ATOM_ID, SKIP_ATOM_ID, STSD_ATOM_ID,
+    TO_END_OF_FILE_SIZE_MARKER, WIDE_ATOM_ID,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use std::io::Seek;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp4.tmpl");
+const MOVIE_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp4_movie.tmpl");
+const TRACK_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/mp4_track.tmpl");
+const OTHER_TRACKS_TEMPLATE_CONTENT: &str =
+    include_str!("../templates/file_types/mp4_other_tracks.tmpl");
+const SKIPPED_DATA_ATOM_IDS: [&str; 4] = [MDAT_ATOM_ID, FREE_ATOM_ID, SKIP_ATOM_ID, WIDE_ATOM_ID];
+const ILST_ATOM_PATH: [&str; 4] = ["moov", "udta", "meta", ILST_ATOM_ID];
+const MOVIE_HEADER_ATOM_PATH: [&str; 2] = ["moov", "mvhd"];
+const TRACK_HEADER_ATOM_PATH: [&str; 1] = ["tkhd"];
+const MEDIA_HEADER_ATOM_PATH: [&str; 2] = ["mdia", "mdhd"];
+const HANDLER_ATOM_PATH: [&str; 2] = ["mdia", "hdlr"];
+const SAMPLE_DESCRIPTION_ATOM_PATH: [&str; 4] = ["mdia", "minf", "stbl", STSD_ATOM_ID];
+const SAMPLE_SIZE_ATOM_PATH: [&str; 4] = ["mdia", "minf", "stbl", "stsz"];
+const CHAPTER_REFERENCE_ATOM_PATH: [&str; 2] = ["tref", "chap"];
+const FILE_TYPE_ATOM_ID: &str = "ftyp";
+const MOVIE_ATOM_ID: &str = "moov";
+const TRACK_ATOM_ID: &str = "trak";
+const SOUND_HANDLER_TYPE: &str = "soun";
+const QUICKTIME_CONTAINER_NAME: &str = "QuickTime Movie";
+const TRACK_ID_LENGTH_IN_BYTES: usize = 4;
+
+#[derive(Debug)]
+struct Track<'a> {
+    header: TrackHeader,
+    media_header: MediaHeader,
+    handler: Handler,
+    sample_entry: Option<&'a Mp4Atom>,
+    sample_size_atom: Option<&'a Mp4Atom>,
+    chapter_track_ids: Vec<u32>,
+}
+
+pub fn get_metadata_from_file(
+    mp4_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut mp4_file = File::open(mp4_file_path)?;
+    let file_metadata = get_file_metadata(mp4_file_path, &mp4_file, TEMPLATE_CONTENT)?;
+
+    let atoms = get_top_level_atoms_from_file(&mut mp4_file)?;
+    let moov_atom = find_atom(&atoms, &[MOVIE_ATOM_ID]).ok_or("The file has no moov atom")?;
+    let tracks = get_tracks_from_atom(moov_atom)?;
+
+    let mut output = vec![file_metadata, get_movie_metadata(&atoms, &tracks)?];
+
+    let (audio_tracks, other_tracks): (Vec<&Track>, Vec<&Track>) = tracks
+        .iter()
+        .partition(|track| track.handler.handler_type == SOUND_HANDLER_TYPE);
+
+    for track in audio_tracks {
+        output.push(get_audio_track_metadata(track)?);
+
+        if let Some(sample_entry) = track.sample_entry {
+            output.extend(sample_entries::get_metadata(
+                sample_entry,
+                mandatory_sections_only,
+            )?);
+        }
+    }
+
+    if !other_tracks.is_empty() {
+        let chapter_track_ids: Vec<u32> = tracks
+            .iter()
+            .flat_map(|track| track.chapter_track_ids.clone())
+            .collect();
+        output.push(get_other_tracks_metadata(
+            &other_tracks,
+            &chapter_track_ids,
+        )?);
+    }
+
+    if !mandatory_sections_only {
+        output.extend(get_ilst_metadata(&atoms)?);
+        output.push(tree::get_metadata(&atoms)?);
+    }
+
+    Ok(output)
+}
+
+fn get_tracks_from_atom(moov_atom: &Mp4Atom) -> Result<Vec<Track<'_>>, Box<dyn Error>> {
+    let mut tracks: Vec<Track> = vec![];
+
+    for track_atom in moov_atom
+        .children
+        .iter()
+        .filter(|atom| atom.id == TRACK_ATOM_ID)
+    {
+        let header = match find_atom(&track_atom.children, &TRACK_HEADER_ATOM_PATH) {
+            Some(atom) => get_track_header_from_bytes(atom.data.clone())?,
+            None => TrackHeader::default(),
+        };
+
+        let media_header = match find_atom(&track_atom.children, &MEDIA_HEADER_ATOM_PATH) {
+            Some(atom) => get_media_header_from_bytes(atom.data.clone())?,
+            None => MediaHeader::default(),
+        };
+
+        let handler = match find_atom(&track_atom.children, &HANDLER_ATOM_PATH) {
+            Some(atom) => get_handler_from_bytes(atom.data.clone())?,
+            None => Handler::default(),
+        };
+
+        let chapter_track_ids = find_atom(&track_atom.children, &CHAPTER_REFERENCE_ATOM_PATH)
+            .map(|atom| {
+                atom.data
+                    .chunks_exact(TRACK_ID_LENGTH_IN_BYTES)
+                    .map(|id| u32::from_be_bytes([id[0], id[1], id[2], id[3]]))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        tracks.push(Track {
+            header,
+            media_header,
+            handler,
+            sample_entry: find_atom(&track_atom.children, &SAMPLE_DESCRIPTION_ATOM_PATH)
+                .and_then(|stsd_atom| stsd_atom.children.first()),
+            sample_size_atom: find_atom(&track_atom.children, &SAMPLE_SIZE_ATOM_PATH),
+            chapter_track_ids,
+        });
+    }
+
+    Ok(tracks)
+}
+
+fn get_movie_metadata(atoms: &[Mp4Atom], tracks: &[Track]) -> Result<OutputEntry, Box<dyn Error>> {
+    let movie_header = match find_atom(atoms, &MOVIE_HEADER_ATOM_PATH) {
+        Some(atom) => get_movie_header_from_bytes(atom.data.clone())?,
+        None => Default::default(),
+    };
+
+    let brands = match find_atom(atoms, &[FILE_TYPE_ATOM_ID]) {
+        Some(atom) => Some(get_brands_from_bytes(atom.data.clone())?),
+        None => None,
+    };
+
+    let container = match &brands {
+        Some(brands) => format!(
+            "{} ({})",
+            get_brand_name(&brands.major_brand),
+            brands.major_brand.trim_end()
+        ),
+        None => QUICKTIME_CONTAINER_NAME.to_string(),
+    };
+
+    let (major_brand, minor_version, compatible_brands) = match brands {
+        Some(brands) => (
+            brands.major_brand.trim_end().to_string(),
+            brands.minor_version,
+            brands
+                .compatible_brands
+                .iter()
+                .map(|brand| brand.trim_end())
+                .collect::<Vec<&str>>()
+                .join(", "),
+        ),
+        None => Default::default(),
+    };
+
+    let output_values: Value = upon::value! {
+        container: container,
+        has_brands: !major_brand.is_empty(),
+        major_brand: major_brand,
+        minor_version: minor_version,
+        compatible_brands: compatible_brands,
+        timescale: movie_header.timescale,
+        duration: format_duration(movie_header.duration, movie_header.timescale),
+        track_summary: get_track_summary(tracks),
+    };
+
+    let formated_output = get_file_chunk_output(MOVIE_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_track_summary(tracks: &[Track]) -> String {
+    let mut track_type_counts: Vec<(String, usize)> = vec![];
+
+    for track in tracks {
+        let type_name = get_handler_type_name(&track.handler.handler_type);
+
+        match track_type_counts
+            .iter_mut()
+            .find(|(name, _)| *name == type_name)
+        {
+            Some((_, count)) => *count += 1,
+            None => track_type_counts.push((type_name, 1)),
+        }
+    }
+
+    let counts = track_type_counts
+        .iter()
+        .map(|(name, count)| format!("{} {}", count, name))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    match tracks.is_empty() {
+        true => "0".to_string(),
+        false => format!("{} ({})", tracks.len(), counts),
+    }
+}
+
+fn get_audio_track_metadata(track: &Track) -> Result<OutputEntry, Box<dyn Error>> {
+    let audio_sample_entry = match track.sample_entry {
+        Some(atom) => get_audio_sample_entry_from_bytes(atom.data.clone())?,
+        None => Default::default(),
+    };
+
+    let sample_sizes = match track.sample_size_atom {
+        Some(atom) => get_sample_sizes_from_bytes(atom.data.clone())?,
+        None => Default::default(),
+    };
+
+    // QuickTime PCM tracks count one stsz sample per frame with a sample size of 1 byte.
+    let total_size = match track.sample_entry {
+        Some(atom)
+            if sample_sizes.sample_size == 1 && is_uncompressed_audio_sample_entry(&atom.id) =>
+        {
+            sample_sizes.total_size as u128 * audio_sample_entry.bytes_per_frame as u128
+        }
+        _ => sample_sizes.total_size as u128,
+    };
+
+    let average_bitrate = get_average_bitrate(
+        total_size,
+        track.media_header.duration,
+        track.media_header.timescale,
+    );
+
+    let output_values: Value = upon::value! {
+        track_id: track.header.track_id,
+        is_enabled: track.header.is_enabled,
+        handler_name: &track.handler.name,
+        language: &track.media_header.language,
+        duration: format_duration(track.media_header.duration, track.media_header.timescale),
+        channels: audio_sample_entry.channels,
+        sample_rate: format_sample_rate(audio_sample_entry.sample_rate as u32),
+        sample_count: sample_sizes.sample_count,
+        average_bitrate: average_bitrate,
+    };
+
+    let formated_output = get_file_chunk_output(TRACK_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_other_tracks_metadata(
+    tracks: &[&Track],
+    chapter_track_ids: &[u32],
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut summaries: Vec<TrackSummary> = tracks
+        .iter()
+        .map(|track| TrackSummary {
+            key: format!("Track {}", track.header.track_id),
+            spacer: String::new(),
+            value: get_other_track_summary(track, chapter_track_ids),
+        })
+        .collect();
+
+    set_key_value_pair_spacers(&mut summaries);
+
+    let output_values: Value = upon::value! {
+        tracks: summaries,
+    };
+
+    let formated_output = get_file_chunk_output(OTHER_TRACKS_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_other_track_summary(track: &Track, chapter_track_ids: &[u32]) -> String {
+    let mut summary = get_handler_type_name(&track.handler.handler_type);
+
+    if chapter_track_ids.contains(&track.header.track_id) {
+        summary.push_str(" (Chapters)");
+    }
+
+    if let Some(sample_entry) = track.sample_entry {
+        summary.push_str(&format!(", {}", format_codec_name(&sample_entry.id)));
+
+        if is_video_sample_entry(&sample_entry.id) {
+            if let Ok(video_sample_entry) =
+                get_video_sample_entry_from_bytes(sample_entry.data.clone())
+            {
+                summary.push_str(&format!(
+                    ", {} x {}",
+                    video_sample_entry.width, video_sample_entry.height
+                ));
+            }
+        }
+    }
+
+    summary.push_str(&format!(
+        ", {}, {}",
+        format_duration(track.media_header.duration, track.media_header.timescale),
+        track.media_header.language
+    ));
+
+    summary
+}
+
+fn get_top_level_atoms_from_file(mp4_file: &mut File) -> Result<Vec<Mp4Atom>, Box<dyn Error>> {
+    let file_length = mp4_file.metadata()?.len();
+    let mut atoms: Vec<Mp4Atom> = vec![];
+
+    loop {
+        let offset = mp4_file.stream_position()?;
+        if offset + ATOM_HEADER_LENGTH_IN_BYTES as u64 > file_length {
+            break;
+        }
+
+        let mut header = read_bytes_from_file(mp4_file, ATOM_HEADER_LENGTH_IN_BYTES)?;
+        let mut size = take_first_four_bytes_as_unsigned_integer(&mut header, Endian::Big)? as u64;
+        let id = get_atom_id_from_bytes(&header);
+        let mut header_length = ATOM_HEADER_LENGTH_IN_BYTES as u64;
+
+        if size == EXTENDED_SIZE_MARKER {
+            let mut extended_size = read_bytes_from_file(mp4_file, EXTENDED_SIZE_LENGTH_IN_BYTES)?;
+            size = take_first_eight_bytes_as_unsigned_integer(&mut extended_size, Endian::Big)?;
+            header_length += EXTENDED_SIZE_LENGTH_IN_BYTES as u64;
+        } else if size == TO_END_OF_FILE_SIZE_MARKER {
+            size = file_length - offset;
+        }
+
+        if size < header_length {
+            break;
+        }
+
+        let data_length = (size - header_length).min(file_length - offset - header_length) as usize;
+
+        if SKIPPED_DATA_ATOM_IDS.contains(&id.as_str()) {
+            skip_over_bytes_in_file(mp4_file, data_length)?;
+            atoms.push(Mp4Atom {
+                id,
+                offset,
+                size,
+                ..Default::default()
+            });
+            continue;
+        }
+
+        let data = read_bytes_from_file(mp4_file, data_length)?;
+        atoms.push(get_atom_from_bytes(id, offset, size, data, "")?);
+    }
+
+    Ok(atoms)
+}
+
+fn get_ilst_metadata(atoms: &[Mp4Atom]) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let Some(ilst_atom) = find_atom(atoms, &ILST_ATOM_PATH) else {
+        return Ok(vec![]);
+    };
+
+    let (serato_items, items): (Vec<IlstItem>, Vec<IlstItem>) =
+        ilst::get_items_from_atom(ilst_atom)
+            .into_iter()
+            .partition(|item| item.id == FREEFORM_ATOM_ID && item.mean == serato::FREEFORM_MEAN);
+
+    let mut output = vec![ilst::get_metadata(&items)?];
+
+    for item in serato_items {
+        output.push(serato::get_metadata_from_freeform_value(
+            &String::from_utf8_lossy(&item.value),
+        )?);
+    }
+
+    Ok(output)
+}
+
+fn get_average_bitrate(total_size: u128, duration: u64, timescale: u32) -> u64 {
+    match duration {
+        0 => 0,
+        duration => (total_size * 8 * timescale as u128 / duration as u128 / 1000) as u64,
+    }
+}
+
+fn format_sample_rate(sample_rate: u32) -> String {
+    let sample_rate_in_khz = f64::from(sample_rate) / 1000.0;
+
+    if sample_rate_in_khz == sample_rate_in_khz.floor() {
+        format!("{:#.0}", sample_rate_in_khz)
+    } else {
+        format!("{:#.1}", sample_rate_in_khz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_track(track_id: u32, handler_type: &str) -> Track<'static> {
+        Track {
+            header: TrackHeader {
+                track_id,
+                ..Default::default()
+            },
+            media_header: MediaHeader {
+                timescale: 1000,
+                duration: 61500,
+                language: "eng".to_string(),
+            },
+            handler: Handler {
+                handler_type: handler_type.to_string(),
+                ..Default::default()
+            },
+            sample_entry: None,
+            sample_size_atom: None,
+            chapter_track_ids: vec![],
+        }
+    }
+
+    #[test]
+    fn return_track_counts_by_type_in_order_of_first_appearance() {
+        let tracks = vec![
+            get_test_track(1, "soun"),
+            get_test_track(2, "vide"),
+            get_test_track(3, "soun"),
+        ];
+
+        assert_eq!(get_track_summary(&tracks), "3 (2 Audio, 1 Video)");
+        assert_eq!(get_track_summary(&[]), "0");
+    }
+
+    #[test]
+    fn return_other_track_summary_marked_as_chapters_when_referenced() {
+        let track = get_test_track(2, "text");
+        assert_eq!(
+            get_other_track_summary(&track, &[2]),
+            "Text (Chapters), 00:01:01.500, eng"
+        );
+        assert_eq!(
+            get_other_track_summary(&track, &[]),
+            "Text, 00:01:01.500, eng"
+        );
+    }
+
+    #[test]
+    fn return_average_bitrate_without_overflowing_large_totals() {
+        assert_eq!(get_average_bitrate(176400, 1000, 1000), 1411);
+        assert_eq!(get_average_bitrate(u64::MAX as u128, u64::MAX, 1000), 8);
+        assert_eq!(get_average_bitrate(176400, 0, 1000), 0);
+    }
+
+    #[test]
+    fn return_single_digit_decimal_khz_when_sample_rate_is_44100hz() {
+        let test_sample_rate = 44100;
+        let correct_result = "44.1";
+        let result = format_sample_rate(test_sample_rate);
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_whole_number_khz_when_sample_rate_is_48000hz() {
+        let test_sample_rate = 48000;
+        let correct_result = "48";
+        let result = format_sample_rate(test_sample_rate);
+        assert_eq!(result, correct_result);
+    }
+}
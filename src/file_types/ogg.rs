@@ -1,288 +1,329 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_eight_bytes_as_unsigned_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
 use crate::errors::LocalError;
-use crate::fileio::{
-    get_file_metadata, read_byte_from_file, read_bytes_from_file, skip_over_bytes_in_file,
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::ogg_headers::{
+    get_codec_from_identification_packet, get_codec_name, get_metadata_from_packets,
+    has_all_metadata_packets, OggCodec,
 };
-use crate::formating::{set_key_value_pair_spacers, KeyValuePair as UserComment};
 use crate::output::{OutputEntry, Section};
 use crate::template::get_file_chunk_output;
-use byte_unit::rust_decimal::prelude::Zero;
-use serde::Serialize;
 use std::error::Error;
 use std::fs::File;
 use upon::Value;
 
-const OGG_CONTAINER_HEADER_LENGTH_IN_BYTES: usize = 26;
-const VORBIS_COMMON_HEADER_LENGTH_IN_BYTES: usize = 7;
-const FRAMING_FLAG_LENGTH_IN_BYTES: usize = 1;
 const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ogg.tmpl");
-const HEADER_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ogg_headers.tmpl");
-const FIXED_BIT_RATE_OUTPUT_STRING: &str = "Fixed";
-const VBR_ABR_BIT_RATE_OUTPUT_STRING: &str = "VBR or ABR";
-const MAX_LIMITED_BIT_RATE_OUTPUT_STRING: &str = "Maximum Limited";
-const MIN_LIMITED_BIT_RATE_OUTPUT_STRING: &str = "Minimum Limited";
-const UNKNOWN_BIT_RATE_OUTPUT_STRING: &str = "Unknown";
-const BAD_USER_COMMENT_KEY: &str = "XXXX";
-const BAD_USER_COMMENT_VALUE: &str =
-    "[Corrupt or Non-Standard Format User Comment. Halting processing comments.]";
-
-#[derive(Debug, Default, Serialize)]
-struct HeaderMetadata {
-    vorbis_version: u32,
-    audio_channels: u8,
-    audio_sample_rate: u32,
-    bitrate_maximum: u32,
-    bitrate_nominal: u32,
-    bitrate_minimum: u32,
-    blocksizes: (u8, u8),
-    bitrate_type: String,
-    vendor_comment: String,
-    user_comments: Vec<UserComment>,
+const STREAMS_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ogg_streams.tmpl");
+const STREAM_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/ogg_stream.tmpl");
+const OGG_PAGE_CAPTURE_PATTERN: &[u8] = "OggS".as_bytes();
+const OGG_PAGE_HEADER_LENGTH_IN_BYTES: usize = 27;
+const OGG_PAGE_VERSION_LENGTH_IN_BYTES: usize = 1;
+const OGG_PAGE_SEQUENCE_AND_CHECKSUM_LENGTH_IN_BYTES: usize = 8;
+const BEGINNING_OF_STREAM_FLAG: u8 = 0x02;
+const END_OF_STREAM_FLAG: u8 = 0x04;
+const NO_PACKET_FINISHED_GRANULE_POSITION: u64 = u64::MAX;
+const MAXIMUM_LACING_VALUE: u8 = 255;
+
+struct OggPage {
+    header_type: u8,
+    granule_position: u64,
+    serial_number: u32,
+    segment_table: Vec<u8>,
+    data: Vec<u8>,
 }
 
-pub fn get_metadata_from_file(ogg_file_path: &str) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
-    let mut ogg_file = File::open(ogg_file_path)?;
-    let file_metadata = get_file_metadata(ogg_file_path, &ogg_file, TEMPLATE_CONTENT)?;
-    let vorbis_metadata = get_metadata_from_headers(&mut ogg_file)?;
-    let chunks = vec![file_metadata, vorbis_metadata];
-
-    Ok(chunks)
+#[derive(Default)]
+struct LogicalStream {
+    serial_number: u32,
+    chain_link: usize,
+    codec: Option<OggCodec>,
+    packets: Vec<Vec<u8>>,
+    partial_packet: Vec<u8>,
+    has_all_headers: bool,
+    has_ended: bool,
+    number_of_pages: usize,
+    last_granule_position: Option<u64>,
 }
 
-fn get_metadata_from_headers(ogg_file: &mut File) -> Result<OutputEntry, Box<dyn Error>> {
-    let mut header_metadata = get_identification_header_metadata_from_file(ogg_file)?;
-    get_comment_header_metadata_from_file(ogg_file, &mut header_metadata)?;
-
-    let output_values: Value = upon::value! {
-        vorbis_version: header_metadata.vorbis_version,
-        audio_channels: header_metadata.audio_channels,
-        audio_sample_rate: header_metadata.audio_sample_rate as f64 / 1000.0,
-        bitrate_maximum: header_metadata.bitrate_maximum/1000,
-        bitrate_nominal: header_metadata.bitrate_nominal/1000,
-        bitrate_minimum: header_metadata.bitrate_minimum/1000,
-        blocksize_0: header_metadata.blocksizes.0,
-        blocksize_1: header_metadata.blocksizes.1,
-        bitrate_type: header_metadata.bitrate_type,
-        vendor_comment: header_metadata.vendor_comment,
-        user_comments: header_metadata.user_comments,
-    };
+pub fn get_metadata_from_file(
+    ogg_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut ogg_file = File::open(ogg_file_path)?;
+    let file_metadata = get_file_metadata(ogg_file_path, &ogg_file, TEMPLATE_CONTENT)?;
+    let logical_streams = get_logical_streams_from_file(&mut ogg_file)?;
+    let stream_metadata =
+        get_metadata_from_logical_streams(logical_streams, mandatory_sections_only)?;
 
-    let formated_output = get_file_chunk_output(HEADER_TEMPLATE_CONTENT, output_values)?;
+    let mut output = vec![file_metadata];
+    output.extend(stream_metadata);
 
-    Ok(OutputEntry {
-        section: Section::Mandatory,
-        text: formated_output,
-    })
+    Ok(output)
 }
 
-fn get_identification_header_metadata_from_file(
+fn get_logical_streams_from_file(
     ogg_file: &mut File,
-) -> Result<HeaderMetadata, Box<dyn Error>> {
-    skip_over_ogg_packet_and_vorbis_common_headers_in_file(ogg_file)?;
-
-    let vorbis_version = get_4_byte_field_from_file(ogg_file)?;
-    let audio_channels = read_byte_from_file(ogg_file)?;
-    let audio_sample_rate = get_4_byte_field_from_file(ogg_file)?;
-    let bitrate_maximum = get_4_byte_field_from_file(ogg_file)?;
-    let bitrate_nominal = get_4_byte_field_from_file(ogg_file)?;
-    let bitrate_minimum = get_4_byte_field_from_file(ogg_file)?;
-    let blocksizes: (u8, u8) = get_blocksizes_from_byte(read_byte_from_file(ogg_file)?);
-    let bitrate_type =
-        get_bitrate_type_from_bitrate_values(bitrate_minimum, bitrate_nominal, bitrate_maximum);
-
-    let header_metadata = HeaderMetadata {
-        vorbis_version,
-        audio_channels,
-        audio_sample_rate,
-        bitrate_maximum,
-        bitrate_nominal,
-        bitrate_minimum,
-        blocksizes,
-        bitrate_type,
-        ..Default::default()
-    };
-
-    skip_over_bytes_in_file(ogg_file, FRAMING_FLAG_LENGTH_IN_BYTES)?;
+) -> Result<Vec<LogicalStream>, Box<dyn Error>> {
+    let mut logical_streams: Vec<LogicalStream> = vec![];
+    let mut chain_link: usize = 0;
+    let mut has_seen_non_beginning_page = true;
+
+    loop {
+        let mut page_header = match read_page_header_from_file(ogg_file) {
+            Ok(Some(page_header)) => page_header,
+            Ok(None) => break,
+            Err(error)
+                if !logical_streams.is_empty()
+                    && error.to_string()
+                        == LocalError::InvalidOggPageCapturePattern.to_string() =>
+            {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        let is_beginning_of_stream = page_header.header_type & BEGINNING_OF_STREAM_FLAG != 0;
+
+        if is_beginning_of_stream && has_seen_non_beginning_page {
+            chain_link += 1;
+        }
+        has_seen_non_beginning_page = !is_beginning_of_stream;
+
+        let stream_index = match logical_streams.iter().position(|stream| {
+            stream.serial_number == page_header.serial_number && stream.chain_link == chain_link
+        }) {
+            Some(index) => index,
+            None => {
+                logical_streams.push(LogicalStream {
+                    serial_number: page_header.serial_number,
+                    chain_link,
+                    ..Default::default()
+                });
+                logical_streams.len() - 1
+            }
+        };
+
+        let stream = &mut logical_streams[stream_index];
+        let page_data_length: usize = page_header
+            .segment_table
+            .iter()
+            .map(|segment| *segment as usize)
+            .sum();
+
+        stream.number_of_pages += 1;
+        stream.has_ended = page_header.header_type & END_OF_STREAM_FLAG != 0;
+        if page_header.granule_position != NO_PACKET_FINISHED_GRANULE_POSITION {
+            stream.last_granule_position = Some(page_header.granule_position);
+        }
 
-    Ok(header_metadata)
-}
+        if stream.has_all_headers {
+            skip_over_bytes_in_file(ogg_file, page_data_length)?;
+            continue;
+        }
 
-fn get_comment_header_metadata_from_file(
-    ogg_file: &mut File,
-    header_metadata: &mut HeaderMetadata,
-) -> Result<(), Box<dyn Error>> {
-    skip_over_ogg_packet_and_vorbis_common_headers_in_file(ogg_file)?;
-
-    let vendor_comment_length_in_bytes = get_4_byte_field_from_file(ogg_file)?;
-    header_metadata.vendor_comment =
-        get_string_from_file(ogg_file, vendor_comment_length_in_bytes as usize)?;
-
-    let number_of_user_comments = get_4_byte_field_from_file(ogg_file)?;
-    for _ in 0..number_of_user_comments {
-        match get_user_comment_from_file(ogg_file) {
-            Ok(comment) => header_metadata.user_comments.push(comment),
-            Err(_) => header_metadata.user_comments.push(UserComment {
-                key: BAD_USER_COMMENT_KEY.to_string(),
-                spacer: " ".to_string(),
-                value: BAD_USER_COMMENT_VALUE.to_string(),
-            }),
+        page_header.data = match read_bytes_from_file(ogg_file, page_data_length) {
+            Ok(data) => data,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        add_packets_from_page(page_header, &mut stream.packets, &mut stream.partial_packet);
+
+        if let Some(identification_packet) = stream.packets.first() {
+            let codec = stream
+                .codec
+                .get_or_insert_with(|| get_codec_from_identification_packet(identification_packet));
+            stream.has_all_headers = has_all_metadata_packets(codec, &stream.packets);
         }
     }
 
-    set_key_value_pair_spacers(&mut header_metadata.user_comments);
-
-    Ok(())
-}
-
-fn skip_over_ogg_packet_and_vorbis_common_headers_in_file(
-    ogg_file: &mut File,
-) -> Result<(), Box<dyn Error>> {
-    skip_over_bytes_in_file(ogg_file, OGG_CONTAINER_HEADER_LENGTH_IN_BYTES)?;
-    let number_of_page_segments = read_byte_from_file(ogg_file)?;
-    skip_over_bytes_in_file(ogg_file, number_of_page_segments as usize)?;
-
-    skip_over_bytes_in_file(ogg_file, VORBIS_COMMON_HEADER_LENGTH_IN_BYTES)?;
-
-    Ok(())
+    Ok(logical_streams)
 }
 
-fn get_user_comment_from_file(ogg_file: &mut File) -> Result<UserComment, Box<dyn Error>> {
-    let user_comment_length = get_4_byte_field_from_file(ogg_file)?;
-    let user_comment = get_string_from_file(ogg_file, user_comment_length as usize)?;
-    let user_comment_key_value = user_comment
-        .split_once("=")
-        .ok_or(LocalError::InvalidVorbisComment)?;
-
-    Ok(UserComment {
-        key: user_comment_key_value.0.to_string(),
-        spacer: " ".to_string(),
-        value: user_comment_key_value.1.to_string(),
-    })
-}
-
-fn get_4_byte_field_from_file(file: &mut File) -> Result<u32, Box<dyn Error>> {
-    let size_bytes = read_bytes_from_file(file, 4)?;
-    let mut byte_array: [u8; 4] = Default::default();
-    byte_array.copy_from_slice(size_bytes.as_slice());
-
-    Ok(u32::from_le_bytes(byte_array))
-}
-
-fn get_string_from_file(
-    file: &mut File,
-    string_length_in_bytes: usize,
-) -> Result<String, Box<dyn Error>> {
-    let read_bytes = read_bytes_from_file(file, string_length_in_bytes)?;
+fn read_page_header_from_file(ogg_file: &mut File) -> Result<Option<OggPage>, Box<dyn Error>> {
+    let mut page_header = match read_bytes_from_file(ogg_file, OGG_PAGE_HEADER_LENGTH_IN_BYTES) {
+        Ok(bytes) => bytes,
+        Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+            return Ok(None);
+        }
+        Err(error) => return Err(error),
+    };
 
-    Ok(String::from_utf8(read_bytes)?)
-}
+    if !page_header.starts_with(OGG_PAGE_CAPTURE_PATTERN) {
+        return Err(Box::new(LocalError::InvalidOggPageCapturePattern));
+    }
 
-fn get_blocksizes_from_byte(blocksize_byte: u8) -> (u8, u8) {
-    let blocksize_1: u8 = blocksize_byte & 15;
-    let blocksize_0: u8 = blocksize_byte >> 4;
+    skip_over_bytes(
+        &mut page_header,
+        OGG_PAGE_CAPTURE_PATTERN.len() + OGG_PAGE_VERSION_LENGTH_IN_BYTES,
+    )?;
+    let header_type = take_first_byte(&mut page_header)?;
+    let granule_position =
+        take_first_eight_bytes_as_unsigned_integer(&mut page_header, Endian::Little)?;
+    let serial_number =
+        take_first_four_bytes_as_unsigned_integer(&mut page_header, Endian::Little)?;
+    skip_over_bytes(
+        &mut page_header,
+        OGG_PAGE_SEQUENCE_AND_CHECKSUM_LENGTH_IN_BYTES,
+    )?;
+    let number_of_page_segments = take_first_byte(&mut page_header)?;
+
+    let segment_table = match read_bytes_from_file(ogg_file, number_of_page_segments as usize) {
+        Ok(bytes) => bytes,
+        Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+            return Ok(None);
+        }
+        Err(error) => return Err(error),
+    };
 
-    (blocksize_0, blocksize_1)
+    Ok(Some(OggPage {
+        header_type,
+        granule_position,
+        serial_number,
+        segment_table,
+        data: vec![],
+    }))
 }
 
-fn get_bitrate_type_from_bitrate_values(minimum: u32, nominal: u32, maximum: u32) -> String {
-    if maximum == nominal && nominal == minimum {
-        return FIXED_BIT_RATE_OUTPUT_STRING.to_string();
-    }
-
-    if !nominal.is_zero() && maximum.is_zero() && minimum.is_zero() {
-        return VBR_ABR_BIT_RATE_OUTPUT_STRING.to_string();
+fn add_packets_from_page(
+    mut page: OggPage,
+    packets: &mut Vec<Vec<u8>>,
+    partial_packet: &mut Vec<u8>,
+) {
+    for lacing_value in page.segment_table {
+        let segment =
+            take_first_number_of_bytes(&mut page.data, lacing_value as usize).unwrap_or_default();
+        partial_packet.extend(segment);
+
+        if lacing_value < MAXIMUM_LACING_VALUE {
+            packets.push(std::mem::take(partial_packet));
+        }
     }
+}
 
-    if !maximum.is_zero() && nominal.is_zero() && minimum.is_zero() {
-        return MAX_LIMITED_BIT_RATE_OUTPUT_STRING.to_string();
-    }
+fn get_metadata_from_logical_streams(
+    logical_streams: Vec<LogicalStream>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let number_of_chain_links = logical_streams
+        .iter()
+        .map(|stream| stream.chain_link)
+        .max()
+        .unwrap_or_default();
+
+    let streams_output_values: Value = upon::value! {
+        number_of_chain_links: number_of_chain_links,
+        number_of_logical_streams: logical_streams.len(),
+    };
 
-    if !minimum.is_zero() && nominal.is_zero() && maximum.is_zero() {
-        return MIN_LIMITED_BIT_RATE_OUTPUT_STRING.to_string();
+    let mut output = vec![OutputEntry {
+        section: Section::Mandatory,
+        text: get_file_chunk_output(STREAMS_TEMPLATE_CONTENT, streams_output_values)?,
+    }];
+
+    for (index, stream) in logical_streams.into_iter().enumerate() {
+        let stream_output_values: Value = upon::value! {
+            stream_number: index + 1,
+            chain_link: stream.chain_link,
+            number_of_chain_links: number_of_chain_links,
+            serial_number: format!("{:#010X}", stream.serial_number),
+            codec: stream.codec.as_ref().map(get_codec_name).unwrap_or_default(),
+            number_of_pages: stream.number_of_pages,
+            last_granule_position: stream.last_granule_position.map(|position| position.to_string()).unwrap_or_default(),
+            has_ended: stream.has_ended,
+            has_all_headers: stream.has_all_headers,
+        };
+
+        output.push(OutputEntry {
+            section: Section::Mandatory,
+            text: get_file_chunk_output(STREAM_TEMPLATE_CONTENT, stream_output_values)?,
+        });
+
+        if let (Some(codec), true) = (stream.codec, stream.has_all_headers) {
+            output.extend(get_metadata_from_packets(
+                codec,
+                stream.packets,
+                mandatory_sections_only,
+            )?);
+        }
     }
 
-    UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string()
+    Ok(output)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn returns_correct_blocksizes_from_valid_blocksize_byte() {
-        let test_byte = 0b11010101;
-        let correct_blocksizes = (13, 5);
-        let blocksizes = get_blocksizes_from_byte(test_byte);
-
-        assert_eq!(correct_blocksizes, blocksizes);
-    }
-
-    #[test]
-    fn return_fixed_bit_rate_output_string_when_all_bitrates_are_equal() {
-        let test_minimum = 123456;
-        let test_nominal = 123456;
-        let test_maximum = 123456;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(output_string, FIXED_BIT_RATE_OUTPUT_STRING.to_string());
-    }
-
-    #[test]
-    fn return_variable_bit_rate_output_string_when_min_and_max_are_zero_but_nominal_is_not_zero() {
-        let test_minimum = 0;
-        let test_nominal = 123456;
-        let test_maximum = 0;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(output_string, VBR_ABR_BIT_RATE_OUTPUT_STRING.to_string());
+    fn get_test_page(segment_table: Vec<u8>, data: Vec<u8>) -> OggPage {
+        OggPage {
+            header_type: 0,
+            granule_position: 0,
+            serial_number: 0,
+            segment_table,
+            data,
+        }
     }
 
     #[test]
-    fn return_max_limited_bit_rate_output_string_when_min_and_nominal_are_zero_but_max_is_not_zero()
-    {
-        let test_minimum = 0;
-        let test_nominal = 0;
-        let test_maximum = 123456;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(
-            output_string,
-            MAX_LIMITED_BIT_RATE_OUTPUT_STRING.to_string()
+    fn return_correct_packets_from_page_with_lacing_values() {
+        let test_page = get_test_page(
+            vec![255, 10, 3],
+            [vec![1; 255], vec![2; 10], vec![3; 3]].concat(),
         );
-    }
+        let mut packets: Vec<Vec<u8>> = vec![];
+        let mut partial_packet: Vec<u8> = vec![];
 
-    #[test]
-    fn return_min_limited_bit_rate_output_string_when_max_and_nominal_are_zero_but_min_is_not_zero()
-    {
-        let test_minimum = 123456;
-        let test_nominal = 0;
-        let test_maximum = 0;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(
-            output_string,
-            MIN_LIMITED_BIT_RATE_OUTPUT_STRING.to_string()
-        );
+        add_packets_from_page(test_page, &mut packets, &mut partial_packet);
+
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[0].len(), 265);
+        assert_eq!(packets[1], vec![3; 3]);
+        assert!(partial_packet.is_empty());
     }
 
     #[test]
-    fn return_unknown_bit_rate_output_string_when_max_and_nominal_bitrates_are_set_and_min_is_zero()
-    {
-        let test_minimum = 0;
-        let test_nominal = 123456;
-        let test_maximum = 123456;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(output_string, UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string());
+    fn return_packet_continued_across_pages() {
+        let first_page = get_test_page(vec![255], vec![1; 255]);
+        let second_page = get_test_page(vec![5], vec![2; 5]);
+        let mut packets: Vec<Vec<u8>> = vec![];
+        let mut partial_packet: Vec<u8> = vec![];
+
+        add_packets_from_page(first_page, &mut packets, &mut partial_packet);
+        assert!(packets.is_empty());
+
+        add_packets_from_page(second_page, &mut packets, &mut partial_packet);
+        assert_eq!(packets.len(), 1);
+        assert_eq!(packets[0].len(), 260);
     }
 
     #[test]
-    fn return_unknown_bit_rate_output_string_when_min_and_nominal_bitrates_are_set_and_max_is_zero()
-    {
-        let test_minimum = 123456;
-        let test_nominal = 123456;
-        let test_maximum = 0;
-        let output_string =
-            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
-        assert_eq!(output_string, UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string());
+    fn return_collected_streams_when_trailing_bytes_follow_the_last_page() {
+        let mut test_file_bytes = OGG_PAGE_CAPTURE_PATTERN.to_vec();
+        test_file_bytes.extend([0x00, BEGINNING_OF_STREAM_FLAG | END_OF_STREAM_FLAG]);
+        test_file_bytes.extend(0u64.to_le_bytes());
+        test_file_bytes.extend(1u32.to_le_bytes());
+        test_file_bytes.extend([0x00; OGG_PAGE_SEQUENCE_AND_CHECKSUM_LENGTH_IN_BYTES]);
+        test_file_bytes.extend([1, 5]);
+        test_file_bytes.extend([0x00; 5]);
+        test_file_bytes.extend(b"TAG");
+        test_file_bytes.extend([0x00; 125]);
+
+        let mut test_file_path = std::env::temp_dir();
+        test_file_path.push("test_ogg_with_trailing_id3v1_tag.ogg");
+        std::fs::write(&test_file_path, test_file_bytes).unwrap();
+        let mut test_file = File::open(&test_file_path).unwrap();
+        let result = get_logical_streams_from_file(&mut test_file);
+        std::fs::remove_file(test_file_path).unwrap();
+
+        let logical_streams = result.unwrap();
+        assert_eq!(logical_streams.len(), 1);
+        assert_eq!(logical_streams[0].number_of_pages, 1);
+        assert!(logical_streams[0].has_ended);
     }
 }
@@ -1,5 +1,6 @@
-use crate::byte_arrays::Endian;
+use crate::byte_arrays::{skip_over_bytes, Endian};
 use crate::chunks::{get_chunk_metadata, CHUNKS_NOT_TO_EXTRACT_DATA_FROM, MANDATORY_CHUNKS};
+use crate::dls_chunks::{get_metadata_from_dls_data, DLS_FORM_TYPE_ID};
 use crate::file_types::midi::get_metadata_from_midi_data;
 use crate::fileio::{
     get_file_metadata, read_bytes_from_file, read_chunk_id_from_file, read_chunk_size_from_file,
@@ -14,6 +15,8 @@ const TEMPLATE_HEADER_CONTENT: &str = include_str!("../templates/file_types/rmid
 const RMID_HEADER_FIELDS_LENGTH_IN_BYTES: usize = 12;
 
 const RMID_MIDI_DATA_CHUNK_ID: &str = "data";
+const RMID_EMBEDDED_RIFF_CHUNK_ID: &str = "riff";
+const RIFF_FORM_TYPE_LENGTH_IN_BYTES: usize = 4;
 
 pub fn get_metadata_from_file(
     rmid_file_path: &str,
@@ -23,8 +26,7 @@ pub fn get_metadata_from_file(
     skip_over_bytes_in_file(&mut rmid_file, RMID_HEADER_FIELDS_LENGTH_IN_BYTES)?;
 
     let file_metadata = get_file_metadata(rmid_file_path, &rmid_file, TEMPLATE_HEADER_CONTENT)?;
-    let chunk_metadata =
-        get_metadata_from_rmid_chunks(&mut rmid_file, rmid_file_path, mandatory_sections_only)?;
+    let chunk_metadata = get_metadata_from_rmid_chunks(&mut rmid_file, mandatory_sections_only)?;
 
     let mut output = vec![file_metadata];
     output.extend(chunk_metadata);
@@ -34,7 +36,6 @@ pub fn get_metadata_from_file(
 
 fn get_metadata_from_rmid_chunks(
     input_file: &mut File,
-    file_path: &str,
     mandatory_sections_only: bool,
 ) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
     let mut output: Vec<OutputEntry> = vec![];
@@ -57,6 +58,21 @@ fn get_metadata_from_rmid_chunks(
             continue;
         }
 
+        if chunk_id == RMID_EMBEDDED_RIFF_CHUNK_ID {
+            let mut chunk_data = read_bytes_from_file(input_file, chunk_size).unwrap_or_default();
+
+            if chunk_data.starts_with(DLS_FORM_TYPE_ID.as_bytes()) {
+                skip_over_bytes(&mut chunk_data, RIFF_FORM_TYPE_LENGTH_IN_BYTES)?;
+                output.extend(get_metadata_from_dls_data(
+                    chunk_data,
+                    mandatory_sections_only,
+                )?);
+            } else if !mandatory_sections_only {
+                output.push(get_chunk_metadata(chunk_id, chunk_data)?);
+            }
+            continue;
+        }
+
         if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
             skip_over_bytes_in_file(input_file, chunk_size)?;
             continue;
@@ -69,7 +85,7 @@ fn get_metadata_from_rmid_chunks(
             read_bytes_from_file(input_file, chunk_size).unwrap_or_default()
         };
 
-        output.push(get_chunk_metadata(chunk_id, chunk_data, file_path)?);
+        output.push(get_chunk_metadata(chunk_id, chunk_data)?);
     }
 
     Ok(output)
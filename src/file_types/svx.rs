@@ -0,0 +1,48 @@
+use crate::fileio::{get_file_metadata, read_bytes_from_file, skip_over_bytes_in_file};
+use crate::output::{OutputEntry, Section};
+use crate::svx_chunks::get_metadata_from_svx_chunks;
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use std::fs::File;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/svx.tmpl");
+const FORM_TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/aiff-form.tmpl");
+const CHUNK_ID_LENGTH_IN_BYTES: usize = 4;
+const CHUNK_SIZE_LENGTH_IN_BYTES: usize = 4;
+const FORM_TYPE_LENGTH_IN_BYTES: usize = 4;
+
+pub fn get_metadata_from_file(
+    file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut svx_file = File::open(file_path)?;
+
+    let file_metadata = get_file_metadata(file_path, &svx_file, TEMPLATE_CONTENT)?;
+    let form_metadata = get_form_metadata_from_file(&mut svx_file)?;
+    let chunk_metadata = get_metadata_from_svx_chunks(&mut svx_file, mandatory_sections_only)?;
+
+    let mut output = vec![file_metadata, form_metadata];
+    output.extend(chunk_metadata);
+
+    Ok(output)
+}
+
+fn get_form_metadata_from_file(svx_file: &mut File) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes_in_file(
+        svx_file,
+        CHUNK_ID_LENGTH_IN_BYTES + CHUNK_SIZE_LENGTH_IN_BYTES,
+    )?;
+    let form_type_bytes = read_bytes_from_file(svx_file, FORM_TYPE_LENGTH_IN_BYTES)?;
+
+    let output_values: Value = upon::value! {
+        form_type: String::from_utf8(form_type_bytes)?,
+    };
+
+    let formated_output = get_file_chunk_output(FORM_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Header,
+        text: formated_output,
+    })
+}
@@ -0,0 +1,33 @@
+use crate::file_types::TrackerModuleType;
+use crate::fileio::get_file_metadata;
+use crate::output::OutputEntry;
+use crate::tracker_modules::{it, protracker, s3m, xm};
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/tracker_module.tmpl");
+
+pub fn get_metadata_from_file(
+    module_file_path: &str,
+    module_type: TrackerModuleType,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut module_file = File::open(module_file_path)?;
+    let file_metadata = get_file_metadata(module_file_path, &module_file, TEMPLATE_CONTENT)?;
+
+    let mut module_data: Vec<u8> = Vec::new();
+    module_file.read_to_end(&mut module_data)?;
+
+    let module_metadata = match module_type {
+        TrackerModuleType::Mod => protracker::get_metadata(module_data, mandatory_sections_only)?,
+        TrackerModuleType::S3m => s3m::get_metadata(module_data, mandatory_sections_only)?,
+        TrackerModuleType::Xm => xm::get_metadata(module_data, mandatory_sections_only)?,
+        TrackerModuleType::It => it::get_metadata(module_data, mandatory_sections_only)?,
+    };
+
+    let mut output = vec![file_metadata];
+    output.extend(module_metadata);
+
+    Ok(output)
+}
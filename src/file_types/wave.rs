@@ -16,12 +16,8 @@ pub fn get_metadata_from_file(
     skip_over_bytes_in_file(&mut wave_file, WAVE_HEADER_FIELDS_LENGTH_IN_BYTES)?;
 
     let file_metadata = get_file_metadata(wave_file_path, &wave_file, TEMPLATE_CONTENT)?;
-    let chunk_metadata = get_metadata_from_chunks(
-        &mut wave_file,
-        wave_file_path,
-        mandatory_sections_only,
-        Endian::Little,
-    )?;
+    let chunk_metadata =
+        get_metadata_from_chunks(&mut wave_file, mandatory_sections_only, Endian::Little)?;
 
     let mut chunks = vec![file_metadata];
     chunks.extend(chunk_metadata);
@@ -0,0 +1,206 @@
+use crate::byte_arrays::Endian;
+use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
+use crate::chunks::{get_metadata_from_chunk_bytes, skipped};
+use crate::fileio::{
+    get_file_metadata, read_byte_from_file, read_bytes_from_file, skip_over_bytes_in_file,
+};
+use crate::output::OutputEntry;
+use crate::trailing_tags::get_metadata_from_trailing_tags;
+use crate::wavpack_blocks::{
+    channel_info, checksum, get_sub_block_header_from_bytes,
+    get_sub_block_header_length_from_first_byte, get_sub_block_name, header, ALT_HEADER_ID,
+    ALT_MD5_CHECKSUM_ID, ALT_TRAILER_ID, BLOCK_HEADER_LENGTH_IN_BYTES, BLOCK_ID, CHANNEL_INFO_ID,
+    MD5_CHECKSUM_ID, METADATA_SUB_BLOCK_IDS, RIFF_HEADER_ID, RIFF_TRAILER_ID, SAMPLE_RATE_ID,
+};
+use std::error::Error;
+use std::fs::File;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/file_types/wavpack.tmpl");
+const BLOCK_ID_AND_SIZE_LENGTH_IN_BYTES: usize = 8;
+const RIFF_FORM_HEADER_LENGTH_IN_BYTES: usize = 12;
+const RIFF_FORM_ID: &[u8] = b"RIFF";
+const AIFF_FORM_ID: &[u8] = b"FORM";
+const MD5_COVERAGE: &str = "Decoded audio samples";
+const ALT_MD5_COVERAGE: &str = "Audio data in the original file format";
+
+#[derive(Debug, Default)]
+struct WavPackMetadata {
+    first_block_header: Vec<u8>,
+    number_of_blocks: usize,
+    sub_blocks: Vec<(u8, Vec<u8>)>,
+    skipped_sub_block_names: Vec<String>,
+}
+
+pub fn get_metadata_from_file(
+    wavpack_file_path: &str,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut wavpack_file = File::open(wavpack_file_path)?;
+    let file_metadata = get_file_metadata(wavpack_file_path, &wavpack_file, TEMPLATE_CONTENT)?;
+    let wavpack_metadata = get_wavpack_metadata_from_file(&mut wavpack_file)?;
+
+    let mut output = vec![file_metadata];
+    output.extend(get_metadata_from_sub_blocks(
+        &wavpack_metadata,
+        mandatory_sections_only,
+    )?);
+
+    if !mandatory_sections_only {
+        output.extend(get_metadata_from_trailing_tags(&mut wavpack_file)?);
+
+        for sub_block_name in wavpack_metadata.skipped_sub_block_names {
+            output.push(skipped::get_metadata(sub_block_name)?);
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_wavpack_metadata_from_file(
+    wavpack_file: &mut File,
+) -> Result<WavPackMetadata, Box<dyn Error>> {
+    let mut wavpack_metadata = WavPackMetadata::default();
+
+    loop {
+        let block_header = match read_bytes_from_file(wavpack_file, BLOCK_HEADER_LENGTH_IN_BYTES) {
+            Ok(bytes) => bytes,
+            Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+                break;
+            }
+            Err(error) => return Err(error),
+        };
+
+        if !block_header.starts_with(BLOCK_ID) {
+            break;
+        }
+
+        let block_size = u32::from_le_bytes([
+            block_header[4],
+            block_header[5],
+            block_header[6],
+            block_header[7],
+        ]) as usize;
+        let sub_blocks_size = (block_size + BLOCK_ID_AND_SIZE_LENGTH_IN_BYTES)
+            .saturating_sub(BLOCK_HEADER_LENGTH_IN_BYTES);
+
+        if wavpack_metadata.number_of_blocks == 0 {
+            wavpack_metadata.first_block_header = block_header;
+        }
+        wavpack_metadata.number_of_blocks += 1;
+
+        add_sub_blocks_from_file(wavpack_file, sub_blocks_size, &mut wavpack_metadata)?;
+    }
+
+    Ok(wavpack_metadata)
+}
+
+fn add_sub_blocks_from_file(
+    wavpack_file: &mut File,
+    sub_blocks_size: usize,
+    wavpack_metadata: &mut WavPackMetadata,
+) -> Result<(), Box<dyn Error>> {
+    let mut remaining_bytes = sub_blocks_size;
+
+    while remaining_bytes > 0 {
+        let first_byte = read_byte_from_file(wavpack_file)?;
+        let header_length = get_sub_block_header_length_from_first_byte(first_byte);
+        let mut header_bytes = vec![first_byte];
+        header_bytes.extend(read_bytes_from_file(wavpack_file, header_length - 1)?);
+
+        let sub_block_header = get_sub_block_header_from_bytes(&header_bytes);
+        let padded_size = sub_block_header
+            .padded_size
+            .min(remaining_bytes.saturating_sub(header_length));
+        remaining_bytes = remaining_bytes.saturating_sub(header_length + padded_size);
+
+        let is_new_sub_block = !wavpack_metadata
+            .sub_blocks
+            .iter()
+            .any(|(id, _)| *id == sub_block_header.id);
+
+        if METADATA_SUB_BLOCK_IDS.contains(&sub_block_header.id) && is_new_sub_block {
+            let mut sub_block_data = read_bytes_from_file(wavpack_file, padded_size)?;
+            sub_block_data.truncate(sub_block_header.data_size);
+            wavpack_metadata
+                .sub_blocks
+                .push((sub_block_header.id, sub_block_data));
+            continue;
+        }
+
+        skip_over_bytes_in_file(wavpack_file, padded_size)?;
+
+        let sub_block_name = get_sub_block_name(sub_block_header.id);
+        if !METADATA_SUB_BLOCK_IDS.contains(&sub_block_header.id)
+            && !wavpack_metadata
+                .skipped_sub_block_names
+                .contains(&sub_block_name)
+        {
+            wavpack_metadata
+                .skipped_sub_block_names
+                .push(sub_block_name);
+        }
+    }
+
+    Ok(())
+}
+
+fn get_metadata_from_sub_blocks(
+    wavpack_metadata: &WavPackMetadata,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+
+    let custom_sample_rate = wavpack_metadata
+        .sub_blocks
+        .iter()
+        .find(|(id, _)| *id == SAMPLE_RATE_ID)
+        .map(|(_, data)| {
+            data.iter()
+                .take(4)
+                .rev()
+                .fold(0, |rate, byte| (rate << 8) | *byte as u32)
+        });
+
+    if !wavpack_metadata.first_block_header.is_empty() {
+        output.push(header::get_metadata(
+            wavpack_metadata.first_block_header.clone(),
+            wavpack_metadata.number_of_blocks,
+            custom_sample_rate,
+        )?);
+    }
+
+    for (id, data) in wavpack_metadata.sub_blocks.iter().cloned() {
+        match id {
+            CHANNEL_INFO_ID => output.push(channel_info::get_metadata(data)?),
+            RIFF_HEADER_ID | RIFF_TRAILER_ID | ALT_HEADER_ID | ALT_TRAILER_ID => output.extend(
+                get_metadata_from_stored_chunks(data, mandatory_sections_only)?,
+            ),
+            MD5_CHECKSUM_ID if !mandatory_sections_only => {
+                output.push(checksum::get_metadata(MD5_COVERAGE, data)?)
+            }
+            ALT_MD5_CHECKSUM_ID if !mandatory_sections_only => {
+                output.push(checksum::get_metadata(ALT_MD5_COVERAGE, data)?)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_metadata_from_stored_chunks(
+    mut chunk_bytes: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let endianness = if chunk_bytes.starts_with(AIFF_FORM_ID) {
+        Endian::Big
+    } else {
+        Endian::Little
+    };
+
+    if chunk_bytes.starts_with(RIFF_FORM_ID) || chunk_bytes.starts_with(AIFF_FORM_ID) {
+        chunk_bytes.drain(..RIFF_FORM_HEADER_LENGTH_IN_BYTES.min(chunk_bytes.len()));
+    }
+
+    get_metadata_from_chunk_bytes(chunk_bytes, mandatory_sections_only, endianness)
+}
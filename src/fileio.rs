@@ -1,17 +1,23 @@
+use crate::ac3_frames::is_ac3_sync;
 use crate::byte_arrays::Endian;
 use crate::caf_chunks::ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE;
 use crate::chunks::{CHUNK_ID_FIELD_LENGTH_IN_BYTES, CHUNK_SIZE_FIELD_LENGTH_IN_BYTES};
 use crate::errors::LocalError;
-use crate::file_types::{FileType, Mp3SubType};
+use crate::file_types::aac::{is_adts_sync, is_loas_sync};
+use crate::file_types::mp3::{
+    find_first_frame_in_bytes, skip_over_id3_data_in_file, SYNC_SEARCH_LENGTH_IN_BYTES,
+};
+use crate::file_types::{AacSubType, FileType, Mp3SubType, TrackerModuleType};
 use crate::formating::{
     add_one_if_byte_size_is_odd, canonicalize_file_path, format_file_size_as_string,
     get_file_name_from_file_path,
 };
 use crate::output::{OutputEntry, Section};
 use crate::template::get_file_chunk_output;
+use crate::tracker_modules::{protracker, s3m, xm};
 use std::error::Error;
 use std::fs::File;
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 use upon::Value;
 
 const AIFF_FILE_CHUNKID: &[u8] = "FORM".as_bytes();
@@ -19,17 +25,43 @@ const FLAC_FILE_CHUNKID: &[u8] = "fLaC".as_bytes();
 const RIFF_FILE_CHUNKID: &[u8] = "RIFF".as_bytes();
 const MIDI_FILE_CHUNKID: &[u8] = "MThd".as_bytes();
 const WAVE_FILE_TYPE_ID: &[u8] = "WAVE".as_bytes();
+const AIFF_FORM_TYPE_ID: &[u8] = "AIFF".as_bytes();
+const AIFF_C_FORM_TYPE_ID: &[u8] = "AIFC".as_bytes();
+const SVX_8_BIT_FORM_TYPE_ID: &[u8] = "8SVX".as_bytes();
+const SVX_16_BIT_FORM_TYPE_ID: &[u8] = "16SV".as_bytes();
 const RMID_FILE_TYPE_ID: &[u8] = "RMID".as_bytes();
+const DLS_FILE_TYPE_ID: &[u8] = "DLS ".as_bytes();
 const OGG_FILE_TYPE_ID: &[u8] = "OggS".as_bytes();
 const MP3_ID3_FILE_TYPE_ID: &[u8] = "ID3".as_bytes();
-const MP3_NON_ID3_FILE_TYPE_ID: &[u8] = &[0xFF, 0xFB];
-const M4A_FILE_TYPE_ID: &[u8] = "ftyp".as_bytes();
+const AUDIO_SYNC_LENGTH_IN_BYTES: usize = 2;
+const MP4_FILE_TYPE_IDS: [&[u8]; 6] = [
+    "ftyp".as_bytes(),
+    "moov".as_bytes(),
+    "mdat".as_bytes(),
+    "wide".as_bytes(),
+    "free".as_bytes(),
+    "skip".as_bytes(),
+];
 const CAF_FILE_TYPE_ID: &[u8] = "caff".as_bytes();
+const DSF_FILE_TYPE_ID: &[u8] = "DSD ".as_bytes();
+const DSDIFF_FILE_TYPE_ID: &[u8] = "FRM8".as_bytes();
+const WAVPACK_FILE_TYPE_ID: &[u8] = "wvpk".as_bytes();
+const APE_FILE_TYPE_ID: &[u8] = "MAC ".as_bytes();
+const AU_FILE_TYPE_ID: &[u8] = ".snd".as_bytes();
+const IT_FILE_TYPE_ID: &[u8] = "IMPM".as_bytes();
+const XM_FILE_TYPE_ID: &[u8] = "Exte".as_bytes();
+const SIXTY_FOUR_BIT_CHUNK_SIZE_LENGTH_IN_BYTES: usize = 8;
+const MATROSKA_FILE_TYPE_ID: &[u8] = &[0x1A, 0x45, 0xDF, 0xA3];
+const ASF_FILE_TYPE_ID: &[u8] = &[0x30, 0x26, 0xB2, 0x75];
+const ASF_HEADER_GUID_REMAINING_BYTES: &[u8] = &[
+    0x8E, 0x66, 0xCF, 0x11, 0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C,
+];
 
 #[derive(Debug, PartialEq)]
 enum RiffDataType {
     Wave,
     Rmid,
+    Dls,
 }
 
 pub fn skip_over_bytes_in_file(
@@ -51,6 +83,17 @@ pub fn read_bytes_from_file(
     Ok(read_bytes)
 }
 
+pub fn read_up_to_number_of_bytes_from_file(
+    file: &mut File,
+    number_of_bytes: usize,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut read_bytes: Vec<u8> = Vec::with_capacity(number_of_bytes);
+    file.take(number_of_bytes as u64)
+        .read_to_end(&mut read_bytes)?;
+
+    Ok(read_bytes)
+}
+
 pub fn read_byte_from_file(file: &mut File) -> Result<u8, Box<dyn Error>> {
     let mut read_bytes = [0; 1];
     file.read_exact(&mut read_bytes)?;
@@ -67,28 +110,48 @@ pub fn get_file_id_from_file(input_file_path: &str) -> Result<FileType, Box<dyn
     let file_id = &file_id_bytes[0..CHUNK_ID_FIELD_LENGTH_IN_BYTES];
 
     let file_type = match file_id {
-        AIFF_FILE_CHUNKID => FileType::Aiff,
+        AIFF_FILE_CHUNKID => get_form_file_type_from_file(&mut input_file)?,
         FLAC_FILE_CHUNKID => FileType::Flac,
         RIFF_FILE_CHUNKID => match get_riff_data_type_from_file(&mut input_file)? {
             RiffDataType::Wave => FileType::Wave,
             RiffDataType::Rmid => FileType::Rmid,
+            RiffDataType::Dls => FileType::Dls,
         },
         MIDI_FILE_CHUNKID => FileType::Smf,
         OGG_FILE_TYPE_ID => FileType::Ogg,
         CAF_FILE_TYPE_ID => FileType::Caf,
+        MATROSKA_FILE_TYPE_ID => FileType::Matroska,
+        DSF_FILE_TYPE_ID => FileType::Dsf,
+        DSDIFF_FILE_TYPE_ID => FileType::Dsdiff,
+        WAVPACK_FILE_TYPE_ID => FileType::WavPack,
+        APE_FILE_TYPE_ID => FileType::Ape,
+        AU_FILE_TYPE_ID => FileType::Au,
+        IT_FILE_TYPE_ID => FileType::TrackerModule(TrackerModuleType::It),
+        XM_FILE_TYPE_ID => get_xm_file_type_from_file(&mut input_file, file_id)?,
+        ASF_FILE_TYPE_ID => get_asf_file_type_from_file(&mut input_file, file_id)?,
         unknown => {
             if unknown.starts_with(MP3_ID3_FILE_TYPE_ID) {
-                FileType::Mp3(Mp3SubType::ID3)
-            } else if unknown.starts_with(MP3_NON_ID3_FILE_TYPE_ID) {
-                FileType::Mp3(Mp3SubType::NonId3)
+                get_id3_file_type_from_file(&mut input_file)?
+            } else if is_adts_sync(unknown) {
+                FileType::Aac(AacSubType::Adts)
+            } else if is_loas_sync(unknown) {
+                FileType::Aac(AacSubType::Latm)
+            } else if is_ac3_sync(unknown) {
+                FileType::Ac3
             } else {
                 let additional_file_id_bytes =
                     read_bytes_from_file(&mut input_file, CHUNK_ID_FIELD_LENGTH_IN_BYTES)?;
                 let additional_file_id =
                     &additional_file_id_bytes[0..CHUNK_ID_FIELD_LENGTH_IN_BYTES];
 
-                if additional_file_id == M4A_FILE_TYPE_ID {
-                    FileType::M4a
+                if MP4_FILE_TYPE_IDS.contains(&additional_file_id) {
+                    FileType::Mp4
+                } else if let Some(module_type) =
+                    get_tracker_module_type_from_file(&mut input_file)?
+                {
+                    FileType::TrackerModule(module_type)
+                } else if has_mpeg_audio_frames_in_file(&mut input_file)? {
+                    FileType::Mp3(Mp3SubType::NonId3)
                 } else {
                     FileType::Unsupported(String::from_utf8_lossy(file_id).to_string())
                 }
@@ -99,6 +162,122 @@ pub fn get_file_id_from_file(input_file_path: &str) -> Result<FileType, Box<dyn
     Ok(file_type)
 }
 
+fn get_asf_file_type_from_file(
+    asf_file: &mut File,
+    file_id: &[u8],
+) -> Result<FileType, Box<dyn Error>> {
+    let remaining_guid_bytes =
+        read_bytes_from_file(asf_file, ASF_HEADER_GUID_REMAINING_BYTES.len())?;
+
+    if remaining_guid_bytes == ASF_HEADER_GUID_REMAINING_BYTES {
+        Ok(FileType::Asf)
+    } else {
+        Ok(FileType::Unsupported(
+            String::from_utf8_lossy(file_id).to_string(),
+        ))
+    }
+}
+
+fn get_id3_file_type_from_file(id3_file: &mut File) -> Result<FileType, Box<dyn Error>> {
+    id3_file.seek(SeekFrom::Start(0))?;
+    skip_over_id3_data_in_file(id3_file)?;
+
+    let audio_sync_bytes = match read_bytes_from_file(id3_file, AUDIO_SYNC_LENGTH_IN_BYTES) {
+        Ok(bytes) => bytes,
+        Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+            Vec::new()
+        }
+        Err(error) => return Err(error),
+    };
+
+    if is_adts_sync(&audio_sync_bytes) {
+        Ok(FileType::Aac(AacSubType::Adts))
+    } else if is_loas_sync(&audio_sync_bytes) {
+        Ok(FileType::Aac(AacSubType::Latm))
+    } else if is_ac3_sync(&audio_sync_bytes) {
+        Ok(FileType::Ac3)
+    } else {
+        Ok(FileType::Mp3(Mp3SubType::ID3))
+    }
+}
+
+fn has_mpeg_audio_frames_in_file(mp3_file: &mut File) -> Result<bool, Box<dyn Error>> {
+    mp3_file.seek(SeekFrom::Start(0))?;
+    let search_bytes = read_up_to_number_of_bytes_from_file(mp3_file, SYNC_SEARCH_LENGTH_IN_BYTES)?;
+    Ok(find_first_frame_in_bytes(&search_bytes).is_some())
+}
+
+fn get_xm_file_type_from_file(
+    xm_file: &mut File,
+    file_id: &[u8],
+) -> Result<FileType, Box<dyn Error>> {
+    let remaining_id_text_length = xm::ID_TEXT.len() - CHUNK_ID_FIELD_LENGTH_IN_BYTES;
+    let remaining_id_text = read_bytes_from_file(xm_file, remaining_id_text_length)?;
+
+    if remaining_id_text == xm::ID_TEXT[CHUNK_ID_FIELD_LENGTH_IN_BYTES..] {
+        Ok(FileType::TrackerModule(TrackerModuleType::Xm))
+    } else {
+        Ok(FileType::Unsupported(
+            String::from_utf8_lossy(file_id).to_string(),
+        ))
+    }
+}
+
+fn get_tracker_module_type_from_file(
+    module_file: &mut File,
+) -> Result<Option<TrackerModuleType>, Box<dyn Error>> {
+    let s3m_signature =
+        read_bytes_at_offset_from_file(module_file, s3m::SIGNATURE_OFFSET, s3m::SIGNATURE.len())?;
+
+    if s3m_signature.as_deref() == Some(s3m::SIGNATURE) {
+        return Ok(Some(TrackerModuleType::S3m));
+    }
+
+    let mod_signature = read_bytes_at_offset_from_file(
+        module_file,
+        protracker::SIGNATURE_OFFSET,
+        protracker::SIGNATURE_LENGTH_IN_BYTES,
+    )?;
+
+    match mod_signature {
+        Some(signature) if protracker::get_channels_from_signature(&signature).is_some() => {
+            Ok(Some(TrackerModuleType::Mod))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn read_bytes_at_offset_from_file(
+    file: &mut File,
+    offset: usize,
+    number_of_bytes: usize,
+) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    file.seek(SeekFrom::Start(offset as u64))?;
+
+    match read_bytes_from_file(file, number_of_bytes) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(error) if error.to_string() == ERROR_TO_MATCH_IF_NOT_ENOUGH_BYTES_LEFT_IN_FILE => {
+            Ok(None)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+fn get_form_file_type_from_file(form_file: &mut File) -> Result<FileType, Box<dyn Error>> {
+    skip_over_bytes_in_file(form_file, CHUNK_SIZE_FIELD_LENGTH_IN_BYTES)?;
+
+    let form_type_bytes = read_bytes_from_file(form_file, CHUNK_ID_FIELD_LENGTH_IN_BYTES)?;
+
+    match form_type_bytes.as_slice() {
+        AIFF_FORM_TYPE_ID | AIFF_C_FORM_TYPE_ID => Ok(FileType::Aiff),
+        SVX_8_BIT_FORM_TYPE_ID | SVX_16_BIT_FORM_TYPE_ID => Ok(FileType::Svx),
+        form_type => Ok(FileType::Unsupported(format!(
+            "FORM {}",
+            String::from_utf8_lossy(form_type)
+        ))),
+    }
+}
+
 fn get_riff_data_type_from_file(wave_file: &mut File) -> Result<RiffDataType, Box<dyn Error>> {
     skip_over_bytes_in_file(wave_file, CHUNK_SIZE_FIELD_LENGTH_IN_BYTES)?;
 
@@ -108,6 +287,7 @@ fn get_riff_data_type_from_file(wave_file: &mut File) -> Result<RiffDataType, Bo
     match riff_id {
         WAVE_FILE_TYPE_ID => Ok(RiffDataType::Wave),
         RMID_FILE_TYPE_ID => Ok(RiffDataType::Rmid),
+        DLS_FILE_TYPE_ID => Ok(RiffDataType::Dls),
         _ => {
             eprintln!(
                 "RIFF file type mismatch: {:?} ",
@@ -167,6 +347,22 @@ pub fn read_chunk_size_from_file(
     Ok(chunk_size as usize)
 }
 
+pub fn read_64bit_chunk_size_from_file(
+    file: &mut File,
+    endianness: Endian,
+) -> Result<u64, Box<dyn Error>> {
+    let chunk_size_bytes = read_bytes_from_file(file, SIXTY_FOUR_BIT_CHUNK_SIZE_LENGTH_IN_BYTES)?;
+    let mut byte_array: [u8; SIXTY_FOUR_BIT_CHUNK_SIZE_LENGTH_IN_BYTES] = Default::default();
+    byte_array.copy_from_slice(chunk_size_bytes.as_slice());
+
+    let chunk_size = match endianness {
+        Endian::Little => u64::from_le_bytes(byte_array),
+        Endian::Big => u64::from_be_bytes(byte_array),
+    };
+
+    Ok(chunk_size)
+}
+
 pub fn get_file_metadata(
     file_path: &str,
     file: &File,
@@ -128,7 +128,7 @@ pub fn set_key_value_pair_spacers(key_value_pairs: &mut Vec<KeyValuePair>) {
 }
 
 pub fn add_one_if_byte_size_is_odd(mut byte_size: u32) -> u32 {
-    if byte_size % 2 > 0 {
+    if !byte_size.is_multiple_of(2) {
         byte_size += 1;
     }
 
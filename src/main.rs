@@ -1,14 +1,27 @@
+mod ac3_frames;
+mod asf_objects;
 mod blocks;
 mod byte_arrays;
 mod caf_chunks;
 mod chunks;
 mod cli;
+mod dj_tags;
+mod dls_chunks;
+mod dsd_chunks;
+mod ebml_elements;
 mod errors;
 mod file_types;
 mod fileio;
 mod formating;
+mod mp3_frames;
+mod mp4_atoms;
+mod ogg_headers;
 mod output;
+mod svx_chunks;
 mod template;
+mod tracker_modules;
+mod trailing_tags;
+mod wavpack_blocks;
 
 use crate::cli::process_cli_arguments;
 use crate::cli::EXIT_CODE_ERROR;
@@ -29,15 +42,19 @@ fn main() {
         exit(EXIT_CODE_ERROR);
     });
 
-    let metadata =
-        file_types::get_file_metadata(&cli_args.input_file_path, file_type, cli_args.mandatory)
-            .unwrap_or_else(|err| {
-                handle_local_error(
-                    LocalError::CouldNotReadData(cli_args.input_file_path),
-                    err.to_string(),
-                );
-                exit(EXIT_CODE_ERROR);
-            });
+    let metadata = file_types::get_file_metadata(
+        &cli_args.input_file_path,
+        file_type,
+        cli_args.mandatory,
+        cli_args.deep_scan,
+    )
+    .unwrap_or_else(|err| {
+        handle_local_error(
+            LocalError::CouldNotReadData(cli_args.input_file_path),
+            err.to_string(),
+        );
+        exit(EXIT_CODE_ERROR);
+    });
 
     output_metadata(metadata, cli_args.output_file_path).unwrap_or_else(|error| {
         handle_local_error(LocalError::CouldNotWrteOutData, error.to_string());
@@ -0,0 +1,125 @@
+pub mod lame;
+pub mod scan;
+pub mod vbri;
+pub mod xing;
+
+use crate::file_types::mp3::FrameHeader;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const STREAM_TEMPLATE_CONTENT: &str = include_str!("templates/mp3_frames/stream.tmpl");
+const ABR_VBR_METHODS: [u8; 2] = [lame::ABR_VBR_METHOD, lame::TWO_PASS_ABR_VBR_METHOD];
+const CBR_VBR_METHODS: [u8; 2] = [lame::CBR_VBR_METHOD, lame::TWO_PASS_CBR_VBR_METHOD];
+
+#[derive(Debug, Default, PartialEq)]
+struct StreamSummary {
+    bitrate_mode: String,
+    number_of_frames: Option<u32>,
+    number_of_bytes: u64,
+    encoder_delay: u32,
+    padding: u32,
+}
+
+pub fn get_metadata_from_first_frame(
+    frame_bytes: &[u8],
+    frame_header: &FrameHeader,
+    stream_length: u64,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+    let mut stream_summary = StreamSummary {
+        bitrate_mode: "CBR (No VBR Header)".to_string(),
+        number_of_bytes: stream_length,
+        ..Default::default()
+    };
+
+    if let Some(xing_tag) = xing::get_xing_tag_from_bytes(frame_bytes, frame_header) {
+        stream_summary.bitrate_mode = match xing_tag.is_vbr {
+            true => "VBR".to_string(),
+            false => "CBR".to_string(),
+        };
+        stream_summary.number_of_frames = xing_tag.number_of_frames;
+        if let Some(number_of_bytes) = xing_tag.number_of_bytes {
+            stream_summary.number_of_bytes = number_of_bytes as u64;
+        }
+
+        output.push(xing::get_metadata(&xing_tag)?);
+
+        if let Some(lame_tag) = lame::get_lame_tag_from_bytes(frame_bytes, xing_tag.lame_offset) {
+            if ABR_VBR_METHODS.contains(&lame_tag.vbr_method) {
+                stream_summary.bitrate_mode = "ABR".to_string();
+            } else if CBR_VBR_METHODS.contains(&lame_tag.vbr_method) {
+                stream_summary.bitrate_mode = "CBR".to_string();
+            }
+            stream_summary.encoder_delay = lame_tag.encoder_delay;
+            stream_summary.padding = lame_tag.padding;
+
+            output.push(lame::get_metadata(&lame_tag)?);
+        }
+    } else if let Some(vbri_header) = vbri::get_vbri_header_from_bytes(frame_bytes) {
+        stream_summary.bitrate_mode = "VBR".to_string();
+        stream_summary.number_of_frames = Some(vbri_header.number_of_frames);
+        stream_summary.number_of_bytes = vbri_header.number_of_bytes as u64;
+
+        output.push(vbri::get_metadata(&vbri_header)?);
+    }
+
+    output.push(get_stream_metadata(stream_summary, frame_header)?);
+
+    Ok(output)
+}
+
+fn get_stream_metadata(
+    stream_summary: StreamSummary,
+    frame_header: &FrameHeader,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let samples_per_frame = frame_header.samples_per_frame() as u64;
+    let frame_length = frame_header.frame_length() as u64;
+    let is_frame_count_estimated = stream_summary.number_of_frames.is_none();
+
+    let number_of_frames = match stream_summary.number_of_frames {
+        Some(number_of_frames) => number_of_frames as u64,
+        None if frame_length > 0 => stream_summary.number_of_bytes / frame_length,
+        None => 0,
+    };
+
+    let mut duration = String::new();
+    let mut gapless_duration = String::new();
+    let mut average_bitrate = String::new();
+
+    if number_of_frames > 0 && frame_header.sample_rate > 0 {
+        let number_of_samples = number_of_frames * samples_per_frame;
+        let duration_in_seconds = number_of_samples as f64 / frame_header.sample_rate as f64;
+        duration = format!("{:.3} seconds", duration_in_seconds);
+        average_bitrate = format!(
+            "{:.0} kbps",
+            stream_summary.number_of_bytes as f64 * 8.0 / duration_in_seconds / 1000.0
+        );
+
+        let trimmed_samples = (stream_summary.encoder_delay + stream_summary.padding) as u64;
+        if trimmed_samples > 0 && trimmed_samples < number_of_samples {
+            gapless_duration = format!(
+                "{:.3} seconds ({} samples)",
+                (number_of_samples - trimmed_samples) as f64 / frame_header.sample_rate as f64,
+                number_of_samples - trimmed_samples
+            );
+        }
+    }
+
+    let output_values: Value = upon::value! {
+        bitrate_mode: stream_summary.bitrate_mode,
+        number_of_frames: number_of_frames,
+        is_frame_count_estimated: is_frame_count_estimated,
+        duration: duration,
+        gapless_duration: gapless_duration,
+        average_bitrate: average_bitrate,
+    };
+
+    let formated_output = get_file_chunk_output(STREAM_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
@@ -0,0 +1,314 @@
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp3_frames/lame.tmpl");
+const TAG_LENGTH_IN_BYTES: usize = 36;
+const ENCODER_VERSION_LENGTH_IN_BYTES: usize = 9;
+const TAG_CRC_OFFSET: usize = 34;
+const ENCODER_IDS: [&[u8]; 4] = [b"LAME", b"Lavf", b"Lavc", b"GOGO"];
+const REPLAY_GAIN_NOT_SET: u16 = 0;
+const LOWPASS_MULTIPLIER_IN_HZ: u32 = 100;
+const MP3_GAIN_STEP_IN_DB: f32 = 1.5;
+const REPLAY_GAIN_DIVISOR: f32 = 10.0;
+const CRC_16_POLYNOMIAL: u16 = 0xA001;
+const PEAK_SIGNAL_AMPLITUDE_FRACTIONAL_BITS: u32 = 23;
+pub const CBR_VBR_METHOD: u8 = 1;
+pub const ABR_VBR_METHOD: u8 = 2;
+pub const TWO_PASS_CBR_VBR_METHOD: u8 = 8;
+pub const TWO_PASS_ABR_VBR_METHOD: u8 = 9;
+
+const VBR_METHODS: [(u8, &str); 9] = [
+    (0, "Unknown"),
+    (1, "Constant Bitrate"),
+    (2, "Average Bitrate"),
+    (3, "Variable Bitrate (Old / RH)"),
+    (4, "Variable Bitrate (MTRH)"),
+    (5, "Variable Bitrate (MT)"),
+    (6, "Variable Bitrate"),
+    (8, "Constant Bitrate (2 Pass)"),
+    (9, "Average Bitrate (2 Pass)"),
+];
+
+const REPLAY_GAIN_ORIGINATORS: [&str; 4] = ["Not Set", "Artist", "User", "Automatic"];
+
+const ENCODING_FLAGS: [(u8, &str); 4] = [
+    (0x01, "--nspsytune"),
+    (0x02, "--nssafejoint"),
+    (0x04, "--nogap (Next)"),
+    (0x08, "--nogap (Previous)"),
+];
+
+const STEREO_MODES: [&str; 8] = [
+    "Mono",
+    "Stereo",
+    "Dual",
+    "Joint Stereo",
+    "Forced Joint Stereo",
+    "Auto",
+    "Intensity Stereo",
+    "Undefined",
+];
+
+const SOURCE_SAMPLE_RATES: [&str; 4] = ["32 kHz or Less", "44.1 kHz", "48 kHz", "Over 48 kHz"];
+
+const SURROUND_MODES: [&str; 4] = [
+    "None",
+    "DPL Encoding",
+    "DPL2 Encoding",
+    "Ambisonic Encoding",
+];
+
+const PRESETS: [(u16, &str); 18] = [
+    (410, "V9"),
+    (420, "V8"),
+    (430, "V7"),
+    (440, "V6"),
+    (450, "V5"),
+    (460, "V4"),
+    (470, "V3"),
+    (480, "V2"),
+    (490, "V1"),
+    (500, "V0"),
+    (1000, "r3mix"),
+    (1001, "standard"),
+    (1002, "extreme"),
+    (1003, "insane"),
+    (1004, "standard/fast"),
+    (1005, "extreme/fast"),
+    (1006, "medium"),
+    (1007, "medium/fast"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct LameTag {
+    pub encoder_version: String,
+    pub tag_revision: u8,
+    pub vbr_method: u8,
+    pub lowpass: u32,
+    pub peak_signal_amplitude: f64,
+    pub radio_replay_gain: u16,
+    pub audiophile_replay_gain: u16,
+    pub encoding_flags: u8,
+    pub ath_type: u8,
+    pub bitrate: u8,
+    pub encoder_delay: u32,
+    pub padding: u32,
+    pub misc: u8,
+    pub mp3_gain: i8,
+    pub surround_and_preset: u16,
+    pub music_length: u32,
+    pub music_crc: u16,
+    pub tag_crc: u16,
+    pub is_tag_crc_valid: bool,
+}
+
+pub fn get_lame_tag_from_bytes(frame_bytes: &[u8], tag_offset: usize) -> Option<LameTag> {
+    let tag = frame_bytes.get(tag_offset..tag_offset + TAG_LENGTH_IN_BYTES)?;
+
+    if !ENCODER_IDS
+        .iter()
+        .any(|encoder_id| tag.starts_with(encoder_id))
+    {
+        return None;
+    }
+
+    let get_u16 = |offset: usize| u16::from_be_bytes([tag[offset], tag[offset + 1]]);
+    let get_u32 = |offset: usize| {
+        u32::from_be_bytes([
+            tag[offset],
+            tag[offset + 1],
+            tag[offset + 2],
+            tag[offset + 3],
+        ])
+    };
+
+    let tag_crc = get_u16(TAG_CRC_OFFSET);
+    let delay_and_padding = get_u32(20) & 0x00FFFFFF;
+
+    Some(LameTag {
+        encoder_version: String::from_utf8_lossy(&tag[..ENCODER_VERSION_LENGTH_IN_BYTES])
+            .trim_end_matches(['\0', ' '])
+            .to_string(),
+        tag_revision: tag[9] >> 4,
+        vbr_method: tag[9] & 0x0F,
+        lowpass: tag[10] as u32 * LOWPASS_MULTIPLIER_IN_HZ,
+        peak_signal_amplitude: get_u32(11) as f64
+            / (1u32 << PEAK_SIGNAL_AMPLITUDE_FRACTIONAL_BITS) as f64,
+        radio_replay_gain: get_u16(15),
+        audiophile_replay_gain: get_u16(17),
+        encoding_flags: tag[19] >> 4,
+        ath_type: tag[19] & 0x0F,
+        bitrate: tag[20],
+        encoder_delay: delay_and_padding >> 12,
+        padding: delay_and_padding & 0x0FFF,
+        misc: tag[24],
+        mp3_gain: tag[25] as i8,
+        surround_and_preset: get_u16(26),
+        music_length: get_u32(28),
+        music_crc: get_u16(32),
+        tag_crc,
+        is_tag_crc_valid: get_crc_16(&frame_bytes[..tag_offset + TAG_CRC_OFFSET]) == tag_crc,
+    })
+}
+
+pub fn get_metadata(lame_tag: &LameTag) -> Result<OutputEntry, Box<dyn Error>> {
+    let encoding_flags: Vec<&str> = ENCODING_FLAGS
+        .iter()
+        .filter(|(flag, _)| lame_tag.encoding_flags & flag != 0)
+        .map(|(_, name)| *name)
+        .collect();
+
+    let bitrate_label = match lame_tag.vbr_method {
+        CBR_VBR_METHOD | TWO_PASS_CBR_VBR_METHOD => "Bitrate:               ",
+        ABR_VBR_METHOD | TWO_PASS_ABR_VBR_METHOD => "Target Bitrate:        ",
+        _ => "Minimum Bitrate:       ",
+    };
+
+    let output_values: Value = upon::value! {
+        encoder_version: &lame_tag.encoder_version,
+        tag_revision: lame_tag.tag_revision,
+        vbr_method: get_name_from_table(&VBR_METHODS, lame_tag.vbr_method),
+        lowpass: match lame_tag.lowpass {
+            0 => "Unknown".to_string(),
+            lowpass => format!("{} Hz", lowpass),
+        },
+        peak_signal_amplitude: match lame_tag.peak_signal_amplitude {
+            0.0 => "Unknown".to_string(),
+            peak => format!("{:.6}", peak),
+        },
+        radio_replay_gain: format_replay_gain(lame_tag.radio_replay_gain),
+        audiophile_replay_gain: format_replay_gain(lame_tag.audiophile_replay_gain),
+        encoding_flags: match encoding_flags.is_empty() {
+            true => "None".to_string(),
+            false => encoding_flags.join(", "),
+        },
+        ath_type: lame_tag.ath_type,
+        bitrate_label: bitrate_label,
+        bitrate: match lame_tag.bitrate {
+            255 => "255 kbps or Higher".to_string(),
+            bitrate => format!("{} kbps", bitrate),
+        },
+        encoder_delay: lame_tag.encoder_delay,
+        padding: lame_tag.padding,
+        noise_shaping: lame_tag.misc & 0x03,
+        stereo_mode: STEREO_MODES[((lame_tag.misc >> 2) & 0x07) as usize],
+        unwise_settings: lame_tag.misc & 0x20 != 0,
+        source_sample_rate: SOURCE_SAMPLE_RATES[(lame_tag.misc >> 6) as usize],
+        mp3_gain: format!("{:+.1} dB", lame_tag.mp3_gain as f32 * MP3_GAIN_STEP_IN_DB),
+        surround: SURROUND_MODES
+            .get(((lame_tag.surround_and_preset >> 11) & 0x07) as usize)
+            .unwrap_or(&"Reserved"),
+        preset: format_preset(lame_tag.surround_and_preset & 0x07FF),
+        music_length: lame_tag.music_length,
+        music_crc: format!("0x{:04X}", lame_tag.music_crc),
+        tag_crc: format!("0x{:04X}", lame_tag.tag_crc),
+        is_tag_crc_valid: lame_tag.is_tag_crc_valid,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn format_replay_gain(replay_gain: u16) -> String {
+    let name = replay_gain >> 13;
+    if name == REPLAY_GAIN_NOT_SET {
+        return "Not Set".to_string();
+    }
+
+    let originator = ((replay_gain >> 10) & 0x07) as usize;
+    let mut adjustment = (replay_gain & 0x01FF) as f32 / REPLAY_GAIN_DIVISOR;
+    if replay_gain & 0x0200 != 0 {
+        adjustment = -adjustment;
+    }
+
+    format!(
+        "{:+.1} dB (Set by {})",
+        adjustment,
+        REPLAY_GAIN_ORIGINATORS
+            .get(originator)
+            .unwrap_or(&"Unknown")
+    )
+}
+
+fn format_preset(preset: u16) -> String {
+    match preset {
+        0 => "Unknown".to_string(),
+        8..=320 => format!("ABR {} kbps", preset),
+        _ => match PRESETS.iter().find(|(id, _)| *id == preset) {
+            Some((_, name)) => name.to_string(),
+            None => format!("Unknown ({})", preset),
+        },
+    }
+}
+
+fn get_name_from_table(table: &[(u8, &str)], id: u8) -> String {
+    match table.iter().find(|(table_id, _)| *table_id == id) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", id),
+    }
+}
+
+fn get_crc_16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |mut crc, byte| {
+        crc ^= *byte as u16;
+        for _ in 0..8 {
+            crc = match crc & 0x0001 {
+                0 => crc >> 1,
+                _ => (crc >> 1) ^ CRC_16_POLYNOMIAL,
+            };
+        }
+        crc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_replay_gain_and_preset_strings() {
+        assert_eq!(format_replay_gain(0x0000), "Not Set");
+        assert_eq!(format_replay_gain(0x2C41), "+6.5 dB (Set by Automatic)");
+        assert_eq!(format_replay_gain(0x2E41), "-6.5 dB (Set by Automatic)");
+        assert_eq!(format_preset(480), "V2");
+        assert_eq!(format_preset(128), "ABR 128 kbps");
+    }
+
+    #[test]
+    fn return_correct_crc_16_from_bytes() {
+        assert_eq!(get_crc_16(b"123456789"), 0xBB3D);
+    }
+
+    #[test]
+    fn return_correct_delay_and_padding_from_lame_tag() {
+        let mut frame_bytes: Vec<u8> = b"LAME3.100".to_vec();
+        frame_bytes.extend([0x24, 0xC3]);
+        frame_bytes.extend([0x00; 10]);
+        frame_bytes.extend([0x24, 0x04, 0xB0]);
+        frame_bytes.extend([0x00; 12]);
+
+        let result = get_lame_tag_from_bytes(&frame_bytes, 0).unwrap();
+        assert_eq!(result.encoder_version, "LAME3.100");
+        assert_eq!(result.vbr_method, 4);
+        assert_eq!(result.lowpass, 19500);
+        assert_eq!(result.encoder_delay, 576);
+        assert_eq!(result.padding, 1200);
+    }
+
+    #[test]
+    fn return_peak_signal_amplitude_from_fixed_point_bytes() {
+        let mut frame_bytes: Vec<u8> = b"LAME3.100".to_vec();
+        frame_bytes.extend([0x24, 0xC3]);
+        frame_bytes.extend([0x00, 0x80, 0x00, 0x00]);
+        frame_bytes.extend([0x00; 21]);
+
+        let result = get_lame_tag_from_bytes(&frame_bytes, 0).unwrap();
+        assert_eq!(result.peak_signal_amplitude, 1.0);
+    }
+}
@@ -0,0 +1,456 @@
+use crate::file_types::mp3::{
+    find_free_format_frame_length, get_frame_header_from_bytes, FrameHeader, CRC_LENGTH_IN_BYTES,
+    MAXIMUM_FREE_FORMAT_FRAME_LENGTH_IN_BYTES, MP3_HEADER_LENGTH_IN_BYTES,
+};
+use crate::mp3_frames::{vbri, xing};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{Read, Seek};
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp3_frames/scan.tmpl");
+const READ_BLOCK_LENGTH_IN_BYTES: usize = 1024 * 1024;
+const MAXIMUM_LISTED_ISSUES: usize = 20;
+const CRC_16_POLYNOMIAL: u16 = 0x8005;
+const CRC_16_INITIAL_VALUE: u16 = 0xFFFF;
+const TRAILING_TAG_IDS: [&[u8]; 4] = [b"TAG", b"APETAGEX", b"LYRICSBEGIN", b"ID3"];
+
+#[derive(Debug, Default, PartialEq)]
+struct ScanSummary {
+    number_of_frames: u64,
+    number_of_bytes: u64,
+    duration_in_seconds: f64,
+    minimum_bitrate: u16,
+    maximum_bitrate: u16,
+    bitrate_histogram: BTreeMap<u16, u64>,
+    has_info_frame: bool,
+    is_free_format: bool,
+    number_of_crc_checked_frames: u64,
+    number_of_crc_failures: u64,
+    number_of_crc_unverified_frames: u64,
+    number_of_sync_losses: u64,
+    number_of_junk_bytes: u64,
+    has_truncated_final_frame: bool,
+    issues: Vec<String>,
+}
+
+struct StreamReader<'a> {
+    file: &'a mut File,
+    buffer: Vec<u8>,
+    position: usize,
+    buffer_offset: u64,
+    is_end_of_file: bool,
+}
+
+impl<'a> StreamReader<'a> {
+    fn new(file: &'a mut File, buffer_offset: u64) -> Self {
+        Self {
+            file,
+            buffer: vec![],
+            position: 0,
+            buffer_offset,
+            is_end_of_file: false,
+        }
+    }
+
+    fn fill(&mut self, number_of_bytes: usize) -> Result<bool, Box<dyn Error>> {
+        while self.buffer.len() - self.position < number_of_bytes && !self.is_end_of_file {
+            self.buffer.drain(..self.position);
+            self.buffer_offset += self.position as u64;
+            self.position = 0;
+
+            let mut block = vec![0; READ_BLOCK_LENGTH_IN_BYTES.max(number_of_bytes)];
+            let bytes_read = self.file.read(&mut block)?;
+            if bytes_read == 0 {
+                self.is_end_of_file = true;
+            }
+            self.buffer.extend_from_slice(&block[..bytes_read]);
+        }
+
+        Ok(self.buffer.len() - self.position >= number_of_bytes)
+    }
+
+    fn peek(&self, number_of_bytes: usize) -> &[u8] {
+        let end = self.buffer.len().min(self.position + number_of_bytes);
+        &self.buffer[self.position..end]
+    }
+
+    fn advance(&mut self, number_of_bytes: usize) {
+        self.position = self.buffer.len().min(self.position + number_of_bytes);
+    }
+
+    fn offset(&self) -> u64 {
+        self.buffer_offset + self.position as u64
+    }
+
+    fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+}
+
+pub fn get_metadata_from_file(mp3_file: &mut File) -> Result<OutputEntry, Box<dyn Error>> {
+    let start_offset = mp3_file.stream_position()?;
+    let mut reader = StreamReader::new(mp3_file, start_offset);
+    let scan_summary = get_scan_summary(&mut reader)?;
+
+    get_scan_metadata(scan_summary)
+}
+
+fn get_scan_summary(reader: &mut StreamReader) -> Result<ScanSummary, Box<dyn Error>> {
+    let mut scan_summary = ScanSummary {
+        minimum_bitrate: u16::MAX,
+        ..Default::default()
+    };
+    let mut previous_header: Option<FrameHeader> = None;
+    let mut unpadded_free_format_frame_length: Option<usize> = None;
+
+    loop {
+        if !reader.fill(MP3_HEADER_LENGTH_IN_BYTES)? {
+            if reader.remaining() > 0 {
+                scan_summary.issues.push(format!(
+                    "Offset {}: {} trailing bytes after the last frame",
+                    reader.offset(),
+                    reader.remaining()
+                ));
+                scan_summary.number_of_junk_bytes += reader.remaining() as u64;
+            }
+            break;
+        }
+
+        let frame_header = get_frame_header_from_bytes(reader.peek(MP3_HEADER_LENGTH_IN_BYTES));
+        let frame_length = match &frame_header {
+            Some(frame_header) => {
+                get_frame_length(reader, frame_header)?.or(unpadded_free_format_frame_length
+                    .filter(|_| frame_header.is_free_format())
+                    .map(|length| length + frame_header.padding_length()))
+            }
+            None => None,
+        };
+
+        let (Some(mut frame_header), Some(frame_length)) = (frame_header, frame_length) else {
+            if is_trailing_tag(reader)? {
+                break;
+            }
+
+            let sync_loss_offset = reader.offset();
+            let skipped_bytes = resync(reader)?;
+            scan_summary.number_of_sync_losses += 1;
+            scan_summary.number_of_junk_bytes += skipped_bytes as u64;
+            scan_summary.issues.push(format!(
+                "Offset {}: Lost sync, skipped {} junk bytes",
+                sync_loss_offset, skipped_bytes
+            ));
+            continue;
+        };
+
+        if !reader.fill(frame_length)? {
+            scan_summary.has_truncated_final_frame = true;
+            scan_summary.issues.push(format!(
+                "Offset {}: Final frame is truncated to {} of {} bytes",
+                reader.offset(),
+                reader.remaining(),
+                frame_length
+            ));
+            break;
+        }
+
+        if frame_header.is_free_format() {
+            scan_summary.is_free_format = true;
+            unpadded_free_format_frame_length =
+                Some(frame_length.saturating_sub(frame_header.padding_length()));
+            frame_header.bitrate = frame_header.get_free_format_bitrate(frame_length);
+        }
+
+        let frame_bytes = reader.peek(frame_length);
+
+        if scan_summary.number_of_frames == 0
+            && !scan_summary.has_info_frame
+            && is_info_frame(frame_bytes, &frame_header)
+        {
+            scan_summary.has_info_frame = true;
+            reader.advance(frame_length);
+            continue;
+        }
+
+        if let Some(previous_header) = &previous_header {
+            scan_summary.issues.extend(get_stream_changes(
+                previous_header,
+                &frame_header,
+                scan_summary.number_of_frames + 1,
+            ));
+        }
+
+        if frame_header.is_protected && frame_header.is_layer_iii() {
+            scan_summary.number_of_crc_checked_frames += 1;
+            if !is_frame_crc_valid(frame_bytes, &frame_header) {
+                scan_summary.number_of_crc_failures += 1;
+                scan_summary.issues.push(format!(
+                    "Offset {}: Frame {} failed its CRC-16 check",
+                    reader.offset(),
+                    scan_summary.number_of_frames + 1
+                ));
+            }
+        } else if frame_header.is_protected {
+            // Layer I/II CRCs cover the bit allocation, which needs the subband tables.
+            scan_summary.number_of_crc_unverified_frames += 1;
+        }
+
+        scan_summary.number_of_frames += 1;
+        scan_summary.number_of_bytes += frame_length as u64;
+        scan_summary.duration_in_seconds +=
+            frame_header.samples_per_frame() as f64 / frame_header.sample_rate as f64;
+        scan_summary.minimum_bitrate = scan_summary.minimum_bitrate.min(frame_header.bitrate);
+        scan_summary.maximum_bitrate = scan_summary.maximum_bitrate.max(frame_header.bitrate);
+        *scan_summary
+            .bitrate_histogram
+            .entry(frame_header.bitrate)
+            .or_default() += 1;
+
+        reader.advance(frame_length);
+        previous_header = Some(frame_header);
+    }
+
+    Ok(scan_summary)
+}
+
+fn get_frame_length(
+    reader: &mut StreamReader,
+    frame_header: &FrameHeader,
+) -> Result<Option<usize>, Box<dyn Error>> {
+    if !frame_header.is_free_format() {
+        return Ok(Some(frame_header.frame_length()));
+    }
+
+    reader.fill(MAXIMUM_FREE_FORMAT_FRAME_LENGTH_IN_BYTES)?;
+
+    Ok(find_free_format_frame_length(
+        reader.peek(MAXIMUM_FREE_FORMAT_FRAME_LENGTH_IN_BYTES),
+        frame_header,
+    ))
+}
+
+fn is_info_frame(frame_bytes: &[u8], frame_header: &FrameHeader) -> bool {
+    xing::get_xing_tag_from_bytes(frame_bytes, frame_header).is_some()
+        || vbri::get_vbri_header_from_bytes(frame_bytes).is_some()
+}
+
+fn is_trailing_tag(reader: &mut StreamReader) -> Result<bool, Box<dyn Error>> {
+    let longest_tag_id = TRAILING_TAG_IDS
+        .iter()
+        .map(|id| id.len())
+        .max()
+        .unwrap_or(0);
+    reader.fill(longest_tag_id)?;
+
+    Ok(starts_with_trailing_tag(reader.peek(longest_tag_id)))
+}
+
+fn starts_with_trailing_tag(bytes: &[u8]) -> bool {
+    TRAILING_TAG_IDS.iter().any(|id| {
+        let length = id.len().min(bytes.len());
+        length > 0 && bytes.starts_with(&id[..length])
+    })
+}
+
+fn resync(reader: &mut StreamReader) -> Result<usize, Box<dyn Error>> {
+    let mut skipped_bytes = 0;
+
+    loop {
+        reader.advance(1);
+        skipped_bytes += 1;
+
+        if !reader.fill(MP3_HEADER_LENGTH_IN_BYTES)? {
+            skipped_bytes += reader.remaining();
+            reader.advance(reader.remaining());
+            return Ok(skipped_bytes);
+        }
+
+        let Some(candidate) = get_frame_header_from_bytes(reader.peek(MP3_HEADER_LENGTH_IN_BYTES))
+        else {
+            continue;
+        };
+
+        let Some(frame_length) = get_frame_length(reader, &candidate)? else {
+            continue;
+        };
+        if !reader.fill(frame_length + MP3_HEADER_LENGTH_IN_BYTES)? {
+            if reader.remaining() >= frame_length {
+                return Ok(skipped_bytes);
+            }
+            continue;
+        }
+
+        let next_header_bytes =
+            &reader.peek(frame_length + MP3_HEADER_LENGTH_IN_BYTES)[frame_length..];
+        let is_next_frame_consistent =
+            get_frame_header_from_bytes(next_header_bytes).is_some_and(|next| {
+                next.version == candidate.version
+                    && next.layer == candidate.layer
+                    && next.sample_rate == candidate.sample_rate
+            });
+
+        if is_next_frame_consistent || starts_with_trailing_tag(next_header_bytes) {
+            return Ok(skipped_bytes);
+        }
+    }
+}
+
+fn get_stream_changes(
+    previous_header: &FrameHeader,
+    frame_header: &FrameHeader,
+    frame_number: u64,
+) -> Vec<String> {
+    let mut changes: Vec<String> = vec![];
+
+    if previous_header.sample_rate != frame_header.sample_rate {
+        changes.push(format!(
+            "Frame {}: Sample rate changed from {} Hz to {} Hz",
+            frame_number, previous_header.sample_rate, frame_header.sample_rate
+        ));
+    }
+
+    if previous_header.version != frame_header.version
+        || previous_header.layer != frame_header.layer
+    {
+        changes.push(format!(
+            "Frame {}: MPEG version or layer changed",
+            frame_number
+        ));
+    }
+
+    if previous_header.channel_mode != frame_header.channel_mode {
+        changes.push(format!("Frame {}: Channel mode changed", frame_number));
+    }
+
+    changes
+}
+
+fn is_frame_crc_valid(frame_bytes: &[u8], frame_header: &FrameHeader) -> bool {
+    let crc_offset = MP3_HEADER_LENGTH_IN_BYTES;
+    let side_information_start = crc_offset + CRC_LENGTH_IN_BYTES;
+    let side_information_end = side_information_start + frame_header.side_information_length();
+
+    if frame_bytes.len() < side_information_end {
+        return false;
+    }
+
+    let stored_crc = u16::from_be_bytes([frame_bytes[crc_offset], frame_bytes[crc_offset + 1]]);
+    let mut checked_bytes = frame_bytes[2..MP3_HEADER_LENGTH_IN_BYTES].to_vec();
+    checked_bytes.extend_from_slice(&frame_bytes[side_information_start..side_information_end]);
+
+    get_crc_16(&checked_bytes) == stored_crc
+}
+
+fn get_crc_16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(CRC_16_INITIAL_VALUE, |mut crc, byte| {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = match crc & 0x8000 {
+                0 => crc << 1,
+                _ => (crc << 1) ^ CRC_16_POLYNOMIAL,
+            };
+        }
+        crc
+    })
+}
+
+fn get_scan_metadata(scan_summary: ScanSummary) -> Result<OutputEntry, Box<dyn Error>> {
+    let has_frames = scan_summary.number_of_frames > 0;
+    let mut average_bitrate = String::new();
+
+    if scan_summary.duration_in_seconds > 0.0 {
+        average_bitrate = format!(
+            "{:.0} kbps",
+            scan_summary.number_of_bytes as f64 * 8.0 / scan_summary.duration_in_seconds / 1000.0
+        );
+    }
+
+    let bitrate_histogram: Vec<String> = scan_summary
+        .bitrate_histogram
+        .iter()
+        .map(|(bitrate, count)| {
+            format!(
+                "{:>4} kbps: {:>8} frames ({:5.1}%)",
+                bitrate,
+                count,
+                *count as f64 / scan_summary.number_of_frames as f64 * 100.0
+            )
+        })
+        .collect();
+
+    let number_of_unlisted_issues = scan_summary
+        .issues
+        .len()
+        .saturating_sub(MAXIMUM_LISTED_ISSUES);
+    let issues: Vec<&String> = scan_summary
+        .issues
+        .iter()
+        .take(MAXIMUM_LISTED_ISSUES)
+        .collect();
+
+    let output_values: Value = upon::value! {
+        has_frames: has_frames,
+        number_of_frames: scan_summary.number_of_frames,
+        has_info_frame: scan_summary.has_info_frame,
+        is_free_format: scan_summary.is_free_format,
+        duration: format!("{:.3} seconds", scan_summary.duration_in_seconds),
+        minimum_bitrate: scan_summary.minimum_bitrate,
+        maximum_bitrate: scan_summary.maximum_bitrate,
+        average_bitrate: average_bitrate,
+        bitrate_histogram: bitrate_histogram,
+        number_of_crc_checked_frames: scan_summary.number_of_crc_checked_frames,
+        number_of_crc_failures: scan_summary.number_of_crc_failures,
+        number_of_crc_unverified_frames: scan_summary.number_of_crc_unverified_frames,
+        number_of_sync_losses: scan_summary.number_of_sync_losses,
+        number_of_junk_bytes: scan_summary.number_of_junk_bytes,
+        has_truncated_final_frame: scan_summary.has_truncated_final_frame,
+        issues: issues,
+        number_of_unlisted_issues: number_of_unlisted_issues,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_crc_16_from_bytes() {
+        assert_eq!(get_crc_16(b"123456789"), 0xAEE7);
+    }
+
+    #[test]
+    fn return_stream_changes_between_frame_headers() {
+        let previous_header = FrameHeader {
+            version: 3,
+            layer: 1,
+            sample_rate: 44100,
+            channel_mode: 1,
+            ..Default::default()
+        };
+        let frame_header = FrameHeader {
+            sample_rate: 48000,
+            channel_mode: 3,
+            ..previous_header.clone()
+        };
+
+        let result = get_stream_changes(&previous_header, &frame_header, 12);
+        assert_eq!(
+            result,
+            vec![
+                "Frame 12: Sample rate changed from 44100 Hz to 48000 Hz".to_string(),
+                "Frame 12: Channel mode changed".to_string(),
+            ]
+        );
+        assert!(get_stream_changes(&previous_header, &previous_header, 2).is_empty());
+    }
+}
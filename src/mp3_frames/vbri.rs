@@ -0,0 +1,104 @@
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp3_frames/vbri.tmpl");
+const HEADER_ID: &[u8] = b"VBRI";
+const HEADER_OFFSET: usize = 36;
+const HEADER_LENGTH_IN_BYTES: usize = 26;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct VbriHeader {
+    pub version: u16,
+    pub delay: u16,
+    pub quality: u16,
+    pub number_of_bytes: u32,
+    pub number_of_frames: u32,
+    pub number_of_toc_entries: u16,
+    pub toc_scale_factor: u16,
+    pub toc_entry_size: u16,
+    pub frames_per_toc_entry: u16,
+}
+
+pub fn get_vbri_header_from_bytes(frame_bytes: &[u8]) -> Option<VbriHeader> {
+    let header = frame_bytes.get(HEADER_OFFSET..HEADER_OFFSET + HEADER_LENGTH_IN_BYTES)?;
+
+    if !header.starts_with(HEADER_ID) {
+        return None;
+    }
+
+    let get_u16 = |offset: usize| u16::from_be_bytes([header[offset], header[offset + 1]]);
+    let get_u32 = |offset: usize| {
+        u32::from_be_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ])
+    };
+
+    Some(VbriHeader {
+        version: get_u16(4),
+        delay: get_u16(6),
+        quality: get_u16(8),
+        number_of_bytes: get_u32(10),
+        number_of_frames: get_u32(14),
+        number_of_toc_entries: get_u16(18),
+        toc_scale_factor: get_u16(20),
+        toc_entry_size: get_u16(22),
+        frames_per_toc_entry: get_u16(24),
+    })
+}
+
+pub fn get_metadata(vbri_header: &VbriHeader) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        version: vbri_header.version,
+        delay: vbri_header.delay,
+        quality: vbri_header.quality,
+        number_of_bytes: vbri_header.number_of_bytes,
+        number_of_frames: vbri_header.number_of_frames,
+        number_of_toc_entries: vbri_header.number_of_toc_entries,
+        toc_scale_factor: vbri_header.toc_scale_factor,
+        toc_entry_size: vbri_header.toc_entry_size,
+        frames_per_toc_entry: vbri_header.frames_per_toc_entry,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_vbri_header_from_first_frame_bytes() {
+        let mut frame_bytes: Vec<u8> = vec![0x00; HEADER_OFFSET];
+        frame_bytes.extend(HEADER_ID);
+        frame_bytes.extend([0x00, 0x01, 0x15, 0x7C, 0x00, 0x4B]);
+        frame_bytes.extend(2000000u32.to_be_bytes());
+        frame_bytes.extend(4000u32.to_be_bytes());
+        frame_bytes.extend([0x00, 0x64, 0x00, 0x01, 0x00, 0x02, 0x00, 0x28]);
+
+        let correct_result = VbriHeader {
+            version: 1,
+            delay: 0x157C,
+            quality: 75,
+            number_of_bytes: 2000000,
+            number_of_frames: 4000,
+            number_of_toc_entries: 100,
+            toc_scale_factor: 1,
+            toc_entry_size: 2,
+            frames_per_toc_entry: 40,
+        };
+        assert_eq!(
+            get_vbri_header_from_bytes(&frame_bytes),
+            Some(correct_result)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::file_types::mp3::FrameHeader;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp3_frames/xing.tmpl");
+const VBR_TAG_ID: &[u8] = b"Xing";
+const CBR_TAG_ID: &[u8] = b"Info";
+const TAG_ID_LENGTH_IN_BYTES: usize = 4;
+const FLAGS_LENGTH_IN_BYTES: usize = 4;
+const FRAMES_FLAG: u32 = 0x01;
+const BYTES_FLAG: u32 = 0x02;
+const TOC_FLAG: u32 = 0x04;
+const QUALITY_FLAG: u32 = 0x08;
+const FIELD_LENGTH_IN_BYTES: usize = 4;
+const TOC_LENGTH_IN_BYTES: usize = 100;
+const TOC_ENTRIES_PER_ROW: usize = 20;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct XingTag {
+    pub is_vbr: bool,
+    pub number_of_frames: Option<u32>,
+    pub number_of_bytes: Option<u32>,
+    pub toc: Option<Vec<u8>>,
+    pub quality: Option<u32>,
+    pub lame_offset: usize,
+}
+
+pub fn get_xing_tag_from_bytes(frame_bytes: &[u8], frame_header: &FrameHeader) -> Option<XingTag> {
+    if !frame_header.is_layer_iii() {
+        return None;
+    }
+
+    let tag_offset = frame_header.side_information_offset();
+    let tag_id = frame_bytes.get(tag_offset..tag_offset + TAG_ID_LENGTH_IN_BYTES)?;
+
+    let is_vbr = match tag_id {
+        VBR_TAG_ID => true,
+        CBR_TAG_ID => false,
+        _ => return None,
+    };
+
+    let mut position = tag_offset + TAG_ID_LENGTH_IN_BYTES;
+    let flags = get_u32_at_offset(frame_bytes, position)?;
+    position += FLAGS_LENGTH_IN_BYTES;
+
+    let mut take_field = |flag: u32, length: usize| -> Option<&[u8]> {
+        if flags & flag == 0 {
+            return None;
+        }
+        let field = frame_bytes.get(position..position + length);
+        position += length;
+        field
+    };
+
+    let number_of_frames = take_field(FRAMES_FLAG, FIELD_LENGTH_IN_BYTES).map(get_u32_from_field);
+    let number_of_bytes = take_field(BYTES_FLAG, FIELD_LENGTH_IN_BYTES).map(get_u32_from_field);
+    let toc = take_field(TOC_FLAG, TOC_LENGTH_IN_BYTES).map(|field| field.to_vec());
+    let quality = take_field(QUALITY_FLAG, FIELD_LENGTH_IN_BYTES).map(get_u32_from_field);
+
+    Some(XingTag {
+        is_vbr,
+        number_of_frames,
+        number_of_bytes,
+        toc,
+        quality,
+        lame_offset: position,
+    })
+}
+
+pub fn get_metadata(xing_tag: &XingTag) -> Result<OutputEntry, Box<dyn Error>> {
+    let toc_rows: Vec<String> = xing_tag
+        .toc
+        .as_deref()
+        .unwrap_or_default()
+        .chunks(TOC_ENTRIES_PER_ROW)
+        .map(|row| {
+            row.iter()
+                .map(|entry| format!("{:3}", entry))
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect();
+
+    let output_values: Value = upon::value! {
+        tag_id: match xing_tag.is_vbr {
+            true => "Xing (VBR)",
+            false => "Info (CBR)",
+        },
+        number_of_frames: format_optional_field(xing_tag.number_of_frames),
+        number_of_bytes: format_optional_field(xing_tag.number_of_bytes),
+        quality: format_optional_field(xing_tag.quality),
+        toc_rows: toc_rows,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_u32_at_offset(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset + FIELD_LENGTH_IN_BYTES)
+        .map(get_u32_from_field)
+}
+
+fn get_u32_from_field(field: &[u8]) -> u32 {
+    u32::from_be_bytes([field[0], field[1], field[2], field[3]])
+}
+
+fn format_optional_field(field: Option<u32>) -> String {
+    match field {
+        Some(value) => value.to_string(),
+        None => "Not Present".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_xing_tag_from_first_frame_bytes() {
+        let frame_header = FrameHeader {
+            version: 3,
+            layer: 1,
+            is_protected: false,
+            bitrate: 128,
+            sample_rate: 44100,
+            is_padded: false,
+            channel_mode: 1,
+        };
+        let mut frame_bytes: Vec<u8> = vec![0x00; 36];
+        frame_bytes.extend(VBR_TAG_ID);
+        frame_bytes.extend(0x09u32.to_be_bytes());
+        frame_bytes.extend(1000u32.to_be_bytes());
+        frame_bytes.extend(57u32.to_be_bytes());
+
+        let correct_result = XingTag {
+            is_vbr: true,
+            number_of_frames: Some(1000),
+            number_of_bytes: None,
+            toc: None,
+            quality: Some(57),
+            lame_offset: 52,
+        };
+        assert_eq!(
+            get_xing_tag_from_bytes(&frame_bytes, &frame_header),
+            Some(correct_result)
+        );
+    }
+}
@@ -0,0 +1,215 @@
+pub mod alac;
+pub mod dac3;
+pub mod dops;
+pub mod esds;
+pub mod headers;
+pub mod ilst;
+pub mod sample_entries;
+pub mod tree;
+
+use crate::byte_arrays::{
+    take_first_eight_bytes_as_unsigned_integer, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes, Endian,
+};
+use crate::errors::LocalError;
+
+pub const ATOM_HEADER_LENGTH_IN_BYTES: usize = 8;
+pub const EXTENDED_SIZE_LENGTH_IN_BYTES: usize = 8;
+pub const EXTENDED_SIZE_MARKER: u64 = 1;
+pub const TO_END_OF_FILE_SIZE_MARKER: u64 = 0;
+pub const FULL_ATOM_FIELDS_LENGTH_IN_BYTES: usize = 4;
+pub const ATOM_ID_LENGTH_IN_BYTES: usize = 4;
+pub const ILST_ATOM_ID: &str = "ilst";
+pub const STSD_ATOM_ID: &str = "stsd";
+pub const MDAT_ATOM_ID: &str = "mdat";
+pub const FREE_ATOM_ID: &str = "free";
+pub const SKIP_ATOM_ID: &str = "skip";
+pub const WIDE_ATOM_ID: &str = "wide";
+pub const CHANNEL_LAYOUT_ATOM_ID: &str = "chan";
+const META_ATOM_ID: &str = "meta";
+const HDLR_ATOM_ID: &[u8] = b"hdlr";
+const SAMPLE_DESCRIPTION_FIELDS_LENGTH_IN_BYTES: usize = 8;
+const CONTAINER_ATOM_IDS: [&str; 19] = [
+    "moov", "trak", "mdia", "minf", "stbl", "udta", "edts", "dinf", "mvex", "moof", "traf", "tref",
+    "ilst", "sinf", "schi", "gmhd", "wave", "mfra", "trgr",
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Mp4Atom {
+    pub id: String,
+    pub offset: u64,
+    pub size: u64,
+    pub data: Vec<u8>,
+    pub children: Vec<Mp4Atom>,
+}
+
+pub fn get_atom_id_from_bytes(id_bytes: &[u8]) -> String {
+    id_bytes.iter().map(|byte| *byte as char).collect()
+}
+
+pub fn get_atom_from_bytes(
+    id: String,
+    offset: u64,
+    size: u64,
+    mut data: Vec<u8>,
+    parent_id: &str,
+) -> Result<Mp4Atom, LocalError> {
+    let header_length = size.saturating_sub(data.len() as u64);
+
+    let children = match get_children_start(&id, &data, parent_id) {
+        Some(children_start) if children_start <= data.len() => {
+            let children_data = data.split_off(children_start);
+            let children_offset = offset + header_length + children_start as u64;
+            get_child_atoms_from_bytes(children_data, children_offset, &id)?
+        }
+        _ => vec![],
+    };
+
+    Ok(Mp4Atom {
+        id,
+        offset,
+        size,
+        data,
+        children,
+    })
+}
+
+pub fn get_child_atoms_from_bytes(
+    mut atom_data: Vec<u8>,
+    data_offset: u64,
+    parent_id: &str,
+) -> Result<Vec<Mp4Atom>, LocalError> {
+    let mut atoms: Vec<Mp4Atom> = vec![];
+    let mut offset = data_offset;
+
+    while atom_data.len() >= ATOM_HEADER_LENGTH_IN_BYTES {
+        let mut size =
+            take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)? as u64;
+        let id_bytes = take_first_number_of_bytes(&mut atom_data, ATOM_ID_LENGTH_IN_BYTES)?;
+        let mut header_length = ATOM_HEADER_LENGTH_IN_BYTES as u64;
+
+        if id_bytes.iter().all(|byte| *byte == 0x00) {
+            break;
+        }
+
+        if size == EXTENDED_SIZE_MARKER && atom_data.len() >= EXTENDED_SIZE_LENGTH_IN_BYTES {
+            size = take_first_eight_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+            header_length += EXTENDED_SIZE_LENGTH_IN_BYTES as u64;
+        } else if size == TO_END_OF_FILE_SIZE_MARKER {
+            size = header_length + atom_data.len() as u64;
+        }
+
+        if size < header_length {
+            break;
+        }
+
+        let data_length = ((size - header_length) as usize).min(atom_data.len());
+        let data = take_first_number_of_bytes(&mut atom_data, data_length)?;
+
+        atoms.push(get_atom_from_bytes(
+            get_atom_id_from_bytes(&id_bytes),
+            offset,
+            size,
+            data,
+            parent_id,
+        )?);
+        offset += size;
+    }
+
+    Ok(atoms)
+}
+
+pub fn find_atom<'a>(atoms: &'a [Mp4Atom], path: &[&str]) -> Option<&'a Mp4Atom> {
+    let (first_id, remaining_path) = path.split_first()?;
+    let atom = atoms.iter().find(|atom| atom.id == *first_id)?;
+
+    match remaining_path.is_empty() {
+        true => Some(atom),
+        false => find_atom(&atom.children, remaining_path),
+    }
+}
+
+fn get_children_start(id: &str, data: &[u8], parent_id: &str) -> Option<usize> {
+    if CONTAINER_ATOM_IDS.contains(&id) || parent_id == ILST_ATOM_ID {
+        return Some(0);
+    }
+
+    match id {
+        META_ATOM_ID => match data.get(ATOM_ID_LENGTH_IN_BYTES..ATOM_HEADER_LENGTH_IN_BYTES) {
+            Some(HDLR_ATOM_ID) => Some(0),
+            _ => Some(FULL_ATOM_FIELDS_LENGTH_IN_BYTES),
+        },
+        STSD_ATOM_ID => Some(SAMPLE_DESCRIPTION_FIELDS_LENGTH_IN_BYTES),
+        _ if parent_id == STSD_ATOM_ID => sample_entries::get_sample_entry_fields_length(id, data),
+        _ => None,
+    }
+}
+
+pub fn format_duration(duration: u64, timescale: u32) -> String {
+    if timescale == 0 {
+        return "Unknown".to_string();
+    }
+
+    let total_milliseconds = duration as u128 * 1000 / timescale as u128;
+    let total_seconds = total_milliseconds / 1000;
+
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        total_seconds / 3600,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+        total_milliseconds % 1000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_atom(id: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut atom = ((data.len() + ATOM_HEADER_LENGTH_IN_BYTES) as u32)
+            .to_be_bytes()
+            .to_vec();
+        atom.extend(id);
+        atom.extend(data);
+        atom
+    }
+
+    #[test]
+    fn return_nested_atoms_with_offsets_from_valid_bytes() {
+        let mvhd = get_test_atom(b"mvhd", &[0x00; 4]);
+        let udta = get_test_atom(b"udta", &get_test_atom(b"\xA9nam", &[]));
+        let mut moov_data = mvhd.clone();
+        moov_data.extend(udta);
+        let moov = get_test_atom(b"moov", &moov_data);
+
+        let result = get_child_atoms_from_bytes(moov, 100, "").unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].children.len(), 2);
+        assert_eq!(result[0].children[1].offset, 120);
+        assert_eq!(result[0].children[1].children[0].id, "©nam");
+        assert_eq!(
+            find_atom(&result, &["moov", "mvhd"]).unwrap().data,
+            [0x00; 4]
+        );
+    }
+
+    #[test]
+    fn return_children_after_version_and_flags_for_iso_meta_atoms_only() {
+        let hdlr = get_test_atom(b"hdlr", &[]);
+        let mut iso_meta = vec![0x00; FULL_ATOM_FIELDS_LENGTH_IN_BYTES];
+        iso_meta.extend(&hdlr);
+
+        let iso_result = get_child_atoms_from_bytes(get_test_atom(b"meta", &iso_meta), 0, "");
+        let quicktime_result = get_child_atoms_from_bytes(get_test_atom(b"meta", &hdlr), 0, "");
+        assert_eq!(iso_result.unwrap()[0].children[0].id, "hdlr");
+        assert_eq!(quicktime_result.unwrap()[0].children[0].id, "hdlr");
+    }
+
+    #[test]
+    fn return_correct_duration_string_from_timescale_units() {
+        assert_eq!(format_duration(3_661_500, 1000), "01:01:01.500");
+        assert_eq!(format_duration(88200, 44100), "00:00:02.000");
+        assert_eq!(format_duration(1, 0), "Unknown");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::KeyValuePair as Detail;
+use crate::mp4_atoms::{
+    get_atom_id_from_bytes, ATOM_HEADER_LENGTH_IN_BYTES, ATOM_ID_LENGTH_IN_BYTES,
+    CHANNEL_LAYOUT_ATOM_ID, FULL_ATOM_FIELDS_LENGTH_IN_BYTES,
+};
+
+const CHANNEL_LAYOUT_TAG_OFFSET: usize = FULL_ATOM_FIELDS_LENGTH_IN_BYTES;
+const CHANNEL_LAYOUT_TAG_SHIFT: u32 = 16;
+const CHANNEL_LAYOUT_CHANNEL_COUNT_MASK: u32 = 0xFFFF;
+
+const CHANNEL_LAYOUTS: [(u32, &str); 8] = [
+    ((100 << 16) | 1, "Mono (C)"),
+    ((101 << 16) | 2, "Stereo (L R)"),
+    ((113 << 16) | 3, "MPEG 3.0 B (C L R)"),
+    ((116 << 16) | 4, "MPEG 4.0 B (C L R Cs)"),
+    ((120 << 16) | 5, "MPEG 5.0 D (C L R Ls Rs)"),
+    ((124 << 16) | 6, "MPEG 5.1 D (C L R Ls Rs LFE)"),
+    ((142 << 16) | 7, "AAC 6.1 (C L R Ls Rs Cs LFE)"),
+    ((127 << 16) | 8, "MPEG 7.1 B (C Lc Rc L R Ls Rs LFE)"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct AlacSpecificConfig {
+    frame_length: u32,
+    compatible_version: u8,
+    bit_depth: u8,
+    pb: u8,
+    mb: u8,
+    kb: u8,
+    channels: u8,
+    max_run: u16,
+    max_frame_bytes: u32,
+    average_bitrate: u32,
+    sample_rate: u32,
+}
+
+pub fn get_details(
+    mut atom_data: Vec<u8>,
+    channel_layout_data: Option<&[u8]>,
+) -> Result<Vec<Detail>, LocalError> {
+    skip_over_bytes(&mut atom_data, FULL_ATOM_FIELDS_LENGTH_IN_BYTES)?;
+    let config = get_alac_specific_config_from_bytes(&mut atom_data)?;
+
+    let channel_layout_data = channel_layout_data.or(get_trailing_channel_layout_data(&atom_data));
+
+    let channel_layout = match channel_layout_data.and_then(get_channel_layout_tag_from_bytes) {
+        Some(tag) => get_channel_layout_name(tag),
+        None => format!(
+            "{} (Default)",
+            get_default_channel_layout_name(config.channels)
+        ),
+    };
+
+    Ok(vec![
+        get_detail("Frame Length", format!("{} samples", config.frame_length)),
+        get_detail("Compatible Version", config.compatible_version.to_string()),
+        get_detail("Bit Depth", format!("{} bits", config.bit_depth)),
+        get_detail("Rice History Mult (pb)", config.pb.to_string()),
+        get_detail("Rice Initial History (mb)", config.mb.to_string()),
+        get_detail("Rice Parameter Limit (kb)", config.kb.to_string()),
+        get_detail("Channels", config.channels.to_string()),
+        get_detail("Channel Layout", channel_layout),
+        get_detail("Maximum Run", config.max_run.to_string()),
+        get_detail(
+            "Maximum Frame Size",
+            format!("{} bytes", config.max_frame_bytes),
+        ),
+        get_detail(
+            "Average Bitrate",
+            format!("{} kbps", config.average_bitrate as f64 / 1000.0),
+        ),
+        get_detail("Sample Rate", format!("{} Hz", config.sample_rate)),
+    ])
+}
+
+fn get_alac_specific_config_from_bytes(
+    config_data: &mut Vec<u8>,
+) -> Result<AlacSpecificConfig, LocalError> {
+    Ok(AlacSpecificConfig {
+        frame_length: take_first_four_bytes_as_unsigned_integer(config_data, Endian::Big)?,
+        compatible_version: take_first_byte(config_data)?,
+        bit_depth: take_first_byte(config_data)?,
+        pb: take_first_byte(config_data)?,
+        mb: take_first_byte(config_data)?,
+        kb: take_first_byte(config_data)?,
+        channels: take_first_byte(config_data)?,
+        max_run: take_first_two_bytes_as_unsigned_integer(config_data, Endian::Big)?,
+        max_frame_bytes: take_first_four_bytes_as_unsigned_integer(config_data, Endian::Big)?,
+        average_bitrate: take_first_four_bytes_as_unsigned_integer(config_data, Endian::Big)?,
+        sample_rate: take_first_four_bytes_as_unsigned_integer(config_data, Endian::Big)?,
+    })
+}
+
+fn get_trailing_channel_layout_data(trailing_data: &[u8]) -> Option<&[u8]> {
+    let id_bytes = trailing_data.get(ATOM_ID_LENGTH_IN_BYTES..ATOM_HEADER_LENGTH_IN_BYTES)?;
+
+    match get_atom_id_from_bytes(id_bytes) == CHANNEL_LAYOUT_ATOM_ID {
+        true => trailing_data.get(ATOM_HEADER_LENGTH_IN_BYTES..),
+        false => None,
+    }
+}
+
+fn get_channel_layout_tag_from_bytes(channel_layout_data: &[u8]) -> Option<u32> {
+    let tag_bytes =
+        channel_layout_data.get(CHANNEL_LAYOUT_TAG_OFFSET..CHANNEL_LAYOUT_TAG_OFFSET + 4)?;
+
+    Some(u32::from_be_bytes([
+        tag_bytes[0],
+        tag_bytes[1],
+        tag_bytes[2],
+        tag_bytes[3],
+    ]))
+}
+
+fn get_channel_layout_name(tag: u32) -> String {
+    match CHANNEL_LAYOUTS
+        .iter()
+        .find(|(layout_tag, _)| *layout_tag == tag)
+    {
+        Some((_, name)) => name.to_string(),
+        None => format!(
+            "Unknown (Tag {}, {} channels)",
+            tag >> CHANNEL_LAYOUT_TAG_SHIFT,
+            tag & CHANNEL_LAYOUT_CHANNEL_COUNT_MASK
+        ),
+    }
+}
+
+fn get_default_channel_layout_name(channels: u8) -> String {
+    match CHANNEL_LAYOUTS
+        .iter()
+        .find(|(tag, _)| tag & CHANNEL_LAYOUT_CHANNEL_COUNT_MASK == channels as u32)
+    {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({} channels)", channels),
+    }
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_alac_atom_data(channels: u8) -> Vec<u8> {
+        let mut atom_data = vec![0x00; 4];
+        atom_data.extend(4096u32.to_be_bytes());
+        atom_data.extend([0, 16, 40, 10, 14, channels]);
+        atom_data.extend(255u16.to_be_bytes());
+        atom_data.extend(0u32.to_be_bytes());
+        atom_data.extend(1411200u32.to_be_bytes());
+        atom_data.extend(44100u32.to_be_bytes());
+        atom_data
+    }
+
+    #[test]
+    fn return_config_details_with_default_layout_when_no_channel_layout_atom_exists() {
+        let result = get_details(get_test_alac_atom_data(2), None).unwrap();
+        assert_eq!(result[0].value, "4096 samples");
+        assert_eq!(result[2].value, "16 bits");
+        assert_eq!(result[7].value, "Stereo (L R) (Default)");
+        assert_eq!(result[10].value, "1411.2 kbps");
+        assert_eq!(result[11].value, "44100 Hz");
+    }
+
+    #[test]
+    fn return_channel_layout_name_from_channel_layout_atom() {
+        let mut channel_layout_data = vec![0x00; 4];
+        channel_layout_data.extend(((124u32 << 16) | 6).to_be_bytes());
+        channel_layout_data.extend([0x00; 8]);
+
+        let result = get_details(get_test_alac_atom_data(6), Some(&channel_layout_data)).unwrap();
+        assert_eq!(result[7].value, "MPEG 5.1 D (C L R Ls Rs LFE)");
+
+        let mut atom_data = get_test_alac_atom_data(6);
+        atom_data.extend(24u32.to_be_bytes());
+        atom_data.extend(b"chan");
+        atom_data.extend(channel_layout_data);
+
+        let result = get_details(atom_data, None).unwrap();
+        assert_eq!(result[7].value, "MPEG 5.1 D (C L R Ls Rs LFE)");
+    }
+}
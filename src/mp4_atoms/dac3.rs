@@ -0,0 +1,185 @@
+use crate::ac3_frames::{
+    get_channel_count, get_channel_layout, get_name_from_list, BitReader, BITRATES_IN_KBPS,
+    BIT_STREAM_MODES, SAMPLE_RATES,
+};
+use crate::formating::KeyValuePair as Detail;
+
+pub const AC3_SPECIFIC_BOX_ID: &str = "dac3";
+pub const EAC3_SPECIFIC_BOX_ID: &str = "dec3";
+const AC3_SPECIFIC_BOX_LENGTH_IN_BITS: usize = 24;
+const EAC3_INDEPENDENT_SUBSTREAM_LENGTH_IN_BITS: usize = 23;
+const JOINT_OBJECT_CODING_EXTENSION_LENGTH_IN_BITS: usize = 16;
+
+const CHANNEL_LOCATIONS: [&str; 9] = [
+    "Lc/Rc", "Lrs/Rrs", "Cs", "Ts", "Lsd/Rsd", "Lw/Rw", "Lvh/Rvh", "Cvh", "LFE2",
+];
+
+pub fn get_details(id: &str, atom_data: &[u8]) -> Vec<Detail> {
+    match id {
+        EAC3_SPECIFIC_BOX_ID => get_eac3_details(atom_data),
+        _ => get_ac3_details(atom_data),
+    }
+}
+
+fn get_ac3_details(atom_data: &[u8]) -> Vec<Detail> {
+    let mut bits = BitReader::new(atom_data);
+
+    if bits.remaining_bits() < AC3_SPECIFIC_BOX_LENGTH_IN_BITS {
+        return vec![];
+    }
+
+    let fscod = bits.take_bits(2) as u8;
+    let bsid = bits.take_bits(5);
+    let bsmod = bits.take_bits(3) as u8;
+    let acmod = bits.take_bits(3) as u8;
+    let lfe_on = bits.take_flag();
+    let bit_rate_code = bits.take_bits(5) as usize;
+
+    let bitrate = match BITRATES_IN_KBPS.get(bit_rate_code) {
+        Some(bitrate) => format!("{} kbps", bitrate),
+        None => format!("Unknown ({})", bit_rate_code),
+    };
+
+    vec![
+        get_detail("Sample Rate", format_sample_rate(fscod)),
+        get_detail("Bit Stream ID", bsid.to_string()),
+        get_detail(
+            "Bit Stream Mode",
+            get_name_from_list(&BIT_STREAM_MODES, bsmod),
+        ),
+        get_detail("Channel Layout", get_channel_layout(acmod, lfe_on)),
+        get_detail("Channels", get_channel_count(acmod, lfe_on).to_string()),
+        get_detail("Bitrate", bitrate),
+    ]
+}
+
+fn get_eac3_details(atom_data: &[u8]) -> Vec<Detail> {
+    let mut bits = BitReader::new(atom_data);
+    let data_rate = bits.take_bits(13);
+    let independent_substreams = bits.take_bits(3) + 1;
+
+    let mut details = vec![
+        get_detail("Data Rate", format!("{} kbps", data_rate)),
+        get_detail("Independent Substreams", independent_substreams.to_string()),
+    ];
+
+    for substream in 0..independent_substreams {
+        if bits.remaining_bits() < EAC3_INDEPENDENT_SUBSTREAM_LENGTH_IN_BITS {
+            break;
+        }
+
+        let fscod = bits.take_bits(2) as u8;
+        let bsid = bits.take_bits(5);
+        let _reserved = bits.take_bits(1);
+        let is_main_service = !bits.take_flag();
+        let bsmod = bits.take_bits(3) as u8;
+        let acmod = bits.take_bits(3) as u8;
+        let lfe_on = bits.take_flag();
+        let _reserved = bits.take_bits(3);
+        let dependent_substreams = bits.take_bits(4);
+
+        let mut channel_layout = get_channel_layout(acmod, lfe_on);
+
+        if dependent_substreams > 0 {
+            let channel_locations = format_channel_locations(bits.take_bits(9));
+            channel_layout = format!("{} + {}", channel_layout, channel_locations);
+        } else {
+            let _reserved = bits.take_bits(1);
+        }
+
+        let prefix = format!("Substream {}", substream);
+        details.push(get_detail(
+            &format!("{} Sample Rate", prefix),
+            format_sample_rate(fscod),
+        ));
+        details.push(get_detail(
+            &format!("{} Bit Stream ID", prefix),
+            bsid.to_string(),
+        ));
+        details.push(get_detail(
+            &format!("{} Bit Stream Mode", prefix),
+            get_name_from_list(&BIT_STREAM_MODES, bsmod),
+        ));
+        details.push(get_detail(
+            &format!("{} Main Service", prefix),
+            is_main_service.to_string(),
+        ));
+        details.push(get_detail(
+            &format!("{} Channel Layout", prefix),
+            channel_layout,
+        ));
+        details.push(get_detail(
+            &format!("{} Dependent Substreams", prefix),
+            dependent_substreams.to_string(),
+        ));
+    }
+
+    if bits.remaining_bits() >= JOINT_OBJECT_CODING_EXTENSION_LENGTH_IN_BITS {
+        let _reserved = bits.take_bits(7);
+
+        if bits.take_flag() {
+            details.push(get_detail(
+                "Joint Object Coding (Atmos)",
+                format!("Complexity Index {}", bits.take_bits(8)),
+            ));
+        }
+    }
+
+    details
+}
+
+fn format_sample_rate(fscod: u8) -> String {
+    match SAMPLE_RATES.get(fscod as usize) {
+        Some(sample_rate) => format!("{} Hz", sample_rate),
+        None => format!("Reserved ({})", fscod),
+    }
+}
+
+fn format_channel_locations(channel_locations: u32) -> String {
+    CHANNEL_LOCATIONS
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| channel_locations & (0x100 >> index) != 0)
+        .map(|(_, location)| *location)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_details_from_5_1_ac3_specific_box() {
+        let result = get_details(AC3_SPECIFIC_BOX_ID, &[0x10, 0x3D, 0xE0]);
+        assert_eq!(result[0].value, "48000 Hz");
+        assert_eq!(result[1].value, "8");
+        assert_eq!(result[2].value, "Complete Main (CM)");
+        assert_eq!(result[3].value, "3/2 (L, C, R, Ls, Rs) + LFE");
+        assert_eq!(result[4].value, "6");
+        assert_eq!(result[5].value, "448 kbps");
+    }
+
+    #[test]
+    fn return_substream_and_atmos_details_from_eac3_specific_box() {
+        let result = get_details(
+            EAC3_SPECIFIC_BOX_ID,
+            &[0x0C, 0x00, 0x20, 0x0F, 0x02, 0x80, 0x01, 0x10],
+        );
+        assert_eq!(result[0].value, "384 kbps");
+        assert_eq!(result[1].value, "1");
+        assert_eq!(result[2].value, "48000 Hz");
+        assert_eq!(result[3].value, "16");
+        assert_eq!(result[6].value, "3/2 (L, C, R, Ls, Rs) + LFE + Lrs/Rrs");
+        assert_eq!(result[7].value, "1");
+        assert_eq!(result[8].value, "Complexity Index 16");
+    }
+}
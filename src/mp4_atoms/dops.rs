@@ -0,0 +1,85 @@
+use crate::byte_arrays::{
+    take_first_byte, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_two_bytes_as_signed_integer, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::KeyValuePair as Detail;
+use crate::ogg_headers::opus::{format_output_gain, get_channel_mapping_family_name};
+
+const RTP_MAPPING_FAMILY: u8 = 0;
+
+pub fn get_details(mut atom_data: Vec<u8>) -> Result<Vec<Detail>, LocalError> {
+    let version = take_first_byte(&mut atom_data)?;
+    let channels = take_first_byte(&mut atom_data)?;
+    let pre_skip = take_first_two_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let input_sample_rate = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let output_gain = take_first_two_bytes_as_signed_integer(&mut atom_data, Endian::Big)?;
+    let channel_mapping_family = take_first_byte(&mut atom_data)?;
+
+    let mut details = vec![
+        get_detail("Version", version.to_string()),
+        get_detail("Output Channels", channels.to_string()),
+        get_detail("Pre-skip", format!("{} samples", pre_skip)),
+        get_detail("Input Sample Rate", format!("{} Hz", input_sample_rate)),
+        get_detail(
+            "Output Gain",
+            format!("{} dB", format_output_gain(output_gain)),
+        ),
+        get_detail(
+            "Channel Mapping Family",
+            format!(
+                "{} - {}",
+                channel_mapping_family,
+                get_channel_mapping_family_name(channel_mapping_family)
+            ),
+        ),
+    ];
+
+    if channel_mapping_family != RTP_MAPPING_FAMILY {
+        let stream_count = take_first_byte(&mut atom_data)?;
+        let coupled_stream_count = take_first_byte(&mut atom_data)?;
+        let channel_mapping = take_first_number_of_bytes(&mut atom_data, channels as usize)?
+            .iter()
+            .map(|channel| channel.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        details.push(get_detail("Stream Count", stream_count.to_string()));
+        details.push(get_detail(
+            "Coupled Stream Count",
+            coupled_stream_count.to_string(),
+        ));
+        details.push(get_detail("Channel Mapping", channel_mapping));
+    }
+
+    Ok(details)
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_details_from_big_endian_surround_dops_bytes() {
+        let mut atom_data = vec![0x00, 0x06];
+        atom_data.extend(312u16.to_be_bytes());
+        atom_data.extend(48000u32.to_be_bytes());
+        atom_data.extend((-1536i16).to_be_bytes());
+        atom_data.extend([0x01, 0x04, 0x02, 0, 4, 1, 2, 3, 5]);
+
+        let result = get_details(atom_data).unwrap();
+        assert_eq!(result[2].value, "312 samples");
+        assert_eq!(result[3].value, "48000 Hz");
+        assert_eq!(result[4].value, "-6.00 dB");
+        assert_eq!(result[5].value, "1 - Vorbis Channel Order");
+        assert_eq!(result[8].value, "0, 4, 1, 2, 3, 5");
+    }
+}
@@ -0,0 +1,403 @@
+use crate::ac3_frames::BitReader;
+use crate::file_types::aac::{
+    get_channel_configuration_name, get_object_type_name, get_sample_rate_from_index,
+    ESCAPE_OBJECT_TYPE, EXPLICIT_SAMPLE_RATE_INDEX,
+};
+use crate::formating::KeyValuePair as Detail;
+use crate::mp4_atoms::FULL_ATOM_FIELDS_LENGTH_IN_BYTES;
+
+const ES_DESCRIPTOR_TAG: u8 = 0x03;
+const DECODER_CONFIG_DESCRIPTOR_TAG: u8 = 0x04;
+const DECODER_SPECIFIC_INFO_TAG: u8 = 0x05;
+const MAXIMUM_DESCRIPTOR_SIZE_LENGTH_IN_BYTES: usize = 4;
+const DESCRIPTOR_SIZE_CONTINUATION_MASK: u8 = 0x80;
+const DESCRIPTOR_SIZE_VALUE_MASK: u8 = 0x7F;
+const ES_ID_LENGTH_IN_BYTES: usize = 2;
+const STREAM_DEPENDENCE_FLAG_MASK: u8 = 0x80;
+const URL_FLAG_MASK: u8 = 0x40;
+const OCR_STREAM_FLAG_MASK: u8 = 0x20;
+const DEPENDS_ON_ES_ID_LENGTH_IN_BYTES: usize = 2;
+const OCR_ES_ID_LENGTH_IN_BYTES: usize = 2;
+const DECODER_CONFIG_FIELDS_LENGTH_IN_BYTES: usize = 13;
+const STREAM_TYPE_SHIFT: u8 = 2;
+const SBR_OBJECT_TYPE: u8 = 5;
+const PS_OBJECT_TYPE: u8 = 29;
+const SBR_SYNC_EXTENSION_TYPE: u32 = 0x2B7;
+const PS_SYNC_EXTENSION_TYPE: u32 = 0x548;
+const SYNC_EXTENSION_TYPE_LENGTH_IN_BITS: usize = 11;
+const MINIMUM_SBR_EXTENSION_LENGTH_IN_BITS: usize = 16;
+const MINIMUM_PS_EXTENSION_LENGTH_IN_BITS: usize = 12;
+const GA_SPECIFIC_CONFIG_OBJECT_TYPES: [u8; 12] = [1, 2, 3, 4, 6, 7, 17, 19, 20, 21, 22, 23];
+const CORE_CODER_DELAY_LENGTH_IN_BITS: usize = 14;
+const LAYER_NUMBER_OBJECT_TYPES: [u8; 2] = [6, 20];
+const LAYER_NUMBER_LENGTH_IN_BITS: usize = 3;
+const EXTENSION_FLAG_OBJECT_TYPES: [u8; 5] = [17, 19, 20, 21, 23];
+const DEFAULT_FRAME_LENGTH: u32 = 1024;
+const SHORT_FRAME_LENGTH: u32 = 960;
+
+const OBJECT_TYPE_INDICATIONS: [(u8, &str); 14] = [
+    (0x20, "MPEG-4 Visual"),
+    (0x21, "H.264/AVC"),
+    (0x40, "MPEG-4 Audio"),
+    (0x66, "MPEG-2 AAC Main"),
+    (0x67, "MPEG-2 AAC LC"),
+    (0x68, "MPEG-2 AAC SSR"),
+    (0x69, "MPEG-2 Audio (MP3)"),
+    (0x6B, "MPEG-1 Audio (MP3)"),
+    (0x6C, "JPEG"),
+    (0xA5, "AC-3"),
+    (0xA6, "E-AC-3"),
+    (0xA9, "DTS"),
+    (0xDD, "Vorbis"),
+    (0xAD, "Opus"),
+];
+
+const STREAM_TYPES: [(u8, &str); 6] = [
+    (1, "Object Descriptor"),
+    (2, "Clock Reference"),
+    (3, "Scene Description"),
+    (4, "Visual"),
+    (5, "Audio"),
+    (6, "MPEG-7"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+struct AudioSpecificConfig {
+    object_type: u8,
+    sample_rate: u32,
+    channel_configuration: u8,
+    frame_length: u32,
+    extension_object_type: Option<u8>,
+    extension_sample_rate: Option<u32>,
+    is_sbr_present: bool,
+    is_ps_present: bool,
+    is_explicitly_signalled: bool,
+}
+
+pub fn get_details(atom_data: &[u8]) -> Vec<Detail> {
+    let mut details: Vec<Detail> = vec![];
+
+    let descriptors = atom_data
+        .get(FULL_ATOM_FIELDS_LENGTH_IN_BYTES..)
+        .unwrap_or_default();
+    let Some(es_descriptor) = find_descriptor(descriptors, ES_DESCRIPTOR_TAG) else {
+        return details;
+    };
+    let Some(es_sub_descriptors) = get_es_sub_descriptors(es_descriptor) else {
+        return details;
+    };
+    let Some(decoder_config) = find_descriptor(es_sub_descriptors, DECODER_CONFIG_DESCRIPTOR_TAG)
+    else {
+        return details;
+    };
+    let Some(config_fields) = decoder_config.get(..DECODER_CONFIG_FIELDS_LENGTH_IN_BYTES) else {
+        return details;
+    };
+
+    let object_type_indication = config_fields[0];
+    let stream_type = config_fields[1] >> STREAM_TYPE_SHIFT;
+    let buffer_size = u32::from_be_bytes([0, config_fields[2], config_fields[3], config_fields[4]]);
+    let maximum_bitrate = u32::from_be_bytes([
+        config_fields[5],
+        config_fields[6],
+        config_fields[7],
+        config_fields[8],
+    ]);
+    let average_bitrate = u32::from_be_bytes([
+        config_fields[9],
+        config_fields[10],
+        config_fields[11],
+        config_fields[12],
+    ]);
+
+    details.push(get_detail(
+        "Object Type Indication",
+        format!(
+            "{} ({:#04X})",
+            get_name_from_table(&OBJECT_TYPE_INDICATIONS, object_type_indication),
+            object_type_indication
+        ),
+    ));
+    details.push(get_detail(
+        "Stream Type",
+        get_name_from_table(&STREAM_TYPES, stream_type),
+    ));
+    details.push(get_detail("Buffer Size", format!("{} bytes", buffer_size)));
+    details.push(get_detail(
+        "Maximum Bitrate",
+        format_bitrate(maximum_bitrate),
+    ));
+    details.push(get_detail(
+        "Average Bitrate",
+        format_bitrate(average_bitrate),
+    ));
+
+    let Some(decoder_specific_info) = find_descriptor(
+        &decoder_config[DECODER_CONFIG_FIELDS_LENGTH_IN_BYTES..],
+        DECODER_SPECIFIC_INFO_TAG,
+    ) else {
+        return details;
+    };
+
+    let config = get_audio_specific_config_from_bytes(decoder_specific_info);
+    details.extend(get_audio_specific_config_details(&config));
+
+    details
+}
+
+fn get_audio_specific_config_details(config: &AudioSpecificConfig) -> Vec<Detail> {
+    let mut details = vec![
+        get_detail(
+            "Audio Object Type",
+            get_object_type_name(config.object_type),
+        ),
+        get_detail("Sample Rate", format!("{} Hz", config.sample_rate)),
+        get_detail(
+            "Channel Configuration",
+            get_channel_configuration_name(config.channel_configuration),
+        ),
+        get_detail("Frame Length", format!("{} samples", config.frame_length)),
+    ];
+
+    if let Some(extension_object_type) = config.extension_object_type {
+        details.push(get_detail(
+            "Extension Object Type",
+            get_object_type_name(extension_object_type),
+        ));
+    }
+
+    if let Some(extension_sample_rate) = config.extension_sample_rate {
+        details.push(get_detail(
+            "Extension Sample Rate",
+            format!("{} Hz", extension_sample_rate),
+        ));
+    }
+
+    let signalling = match config.is_explicitly_signalled {
+        true => "Explicit",
+        false => "Implicit or None",
+    };
+
+    details.push(get_detail(
+        "SBR Present",
+        format!("{} ({})", config.is_sbr_present, signalling),
+    ));
+    details.push(get_detail(
+        "PS Present",
+        format!("{} ({})", config.is_ps_present, signalling),
+    ));
+
+    details
+}
+
+fn get_audio_specific_config_from_bytes(config_bytes: &[u8]) -> AudioSpecificConfig {
+    let mut bits = BitReader::new(config_bytes);
+
+    let mut config = AudioSpecificConfig {
+        frame_length: DEFAULT_FRAME_LENGTH,
+        ..Default::default()
+    };
+
+    config.object_type = take_object_type(&mut bits);
+    config.sample_rate = take_sample_rate(&mut bits);
+    config.channel_configuration = bits.take_bits(4) as u8;
+
+    if config.object_type == SBR_OBJECT_TYPE || config.object_type == PS_OBJECT_TYPE {
+        config.is_sbr_present = true;
+        config.is_ps_present = config.object_type == PS_OBJECT_TYPE;
+        config.is_explicitly_signalled = true;
+        config.extension_object_type = Some(SBR_OBJECT_TYPE);
+        config.extension_sample_rate = Some(take_sample_rate(&mut bits));
+        config.object_type = take_object_type(&mut bits);
+    }
+
+    if !GA_SPECIFIC_CONFIG_OBJECT_TYPES.contains(&config.object_type) {
+        return config;
+    }
+
+    if bits.take_flag() {
+        config.frame_length = SHORT_FRAME_LENGTH;
+    }
+
+    if bits.take_flag() {
+        bits.take_bits(CORE_CODER_DELAY_LENGTH_IN_BITS);
+    }
+
+    let has_extension = bits.take_flag();
+
+    if LAYER_NUMBER_OBJECT_TYPES.contains(&config.object_type) {
+        bits.take_bits(LAYER_NUMBER_LENGTH_IN_BITS);
+    }
+
+    if has_extension && EXTENSION_FLAG_OBJECT_TYPES.contains(&config.object_type) {
+        return config;
+    }
+
+    if config.is_explicitly_signalled
+        || bits.remaining_bits() < MINIMUM_SBR_EXTENSION_LENGTH_IN_BITS
+        || bits.take_bits(SYNC_EXTENSION_TYPE_LENGTH_IN_BITS) != SBR_SYNC_EXTENSION_TYPE
+    {
+        return config;
+    }
+
+    let extension_object_type = take_object_type(&mut bits);
+    config.extension_object_type = Some(extension_object_type);
+
+    if extension_object_type != SBR_OBJECT_TYPE {
+        return config;
+    }
+
+    config.is_explicitly_signalled = true;
+    config.is_sbr_present = bits.take_flag();
+
+    if !config.is_sbr_present {
+        return config;
+    }
+
+    config.extension_sample_rate = Some(take_sample_rate(&mut bits));
+
+    if bits.remaining_bits() >= MINIMUM_PS_EXTENSION_LENGTH_IN_BITS
+        && bits.take_bits(SYNC_EXTENSION_TYPE_LENGTH_IN_BITS) == PS_SYNC_EXTENSION_TYPE
+    {
+        config.is_ps_present = bits.take_flag();
+    }
+
+    config
+}
+
+fn take_object_type(bits: &mut BitReader) -> u8 {
+    match bits.take_bits(5) as u8 {
+        ESCAPE_OBJECT_TYPE => 32 + bits.take_bits(6) as u8,
+        object_type => object_type,
+    }
+}
+
+fn take_sample_rate(bits: &mut BitReader) -> u32 {
+    match bits.take_bits(4) as u8 {
+        EXPLICIT_SAMPLE_RATE_INDEX => bits.take_bits(24),
+        index => get_sample_rate_from_index(index),
+    }
+}
+
+fn find_descriptor(mut descriptors: &[u8], tag: u8) -> Option<&[u8]> {
+    while let Some((descriptor_tag, remaining)) = descriptors.split_first() {
+        let mut size: usize = 0;
+        let mut size_length = 0;
+
+        for size_byte in remaining
+            .iter()
+            .take(MAXIMUM_DESCRIPTOR_SIZE_LENGTH_IN_BYTES)
+        {
+            size = (size << 7) | (size_byte & DESCRIPTOR_SIZE_VALUE_MASK) as usize;
+            size_length += 1;
+
+            if size_byte & DESCRIPTOR_SIZE_CONTINUATION_MASK == 0 {
+                break;
+            }
+        }
+
+        let contents_end = (size_length + size).min(remaining.len());
+        let contents = remaining.get(size_length..contents_end)?;
+
+        if *descriptor_tag == tag {
+            return Some(contents);
+        }
+
+        descriptors = &remaining[contents_end..];
+    }
+
+    None
+}
+
+fn get_es_sub_descriptors(es_descriptor: &[u8]) -> Option<&[u8]> {
+    let flags = *es_descriptor.get(ES_ID_LENGTH_IN_BYTES)?;
+    let mut offset = ES_ID_LENGTH_IN_BYTES + 1;
+
+    if flags & STREAM_DEPENDENCE_FLAG_MASK != 0 {
+        offset += DEPENDS_ON_ES_ID_LENGTH_IN_BYTES;
+    }
+
+    if flags & URL_FLAG_MASK != 0 {
+        offset += 1 + *es_descriptor.get(offset)? as usize;
+    }
+
+    if flags & OCR_STREAM_FLAG_MASK != 0 {
+        offset += OCR_ES_ID_LENGTH_IN_BYTES;
+    }
+
+    es_descriptor.get(offset..)
+}
+
+fn get_name_from_table(table: &[(u8, &str)], id: u8) -> String {
+    match table.iter().find(|(table_id, _)| *table_id == id) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", id),
+    }
+}
+
+fn format_bitrate(bitrate: u32) -> String {
+    match bitrate {
+        0 => "Variable".to_string(),
+        bitrate => format!("{} kbps", bitrate as f64 / 1000.0),
+    }
+}
+
+fn get_detail(key: &str, value: String) -> Detail {
+    Detail {
+        key: key.to_string(),
+        spacer: String::new(),
+        value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_esds(audio_specific_config: &[u8]) -> Vec<u8> {
+        let mut decoder_config = vec![0x40, 0x15, 0x00, 0x18, 0x00];
+        decoder_config.extend(256000u32.to_be_bytes());
+        decoder_config.extend(128000u32.to_be_bytes());
+        decoder_config.extend([DECODER_SPECIFIC_INFO_TAG, audio_specific_config.len() as u8]);
+        decoder_config.extend(audio_specific_config);
+
+        let mut es_descriptor = vec![0x00, 0x01, 0x00];
+        es_descriptor.extend([DECODER_CONFIG_DESCRIPTOR_TAG, 0x80, 0x80, 0x80]);
+        es_descriptor.push(decoder_config.len() as u8);
+        es_descriptor.extend(decoder_config);
+        es_descriptor.extend([0x06, 0x01, 0x02]);
+
+        let mut esds = vec![0x00; FULL_ATOM_FIELDS_LENGTH_IN_BYTES];
+        esds.extend([ES_DESCRIPTOR_TAG, es_descriptor.len() as u8]);
+        esds.extend(es_descriptor);
+        esds
+    }
+
+    #[test]
+    fn return_decoder_config_and_aac_lc_details_from_valid_esds() {
+        let result = get_details(&get_test_esds(&[0x12, 0x10]));
+        assert_eq!(result[0].value, "MPEG-4 Audio (0x40)");
+        assert_eq!(result[1].value, "Audio");
+        assert_eq!(result[2].value, "6144 bytes");
+        assert_eq!(result[3].value, "256 kbps");
+        assert_eq!(result[5].value, "AAC LC (Low Complexity) (2)");
+        assert_eq!(result[6].value, "44100 Hz");
+    }
+
+    #[test]
+    fn return_sbr_and_ps_from_explicit_hierarchical_and_backward_compatible_signalling() {
+        let hierarchical = get_audio_specific_config_from_bytes(&[0xEB, 0x11, 0x88, 0x00]);
+        assert_eq!(hierarchical.object_type, 2);
+        assert_eq!(hierarchical.sample_rate, 24000);
+        assert_eq!(hierarchical.extension_sample_rate, Some(48000));
+        assert!(hierarchical.is_sbr_present && hierarchical.is_ps_present);
+
+        let backward_compatible =
+            get_audio_specific_config_from_bytes(&[0x13, 0x90, 0x56, 0xE5, 0xA0]);
+        assert_eq!(backward_compatible.sample_rate, 22050);
+        assert_eq!(backward_compatible.extension_sample_rate, Some(44100));
+        assert!(backward_compatible.is_sbr_present);
+        assert!(backward_compatible.is_explicitly_signalled);
+        assert!(!backward_compatible.is_ps_present);
+    }
+}
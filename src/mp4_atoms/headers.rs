@@ -0,0 +1,365 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_eight_bytes_as_unsigned_integer,
+    take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::mp4_atoms::{get_atom_id_from_bytes, ATOM_ID_LENGTH_IN_BYTES};
+
+const VERSION_1: u8 = 1;
+const FLAGS_LENGTH_IN_BYTES: usize = 3;
+const TRACK_ENABLED_FLAG_MASK: u32 = 0x01;
+const TRACK_HEADER_RESERVED_LENGTH_IN_BYTES: usize = 4;
+const TRACK_HEADER_FIELDS_BEFORE_VOLUME_LENGTH_IN_BYTES: usize = 12;
+const TRACK_HEADER_FIELDS_BEFORE_SIZE_LENGTH_IN_BYTES: usize = 38;
+const HANDLER_PRE_DEFINED_LENGTH_IN_BYTES: usize = 4;
+const HANDLER_RESERVED_LENGTH_IN_BYTES: usize = 12;
+const FIXED_POINT_16_16_DIVISOR: f64 = 65536.0;
+const FIXED_POINT_8_8_DIVISOR: f64 = 256.0;
+const LANGUAGE_CHARACTER_BITS: u16 = 5;
+const LANGUAGE_CHARACTER_MASK: u16 = 0x1F;
+const LANGUAGE_CHARACTER_OFFSET: u8 = 0x60;
+const MACINTOSH_LANGUAGE_CODE_LIMIT: u16 = 0x400;
+const UNSPECIFIED_MACINTOSH_LANGUAGE_CODE: u16 = 0x7FFF;
+const UNDETERMINED_LANGUAGE: &str = "und";
+
+const BRANDS: [(&str, &str); 24] = [
+    ("M4A ", "iTunes MPEG-4 Audio"),
+    ("M4B ", "iTunes Audiobook"),
+    ("M4P ", "iTunes Protected Audio"),
+    ("M4V ", "iTunes Video"),
+    ("M4VH", "iTunes HD Video"),
+    ("mp41", "MPEG-4 Version 1"),
+    ("mp42", "MPEG-4 Version 2"),
+    ("mp71", "MPEG-7 Metadata"),
+    ("isom", "ISO Base Media"),
+    ("iso2", "ISO Base Media Version 2"),
+    ("iso4", "ISO Base Media Version 4"),
+    ("iso5", "ISO Base Media Version 5"),
+    ("iso6", "ISO Base Media Version 6"),
+    ("avc1", "MPEG-4 with AVC"),
+    ("dash", "MPEG-DASH"),
+    ("qt  ", "QuickTime Movie"),
+    ("3gp4", "3GPP Release 4"),
+    ("3gp5", "3GPP Release 5"),
+    ("3gp6", "3GPP Release 6"),
+    ("3gp7", "3GPP Release 7"),
+    ("3g2a", "3GPP2"),
+    ("F4A ", "Adobe Flash Audio"),
+    ("F4V ", "Adobe Flash Video"),
+    ("caqv", "Casio Digital Camera"),
+];
+
+const HANDLER_TYPES: [(&str, &str); 11] = [
+    ("soun", "Audio"),
+    ("vide", "Video"),
+    ("hint", "Hint"),
+    ("text", "Text"),
+    ("sbtl", "Subtitle"),
+    ("subt", "Subtitle"),
+    ("clcp", "Closed Caption"),
+    ("tmcd", "Timecode"),
+    ("meta", "Timed Metadata"),
+    ("mdir", "iTunes Metadata"),
+    ("alis", "Alias Data"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Brands {
+    pub major_brand: String,
+    pub minor_version: u32,
+    pub compatible_brands: Vec<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct MovieHeader {
+    pub timescale: u32,
+    pub duration: u64,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TrackHeader {
+    pub track_id: u32,
+    pub duration: u64,
+    pub is_enabled: bool,
+    pub volume: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct MediaHeader {
+    pub timescale: u32,
+    pub duration: u64,
+    pub language: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Handler {
+    pub handler_type: String,
+    pub name: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct SampleSizes {
+    pub sample_size: u32,
+    pub sample_count: u32,
+    pub total_size: u64,
+}
+
+pub fn get_brand_name(brand: &str) -> String {
+    BRANDS
+        .iter()
+        .find(|(brand_id, _)| *brand_id == brand)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", brand))
+}
+
+pub fn get_handler_type_name(handler_type: &str) -> String {
+    HANDLER_TYPES
+        .iter()
+        .find(|(type_id, _)| *type_id == handler_type)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown ({})", handler_type))
+}
+
+pub fn get_brands_from_bytes(mut atom_data: Vec<u8>) -> Result<Brands, LocalError> {
+    let major_brand = take_first_number_of_bytes(&mut atom_data, ATOM_ID_LENGTH_IN_BYTES)?;
+    let minor_version = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let compatible_brands = atom_data
+        .chunks_exact(ATOM_ID_LENGTH_IN_BYTES)
+        .map(get_atom_id_from_bytes)
+        .collect();
+
+    Ok(Brands {
+        major_brand: get_atom_id_from_bytes(&major_brand),
+        minor_version,
+        compatible_brands,
+    })
+}
+
+pub fn get_movie_header_from_bytes(mut atom_data: Vec<u8>) -> Result<MovieHeader, LocalError> {
+    let version = take_version_and_flags(&mut atom_data)?.0;
+    skip_over_creation_and_modification_times(&mut atom_data, version)?;
+    let timescale = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let duration = take_versioned_unsigned_integer(&mut atom_data, version)?;
+
+    Ok(MovieHeader {
+        timescale,
+        duration,
+    })
+}
+
+pub fn get_track_header_from_bytes(mut atom_data: Vec<u8>) -> Result<TrackHeader, LocalError> {
+    let (version, flags) = take_version_and_flags(&mut atom_data)?;
+    skip_over_creation_and_modification_times(&mut atom_data, version)?;
+    let track_id = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    skip_over_bytes(&mut atom_data, TRACK_HEADER_RESERVED_LENGTH_IN_BYTES)?;
+    let duration = take_versioned_unsigned_integer(&mut atom_data, version)?;
+    skip_over_bytes(
+        &mut atom_data,
+        TRACK_HEADER_FIELDS_BEFORE_VOLUME_LENGTH_IN_BYTES,
+    )?;
+    let volume = take_first_two_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    skip_over_bytes(
+        &mut atom_data,
+        TRACK_HEADER_FIELDS_BEFORE_SIZE_LENGTH_IN_BYTES,
+    )?;
+    let width = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let height = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+
+    Ok(TrackHeader {
+        track_id,
+        duration,
+        is_enabled: (flags & TRACK_ENABLED_FLAG_MASK) != 0,
+        volume: volume as f64 / FIXED_POINT_8_8_DIVISOR,
+        width: width as f64 / FIXED_POINT_16_16_DIVISOR,
+        height: height as f64 / FIXED_POINT_16_16_DIVISOR,
+    })
+}
+
+pub fn get_media_header_from_bytes(mut atom_data: Vec<u8>) -> Result<MediaHeader, LocalError> {
+    let version = take_version_and_flags(&mut atom_data)?.0;
+    skip_over_creation_and_modification_times(&mut atom_data, version)?;
+    let timescale = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let duration = take_versioned_unsigned_integer(&mut atom_data, version)?;
+    let language_code = take_first_two_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+
+    Ok(MediaHeader {
+        timescale,
+        duration,
+        language: get_language_from_code(language_code),
+    })
+}
+
+pub fn get_handler_from_bytes(mut atom_data: Vec<u8>) -> Result<Handler, LocalError> {
+    take_version_and_flags(&mut atom_data)?;
+    skip_over_bytes(&mut atom_data, HANDLER_PRE_DEFINED_LENGTH_IN_BYTES)?;
+    let handler_type = take_first_number_of_bytes(&mut atom_data, ATOM_ID_LENGTH_IN_BYTES)?;
+    skip_over_bytes(&mut atom_data, HANDLER_RESERVED_LENGTH_IN_BYTES)?;
+
+    Ok(Handler {
+        handler_type: get_atom_id_from_bytes(&handler_type),
+        name: get_handler_name_from_bytes(&atom_data),
+    })
+}
+
+pub fn get_sample_sizes_from_bytes(mut atom_data: Vec<u8>) -> Result<SampleSizes, LocalError> {
+    take_version_and_flags(&mut atom_data)?;
+    let sample_size = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+    let sample_count = take_first_four_bytes_as_unsigned_integer(&mut atom_data, Endian::Big)?;
+
+    let total_size = match sample_size {
+        0 => atom_data
+            .chunks_exact(4)
+            .take(sample_count as usize)
+            .map(|size| u32::from_be_bytes([size[0], size[1], size[2], size[3]]) as u64)
+            .sum(),
+        _ => sample_size as u64 * sample_count as u64,
+    };
+
+    Ok(SampleSizes {
+        sample_size,
+        sample_count,
+        total_size,
+    })
+}
+
+fn take_version_and_flags(atom_data: &mut Vec<u8>) -> Result<(u8, u32), LocalError> {
+    let version = take_first_byte(atom_data)?;
+    let flags = take_first_number_of_bytes(atom_data, FLAGS_LENGTH_IN_BYTES)?
+        .iter()
+        .fold(0, |flags, byte| (flags << 8) | *byte as u32);
+
+    Ok((version, flags))
+}
+
+fn take_versioned_unsigned_integer(
+    atom_data: &mut Vec<u8>,
+    version: u8,
+) -> Result<u64, LocalError> {
+    match version {
+        VERSION_1 => take_first_eight_bytes_as_unsigned_integer(atom_data, Endian::Big),
+        _ => Ok(take_first_four_bytes_as_unsigned_integer(atom_data, Endian::Big)? as u64),
+    }
+}
+
+fn skip_over_creation_and_modification_times(
+    atom_data: &mut Vec<u8>,
+    version: u8,
+) -> Result<(), LocalError> {
+    take_versioned_unsigned_integer(atom_data, version)?;
+    take_versioned_unsigned_integer(atom_data, version)?;
+
+    Ok(())
+}
+
+fn get_language_from_code(language_code: u16) -> String {
+    if language_code == 0 || language_code == UNSPECIFIED_MACINTOSH_LANGUAGE_CODE {
+        return UNDETERMINED_LANGUAGE.to_string();
+    }
+
+    if language_code < MACINTOSH_LANGUAGE_CODE_LIMIT {
+        return format!("Macintosh Language {}", language_code);
+    }
+
+    (0..3)
+        .rev()
+        .map(|index| {
+            let character =
+                (language_code >> (index * LANGUAGE_CHARACTER_BITS)) & LANGUAGE_CHARACTER_MASK;
+            (character as u8 + LANGUAGE_CHARACTER_OFFSET) as char
+        })
+        .collect()
+}
+
+fn get_handler_name_from_bytes(name_bytes: &[u8]) -> String {
+    let is_pascal_string = name_bytes
+        .first()
+        .is_some_and(|length| *length as usize + 1 == name_bytes.len());
+
+    let name_bytes = match is_pascal_string {
+        true => &name_bytes[1..],
+        false => name_bytes,
+    };
+
+    String::from_utf8_lossy(name_bytes)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_media_header_from_version_0_and_version_1_bytes() {
+        let mut version_0 = vec![0x00; 12];
+        version_0.extend(44100u32.to_be_bytes());
+        version_0.extend(88200u32.to_be_bytes());
+        version_0.extend(0x15C7u16.to_be_bytes());
+        version_0.extend([0x00, 0x00]);
+
+        let mut version_1 = vec![0x01, 0x00, 0x00, 0x00];
+        version_1.extend([0x00; 16]);
+        version_1.extend(48000u32.to_be_bytes());
+        version_1.extend(96000u64.to_be_bytes());
+        version_1.extend(0x7FFFu16.to_be_bytes());
+
+        let result = get_media_header_from_bytes(version_0).unwrap();
+        assert_eq!(result.duration, 88200);
+        assert_eq!(result.language, "eng");
+
+        let result = get_media_header_from_bytes(version_1).unwrap();
+        assert_eq!(result.timescale, 48000);
+        assert_eq!(result.duration, 96000);
+        assert_eq!(result.language, "und");
+    }
+
+    #[test]
+    fn return_total_size_from_fixed_and_per_sample_sizes() {
+        let mut fixed_sizes = vec![0x00; 4];
+        fixed_sizes.extend(512u32.to_be_bytes());
+        fixed_sizes.extend(10u32.to_be_bytes());
+
+        let mut per_sample_sizes = vec![0x00; 4];
+        per_sample_sizes.extend(0u32.to_be_bytes());
+        per_sample_sizes.extend(3u32.to_be_bytes());
+        per_sample_sizes.extend(
+            [100u32, 200, 300]
+                .iter()
+                .flat_map(|size| size.to_be_bytes()),
+        );
+
+        let result = get_sample_sizes_from_bytes(fixed_sizes).unwrap();
+        assert_eq!(result.sample_count, 10);
+        assert_eq!(result.total_size, 5120);
+
+        let result = get_sample_sizes_from_bytes(per_sample_sizes).unwrap();
+        assert_eq!(result.sample_count, 3);
+        assert_eq!(result.total_size, 600);
+    }
+
+    #[test]
+    fn return_correct_handler_name_from_iso_and_quicktime_bytes() {
+        let mut iso_handler = vec![0x00; 8];
+        iso_handler.extend(b"soun");
+        iso_handler.extend([0x00; 12]);
+        iso_handler.extend(b"SoundHandler\0");
+
+        let mut quicktime_handler = vec![0x00; 4];
+        quicktime_handler.extend(b"mhlr");
+        quicktime_handler.extend(b"vide");
+        quicktime_handler.extend([0x00; 12]);
+        quicktime_handler.extend(b"\x0CVideoHandler");
+
+        let result = get_handler_from_bytes(iso_handler).unwrap();
+        assert_eq!(result.handler_type, "soun");
+        assert_eq!(result.name, "SoundHandler");
+
+        let result = get_handler_from_bytes(quicktime_handler).unwrap();
+        assert_eq!(result.handler_type, "vide");
+        assert_eq!(result.name, "VideoHandler");
+    }
+}
@@ -0,0 +1,350 @@
+use crate::formating::{
+    format_bytes_as_string_of_bytes, set_key_value_pair_spacers, KeyValuePair as Item,
+};
+use crate::mp4_atoms::Mp4Atom;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::id3v1::format_genre;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp4_atoms/ilst.tmpl");
+pub const FREEFORM_ATOM_ID: &str = "----";
+const DATA_ATOM_ID: &str = "data";
+const MEAN_ATOM_ID: &str = "mean";
+const NAME_ATOM_ID: &str = "name";
+const TRACK_NUMBER_ATOM_ID: &str = "trkn";
+const DISC_NUMBER_ATOM_ID: &str = "disk";
+const GENRE_ATOM_ID: &str = "gnre";
+const FULL_ATOM_FIELDS_LENGTH_IN_BYTES: usize = 4;
+const DATA_TYPE_FIELDS_LENGTH_IN_BYTES: usize = 8;
+const NUMBER_OF_BINARY_BYTES_TO_DISPLAY: usize = 16;
+const ITEM_NUMBER_OFFSET: usize = 2;
+const ITEM_TOTAL_OFFSET: usize = 4;
+
+const DATA_TYPE_BINARY: u32 = 0;
+const DATA_TYPE_UTF8: u32 = 1;
+const DATA_TYPE_UTF16: u32 = 2;
+const DATA_TYPE_UTF8_SORT: u32 = 4;
+const DATA_TYPE_UTF16_SORT: u32 = 5;
+const DATA_TYPE_SIGNED_INTEGER: u32 = 21;
+const DATA_TYPE_UNSIGNED_INTEGER: u32 = 22;
+const DATA_TYPE_FLOAT32: u32 = 23;
+const DATA_TYPE_FLOAT64: u32 = 24;
+const SIZED_SIGNED_INTEGER_DATA_TYPES: [u32; 4] = [65, 66, 67, 74];
+const SIZED_UNSIGNED_INTEGER_DATA_TYPES: [u32; 4] = [75, 76, 77, 78];
+
+const DATA_TYPE_NAMES: [(u32, &str); 22] = [
+    (0, "Binary"),
+    (1, "UTF-8"),
+    (2, "UTF-16"),
+    (3, "S/JIS"),
+    (4, "UTF-8 Sort"),
+    (5, "UTF-16 Sort"),
+    (13, "JPEG"),
+    (14, "PNG"),
+    (21, "Signed Integer"),
+    (22, "Unsigned Integer"),
+    (23, "Float32"),
+    (24, "Float64"),
+    (27, "BMP"),
+    (28, "QuickTime Metadata"),
+    (65, "8-bit Signed Integer"),
+    (66, "16-bit Signed Integer"),
+    (67, "32-bit Signed Integer"),
+    (74, "64-bit Signed Integer"),
+    (75, "8-bit Unsigned Integer"),
+    (76, "16-bit Unsigned Integer"),
+    (77, "32-bit Unsigned Integer"),
+    (78, "64-bit Unsigned Integer"),
+];
+
+const ITEM_NAMES: [(&str, &str); 38] = [
+    ("©nam", "Title"),
+    ("©ART", "Artist"),
+    ("aART", "Album Artist"),
+    ("©alb", "Album"),
+    ("©grp", "Grouping"),
+    ("©wrt", "Composer"),
+    ("©day", "Year"),
+    ("©gen", "Genre"),
+    ("gnre", "Genre"),
+    ("trkn", "Track Number"),
+    ("disk", "Disc Number"),
+    ("tmpo", "BPM"),
+    ("cpil", "Compilation"),
+    ("pgap", "Gapless Playback"),
+    ("©cmt", "Comment"),
+    ("©lyr", "Lyrics"),
+    ("©too", "Encoder"),
+    ("©enc", "Encoded By"),
+    ("cprt", "Copyright"),
+    ("covr", "Cover Art"),
+    ("desc", "Description"),
+    ("ldes", "Long Description"),
+    ("©wrk", "Work"),
+    ("©mvn", "Movement"),
+    ("©nrt", "Narrator"),
+    ("©pub", "Publisher"),
+    ("stik", "Media Kind"),
+    ("rtng", "Rating"),
+    ("tvsh", "TV Show"),
+    ("tves", "TV Episode"),
+    ("tvsn", "TV Season"),
+    ("sonm", "Title Sort"),
+    ("soar", "Artist Sort"),
+    ("soaa", "Album Artist Sort"),
+    ("soal", "Album Sort"),
+    ("soco", "Composer Sort"),
+    ("purd", "Purchase Date"),
+    ("xid ", "Content ID"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct IlstItem {
+    pub id: String,
+    pub mean: String,
+    pub name: String,
+    pub data_type: u32,
+    pub value: Vec<u8>,
+}
+
+pub fn get_items_from_atom(ilst_atom: &Mp4Atom) -> Vec<IlstItem> {
+    let mut items: Vec<IlstItem> = vec![];
+
+    for item_atom in &ilst_atom.children {
+        let mean = get_child_string(item_atom, MEAN_ATOM_ID);
+        let name = get_child_string(item_atom, NAME_ATOM_ID);
+
+        for data_atom in item_atom
+            .children
+            .iter()
+            .filter(|atom| atom.id == DATA_ATOM_ID)
+        {
+            let Some(type_bytes) = data_atom.data.get(..FULL_ATOM_FIELDS_LENGTH_IN_BYTES) else {
+                continue;
+            };
+
+            items.push(IlstItem {
+                id: item_atom.id.clone(),
+                mean: mean.clone(),
+                name: name.clone(),
+                data_type: u32::from_be_bytes([0, type_bytes[1], type_bytes[2], type_bytes[3]]),
+                value: data_atom
+                    .data
+                    .get(DATA_TYPE_FIELDS_LENGTH_IN_BYTES..)
+                    .unwrap_or_default()
+                    .to_vec(),
+            });
+        }
+    }
+
+    items
+}
+
+pub fn get_metadata(items: &[IlstItem]) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut formatted_items: Vec<Item> = items
+        .iter()
+        .map(|item| Item {
+            key: get_item_name(item),
+            spacer: String::new(),
+            value: format!(
+                "{} [{}]",
+                format_item_value(item),
+                get_data_type_name(item.data_type)
+            ),
+        })
+        .collect();
+    set_key_value_pair_spacers(&mut formatted_items);
+
+    let output_values: Value = upon::value! {
+        items: formatted_items,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+pub fn get_item_name(item: &IlstItem) -> String {
+    if item.id == FREEFORM_ATOM_ID {
+        return format!("{}:{}", item.mean, item.name);
+    }
+
+    match ITEM_NAMES.iter().find(|(id, _)| *id == item.id) {
+        Some((_, name)) => name.to_string(),
+        None => item.id.clone(),
+    }
+}
+
+pub fn get_data_type_name(data_type: u32) -> String {
+    match DATA_TYPE_NAMES.iter().find(|(id, _)| *id == data_type) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", data_type),
+    }
+}
+
+pub fn format_item_value(item: &IlstItem) -> String {
+    match (item.id.as_str(), item.data_type) {
+        (TRACK_NUMBER_ATOM_ID | DISC_NUMBER_ATOM_ID, DATA_TYPE_BINARY) => {
+            format_number_and_total(&item.value)
+        }
+        (GENRE_ATOM_ID, _) => match get_unsigned_integer_from_bytes(&item.value) {
+            genre_number @ 1..=256 => format_genre(genre_number as u8 - 1),
+            genre_number => format!("Unknown ({})", genre_number),
+        },
+        (_, DATA_TYPE_UTF8 | DATA_TYPE_UTF8_SORT) => {
+            String::from_utf8_lossy(&item.value).trim().to_string()
+        }
+        (_, DATA_TYPE_UTF16 | DATA_TYPE_UTF16_SORT) => {
+            let code_units: Vec<u16> = item
+                .value
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+            String::from_utf16_lossy(&code_units).trim().to_string()
+        }
+        (_, DATA_TYPE_SIGNED_INTEGER) => get_signed_integer_from_bytes(&item.value).to_string(),
+        (_, data_type) if SIZED_SIGNED_INTEGER_DATA_TYPES.contains(&data_type) => {
+            get_signed_integer_from_bytes(&item.value).to_string()
+        }
+        (_, DATA_TYPE_UNSIGNED_INTEGER) => get_unsigned_integer_from_bytes(&item.value).to_string(),
+        (_, data_type) if SIZED_UNSIGNED_INTEGER_DATA_TYPES.contains(&data_type) => {
+            get_unsigned_integer_from_bytes(&item.value).to_string()
+        }
+        (_, DATA_TYPE_FLOAT32) if item.value.len() == 4 => {
+            f32::from_be_bytes([item.value[0], item.value[1], item.value[2], item.value[3]])
+                .to_string()
+        }
+        (_, DATA_TYPE_FLOAT64) if item.value.len() == 8 => {
+            let mut float_bytes: [u8; 8] = Default::default();
+            float_bytes.copy_from_slice(&item.value);
+            f64::from_be_bytes(float_bytes).to_string()
+        }
+        _ => format_binary_summary(&item.value),
+    }
+}
+
+fn get_child_string(item_atom: &Mp4Atom, child_id: &str) -> String {
+    item_atom
+        .children
+        .iter()
+        .find(|atom| atom.id == child_id)
+        .and_then(|atom| atom.data.get(FULL_ATOM_FIELDS_LENGTH_IN_BYTES..))
+        .map(|string_bytes| String::from_utf8_lossy(string_bytes).to_string())
+        .unwrap_or_default()
+}
+
+fn format_number_and_total(value: &[u8]) -> String {
+    let get_u16_at = |offset: usize| {
+        value
+            .get(offset..offset + 2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+            .unwrap_or_default()
+    };
+
+    match get_u16_at(ITEM_TOTAL_OFFSET) {
+        0 => get_u16_at(ITEM_NUMBER_OFFSET).to_string(),
+        total => format!("{}/{}", get_u16_at(ITEM_NUMBER_OFFSET), total),
+    }
+}
+
+fn get_unsigned_integer_from_bytes(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .take(8)
+        .fold(0, |value, byte| (value << 8) | *byte as u64)
+}
+
+fn get_signed_integer_from_bytes(bytes: &[u8]) -> i64 {
+    let length = bytes.len().min(8);
+    if length == 0 {
+        return 0;
+    }
+
+    let unused_bits = (8 - length) * 8;
+    ((get_unsigned_integer_from_bytes(bytes) << unused_bits) as i64) >> unused_bits
+}
+
+fn format_binary_summary(bytes: &[u8]) -> String {
+    let displayed_bytes = &bytes[..bytes.len().min(NUMBER_OF_BINARY_BYTES_TO_DISPLAY)];
+    let ellipsis = match bytes.len() > NUMBER_OF_BINARY_BYTES_TO_DISPLAY {
+        true => " ...",
+        false => "",
+    };
+
+    format!(
+        "{} bytes: {}{}",
+        bytes.len(),
+        format_bytes_as_string_of_bytes(displayed_bytes),
+        ellipsis
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_atom(id: &str, data: Vec<u8>, children: Vec<Mp4Atom>) -> Mp4Atom {
+        Mp4Atom {
+            id: id.to_string(),
+            data,
+            children,
+            ..Default::default()
+        }
+    }
+
+    fn get_test_data_atom(data_type: u32, value: &[u8]) -> Mp4Atom {
+        let mut data = data_type.to_be_bytes().to_vec();
+        data.extend([0x00; 4]);
+        data.extend(value);
+        get_test_atom(DATA_ATOM_ID, data, vec![])
+    }
+
+    #[test]
+    fn return_freeform_items_with_mean_name_and_data_type() {
+        let freeform = get_test_atom(
+            FREEFORM_ATOM_ID,
+            vec![],
+            vec![
+                get_test_atom(MEAN_ATOM_ID, b"\0\0\0\0com.apple.iTunes".to_vec(), vec![]),
+                get_test_atom(NAME_ATOM_ID, b"\0\0\0\0MOOD".to_vec(), vec![]),
+                get_test_data_atom(DATA_TYPE_UTF8, b"Happy"),
+            ],
+        );
+        let ilst = get_test_atom("ilst", vec![], vec![freeform]);
+
+        let result = get_items_from_atom(&ilst);
+        assert_eq!(result.len(), 1);
+        assert_eq!(get_item_name(&result[0]), "com.apple.iTunes:MOOD");
+        assert_eq!(format_item_value(&result[0]), "Happy");
+        assert_eq!(get_data_type_name(result[0].data_type), "UTF-8");
+    }
+
+    #[test]
+    fn return_correctly_formatted_values_for_binary_and_integer_items() {
+        let track = IlstItem {
+            id: TRACK_NUMBER_ATOM_ID.to_string(),
+            value: vec![0x00, 0x00, 0x00, 0x03, 0x00, 0x0C, 0x00, 0x00],
+            ..Default::default()
+        };
+        let bpm = IlstItem {
+            id: "tmpo".to_string(),
+            data_type: DATA_TYPE_SIGNED_INTEGER,
+            value: vec![0xFF, 0xFE],
+            ..Default::default()
+        };
+        let genre = IlstItem {
+            id: GENRE_ATOM_ID.to_string(),
+            value: vec![0x00, 0x12],
+            ..Default::default()
+        };
+
+        assert_eq!(format_item_value(&track), "3/12");
+        assert_eq!(format_item_value(&bpm), "-2");
+        assert_eq!(format_item_value(&genre), "Rock (17)");
+    }
+}
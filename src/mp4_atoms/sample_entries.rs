@@ -0,0 +1,478 @@
+use crate::blocks::get_metadata_from_block_bytes;
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_eight_bytes_as_float, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as Detail};
+use crate::mp4_atoms::{
+    alac, dac3, dops, esds, Mp4Atom, CHANNEL_LAYOUT_ATOM_ID, FULL_ATOM_FIELDS_LENGTH_IN_BYTES,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const AUDIO_SAMPLE_ENTRY_TEMPLATE_CONTENT: &str =
+    include_str!("../templates/mp4_atoms/audio_sample_entry.tmpl");
+
+const AUDIO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES: usize = 28;
+const AUDIO_SAMPLE_ENTRY_VERSION_1_FIELDS_LENGTH_IN_BYTES: usize = 16;
+const AUDIO_SAMPLE_ENTRY_VERSION_2_FIELDS_LENGTH_IN_BYTES: usize = 36;
+const VIDEO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES: usize = 78;
+const SAMPLE_ENTRY_RESERVED_LENGTH_IN_BYTES: usize = 6;
+const DATA_REFERENCE_INDEX_LENGTH_IN_BYTES: usize = 2;
+const AUDIO_SAMPLE_ENTRY_VERSION_OFFSET: usize = 8;
+const AUDIO_SAMPLE_ENTRY_VENDOR_LENGTH_IN_BYTES: usize = 6;
+const AUDIO_SAMPLE_ENTRY_COMPRESSION_FIELDS_LENGTH_IN_BYTES: usize = 4;
+const AUDIO_SAMPLE_ENTRY_VERSION_2_STRUCT_SIZE_LENGTH_IN_BYTES: usize = 4;
+const AUDIO_SAMPLE_ENTRY_VERSION_2_ALWAYS_7F000000_LENGTH_IN_BYTES: usize = 4;
+const AUDIO_SAMPLE_ENTRY_VERSION_2_FORMAT_FLAGS_LENGTH_IN_BYTES: usize = 4;
+const AUDIO_SAMPLE_ENTRY_VERSION_1_PACKET_FIELDS_LENGTH_IN_BYTES: usize = 8;
+const VIDEO_SAMPLE_ENTRY_FIELDS_BEFORE_SIZE_LENGTH_IN_BYTES: usize = 16;
+const VIDEO_SAMPLE_ENTRY_FIELDS_BEFORE_COMPRESSOR_NAME_LENGTH_IN_BYTES: usize = 14;
+const COMPRESSOR_NAME_LENGTH_IN_BYTES: usize = 32;
+const SOUND_DESCRIPTION_VERSION_1: u16 = 1;
+const SOUND_DESCRIPTION_VERSION_2: u16 = 2;
+const FIXED_POINT_16_16_DIVISOR: f64 = 65536.0;
+const QUICKTIME_DECOMPRESSION_PARAMETERS_ATOM_ID: &str = "wave";
+const FLAC_METADATA_BLOCK_HEADER_LENGTH_IN_BYTES: usize = 4;
+const FLAC_LAST_METADATA_BLOCK_FLAG: u8 = 0x80;
+
+const CODEC_CONFIGURATION_ATOMS: [(&str, &str); 6] = [
+    ("esds", "Elementary Stream Descriptor"),
+    ("alac", "ALAC Specific Config"),
+    ("dOps", "Opus Specific Box"),
+    ("dfLa", "FLAC Specific Box"),
+    (dac3::AC3_SPECIFIC_BOX_ID, "AC-3 Specific Box"),
+    (dac3::EAC3_SPECIFIC_BOX_ID, "E-AC-3 Specific Box"),
+];
+
+const AUDIO_SAMPLE_ENTRIES: [(&str, &str); 27] = [
+    ("mp4a", "MPEG-4 Audio"),
+    ("alac", "Apple Lossless"),
+    ("Opus", "Opus"),
+    ("fLaC", "FLAC"),
+    ("ac-3", "AC-3"),
+    ("ec-3", "E-AC-3"),
+    ("ac-4", "AC-4"),
+    ("dtsc", "DTS"),
+    ("dtsh", "DTS-HD"),
+    ("dtsl", "DTS-HD Lossless"),
+    ("dtse", "DTS Express"),
+    ("mha1", "MPEG-H 3D Audio"),
+    ("samr", "AMR Narrowband"),
+    ("sawb", "AMR Wideband"),
+    ("lpcm", "Linear PCM"),
+    ("sowt", "Little Endian PCM"),
+    ("twos", "Big Endian PCM"),
+    ("in24", "24-bit Integer PCM"),
+    ("in32", "32-bit Integer PCM"),
+    ("fl32", "32-bit Float PCM"),
+    ("fl64", "64-bit Float PCM"),
+    ("ulaw", "µ-law"),
+    ("alaw", "A-law"),
+    ("ima4", "IMA ADPCM"),
+    (".mp3", "MPEG-1 Layer 3"),
+    ("raw ", "Uncompressed PCM"),
+    ("enca", "Encrypted Audio"),
+];
+
+const UNCOMPRESSED_AUDIO_SAMPLE_ENTRY_IDS: [&str; 8] = [
+    "lpcm", "sowt", "twos", "in24", "in32", "fl32", "fl64", "raw ",
+];
+
+const VIDEO_SAMPLE_ENTRIES: [(&str, &str); 19] = [
+    ("avc1", "H.264/AVC"),
+    ("avc3", "H.264/AVC"),
+    ("hvc1", "H.265/HEVC"),
+    ("hev1", "H.265/HEVC"),
+    ("dvh1", "Dolby Vision HEVC"),
+    ("dvhe", "Dolby Vision HEVC"),
+    ("mp4v", "MPEG-4 Visual"),
+    ("av01", "AV1"),
+    ("vp08", "VP8"),
+    ("vp09", "VP9"),
+    ("s263", "H.263"),
+    ("jpeg", "Photo JPEG"),
+    ("mjpa", "Motion JPEG A"),
+    ("mjpb", "Motion JPEG B"),
+    ("apch", "Apple ProRes 422 HQ"),
+    ("apcn", "Apple ProRes 422"),
+    ("apcs", "Apple ProRes 422 LT"),
+    ("apco", "Apple ProRes 422 Proxy"),
+    ("ap4h", "Apple ProRes 4444"),
+];
+
+const OTHER_SAMPLE_ENTRIES: [(&str, &str); 8] = [
+    ("text", "QuickTime Text"),
+    ("tx3g", "3GPP Timed Text"),
+    ("wvtt", "WebVTT"),
+    ("stpp", "TTML Subtitles"),
+    ("c608", "CEA-608 Captions"),
+    ("tmcd", "Timecode"),
+    ("mp4s", "MPEG-4 Systems"),
+    ("rtp ", "RTP Hint"),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct AudioSampleEntry {
+    pub version: u16,
+    pub channels: u32,
+    pub sample_size: u32,
+    pub sample_rate: f64,
+    pub bytes_per_frame: u32,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct VideoSampleEntry {
+    pub width: u16,
+    pub height: u16,
+    pub compressor_name: String,
+}
+
+pub fn is_audio_sample_entry(id: &str) -> bool {
+    AUDIO_SAMPLE_ENTRIES
+        .iter()
+        .any(|(entry_id, _)| *entry_id == id)
+}
+
+pub fn is_uncompressed_audio_sample_entry(id: &str) -> bool {
+    UNCOMPRESSED_AUDIO_SAMPLE_ENTRY_IDS.contains(&id)
+}
+
+pub fn is_video_sample_entry(id: &str) -> bool {
+    VIDEO_SAMPLE_ENTRIES
+        .iter()
+        .any(|(entry_id, _)| *entry_id == id)
+}
+
+pub fn get_codec_name(id: &str) -> String {
+    find_codec_name(id)
+        .map(|name| name.to_string())
+        .unwrap_or(format!("Unknown ({})", id))
+}
+
+pub fn format_codec_name(id: &str) -> String {
+    match find_codec_name(id) {
+        Some(name) => format!("{} ({})", name, id),
+        None => format!("Unknown ({})", id),
+    }
+}
+
+fn find_codec_name(id: &str) -> Option<&'static str> {
+    AUDIO_SAMPLE_ENTRIES
+        .iter()
+        .chain(VIDEO_SAMPLE_ENTRIES.iter())
+        .chain(OTHER_SAMPLE_ENTRIES.iter())
+        .find(|(entry_id, _)| *entry_id == id)
+        .map(|(_, name)| *name)
+}
+
+pub fn get_metadata(
+    sample_entry: &Mp4Atom,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let audio_sample_entry = get_audio_sample_entry_from_bytes(sample_entry.data.clone())?;
+    let config_atom = find_codec_configuration_atom(&sample_entry.children);
+
+    let mut config_name = String::new();
+    let mut details: Vec<Detail> = vec![];
+    let mut flac_blocks: Vec<Vec<u8>> = vec![];
+
+    if let Some(config_atom) = config_atom {
+        config_name = get_codec_configuration_name(&config_atom.id);
+
+        match config_atom.id.as_str() {
+            "esds" => details = esds::get_details(&config_atom.data),
+            "alac" => {
+                let channel_layout_atom =
+                    find_child_atom(&sample_entry.children, CHANNEL_LAYOUT_ATOM_ID);
+                details = alac::get_details(
+                    config_atom.data.clone(),
+                    channel_layout_atom.map(|atom| atom.data.as_slice()),
+                )?;
+            }
+            "dOps" => details = dops::get_details(config_atom.data.clone())?,
+            "dfLa" => flac_blocks = get_flac_blocks_from_bytes(&config_atom.data),
+            id => details = dac3::get_details(id, &config_atom.data),
+        }
+    }
+
+    set_key_value_pair_spacers(&mut details);
+
+    let output_values: Value = upon::value! {
+        codec: format_codec_name(&sample_entry.id),
+        channels: audio_sample_entry.channels,
+        sample_size: audio_sample_entry.sample_size,
+        sample_rate: audio_sample_entry.sample_rate,
+        config_name: config_name,
+        details: details,
+    };
+
+    let formated_output =
+        get_file_chunk_output(AUDIO_SAMPLE_ENTRY_TEMPLATE_CONTENT, output_values)?;
+
+    let mut output = vec![OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    }];
+
+    if !flac_blocks.is_empty() {
+        output.extend(get_metadata_from_block_bytes(
+            flac_blocks,
+            mandatory_sections_only,
+        )?);
+    }
+
+    Ok(output)
+}
+
+fn find_codec_configuration_atom(atoms: &[Mp4Atom]) -> Option<&Mp4Atom> {
+    atoms.iter().find_map(|atom| match atom.id.as_str() {
+        QUICKTIME_DECOMPRESSION_PARAMETERS_ATOM_ID => find_codec_configuration_atom(&atom.children),
+        id if CODEC_CONFIGURATION_ATOMS
+            .iter()
+            .any(|(config_id, _)| *config_id == id) =>
+        {
+            Some(atom)
+        }
+        _ => None,
+    })
+}
+
+fn find_child_atom<'a>(atoms: &'a [Mp4Atom], id: &str) -> Option<&'a Mp4Atom> {
+    atoms.iter().find_map(|atom| match atom.id.as_str() {
+        QUICKTIME_DECOMPRESSION_PARAMETERS_ATOM_ID => find_child_atom(&atom.children, id),
+        atom_id if atom_id == id => Some(atom),
+        _ => None,
+    })
+}
+
+fn get_codec_configuration_name(id: &str) -> String {
+    CODEC_CONFIGURATION_ATOMS
+        .iter()
+        .find(|(config_id, _)| *config_id == id)
+        .map(|(_, name)| format!("{} ({})", name, id))
+        .unwrap_or(id.to_string())
+}
+
+fn get_flac_blocks_from_bytes(atom_data: &[u8]) -> Vec<Vec<u8>> {
+    let mut blocks: Vec<Vec<u8>> = vec![];
+    let mut remaining = atom_data
+        .get(FULL_ATOM_FIELDS_LENGTH_IN_BYTES..)
+        .unwrap_or_default();
+
+    while remaining.len() >= FLAC_METADATA_BLOCK_HEADER_LENGTH_IN_BYTES {
+        let block_length =
+            u32::from_be_bytes([0, remaining[1], remaining[2], remaining[3]]) as usize;
+        let block_end =
+            (FLAC_METADATA_BLOCK_HEADER_LENGTH_IN_BYTES + block_length).min(remaining.len());
+        let is_last_block = remaining[0] & FLAC_LAST_METADATA_BLOCK_FLAG != 0;
+
+        blocks.push(remaining[..block_end].to_vec());
+        remaining = &remaining[block_end..];
+
+        if is_last_block {
+            break;
+        }
+    }
+
+    blocks
+}
+
+pub fn get_sample_entry_fields_length(id: &str, entry_data: &[u8]) -> Option<usize> {
+    if is_video_sample_entry(id) {
+        return Some(VIDEO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES);
+    }
+
+    if !is_audio_sample_entry(id) {
+        return None;
+    }
+
+    let version_bytes =
+        entry_data.get(AUDIO_SAMPLE_ENTRY_VERSION_OFFSET..AUDIO_SAMPLE_ENTRY_VERSION_OFFSET + 2)?;
+
+    match u16::from_be_bytes([version_bytes[0], version_bytes[1]]) {
+        SOUND_DESCRIPTION_VERSION_1 => Some(
+            AUDIO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES
+                + AUDIO_SAMPLE_ENTRY_VERSION_1_FIELDS_LENGTH_IN_BYTES,
+        ),
+        SOUND_DESCRIPTION_VERSION_2 => Some(
+            AUDIO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES
+                + AUDIO_SAMPLE_ENTRY_VERSION_2_FIELDS_LENGTH_IN_BYTES,
+        ),
+        _ => Some(AUDIO_SAMPLE_ENTRY_FIELDS_LENGTH_IN_BYTES),
+    }
+}
+
+pub fn get_audio_sample_entry_from_bytes(
+    mut entry_data: Vec<u8>,
+) -> Result<AudioSampleEntry, LocalError> {
+    skip_over_bytes(&mut entry_data, AUDIO_SAMPLE_ENTRY_VERSION_OFFSET)?;
+    let version = take_first_two_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    skip_over_bytes(&mut entry_data, AUDIO_SAMPLE_ENTRY_VENDOR_LENGTH_IN_BYTES)?;
+    let channels = take_first_two_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    let sample_size = take_first_two_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    skip_over_bytes(
+        &mut entry_data,
+        AUDIO_SAMPLE_ENTRY_COMPRESSION_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let sample_rate = take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+
+    let mut audio_sample_entry = AudioSampleEntry {
+        version,
+        channels: channels as u32,
+        sample_size: sample_size as u32,
+        sample_rate: sample_rate as f64 / FIXED_POINT_16_16_DIVISOR,
+        bytes_per_frame: 0,
+    };
+
+    if version == SOUND_DESCRIPTION_VERSION_1 {
+        skip_over_bytes(
+            &mut entry_data,
+            AUDIO_SAMPLE_ENTRY_VERSION_1_PACKET_FIELDS_LENGTH_IN_BYTES,
+        )?;
+        audio_sample_entry.bytes_per_frame =
+            take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    }
+
+    if version == SOUND_DESCRIPTION_VERSION_2 {
+        skip_over_bytes(
+            &mut entry_data,
+            AUDIO_SAMPLE_ENTRY_VERSION_2_STRUCT_SIZE_LENGTH_IN_BYTES,
+        )?;
+        audio_sample_entry.sample_rate =
+            take_first_eight_bytes_as_float(&mut entry_data, Endian::Big)?;
+        audio_sample_entry.channels =
+            take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+        skip_over_bytes(
+            &mut entry_data,
+            AUDIO_SAMPLE_ENTRY_VERSION_2_ALWAYS_7F000000_LENGTH_IN_BYTES,
+        )?;
+        audio_sample_entry.sample_size =
+            take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+        skip_over_bytes(
+            &mut entry_data,
+            AUDIO_SAMPLE_ENTRY_VERSION_2_FORMAT_FLAGS_LENGTH_IN_BYTES,
+        )?;
+        let bytes_per_packet =
+            take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+        let frames_per_packet =
+            take_first_four_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+        audio_sample_entry.bytes_per_frame = bytes_per_packet / frames_per_packet.max(1);
+    }
+
+    if audio_sample_entry.bytes_per_frame == 0 {
+        audio_sample_entry.bytes_per_frame =
+            audio_sample_entry.channels * audio_sample_entry.sample_size / 8;
+    }
+
+    Ok(audio_sample_entry)
+}
+
+pub fn get_video_sample_entry_from_bytes(
+    mut entry_data: Vec<u8>,
+) -> Result<VideoSampleEntry, LocalError> {
+    skip_over_bytes(
+        &mut entry_data,
+        SAMPLE_ENTRY_RESERVED_LENGTH_IN_BYTES
+            + DATA_REFERENCE_INDEX_LENGTH_IN_BYTES
+            + VIDEO_SAMPLE_ENTRY_FIELDS_BEFORE_SIZE_LENGTH_IN_BYTES,
+    )?;
+    let width = take_first_two_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    let height = take_first_two_bytes_as_unsigned_integer(&mut entry_data, Endian::Big)?;
+    skip_over_bytes(
+        &mut entry_data,
+        VIDEO_SAMPLE_ENTRY_FIELDS_BEFORE_COMPRESSOR_NAME_LENGTH_IN_BYTES,
+    )?;
+    let compressor_name_bytes =
+        take_first_number_of_bytes(&mut entry_data, COMPRESSOR_NAME_LENGTH_IN_BYTES)?;
+    let compressor_name_length =
+        (compressor_name_bytes[0] as usize).min(COMPRESSOR_NAME_LENGTH_IN_BYTES - 1);
+
+    Ok(VideoSampleEntry {
+        width,
+        height,
+        compressor_name: String::from_utf8_lossy(
+            &compressor_name_bytes[1..=compressor_name_length],
+        )
+        .to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_audio_sample_entry(version: u16) -> Vec<u8> {
+        let mut entry_data = vec![0x00; 6];
+        entry_data.extend(1u16.to_be_bytes());
+        entry_data.extend(version.to_be_bytes());
+        entry_data.extend([0x00; 6]);
+        entry_data.extend(2u16.to_be_bytes());
+        entry_data.extend(16u16.to_be_bytes());
+        entry_data.extend([0x00; 4]);
+        entry_data.extend((44100u32 << 16).to_be_bytes());
+        entry_data
+    }
+
+    #[test]
+    fn return_correct_fields_length_for_each_sound_description_version() {
+        assert_eq!(
+            get_sample_entry_fields_length("mp4a", &get_test_audio_sample_entry(0)),
+            Some(28)
+        );
+        assert_eq!(
+            get_sample_entry_fields_length("sowt", &get_test_audio_sample_entry(1)),
+            Some(44)
+        );
+        assert_eq!(get_sample_entry_fields_length("avc1", &[]), Some(78));
+        assert_eq!(get_sample_entry_fields_length("tx3g", &[]), None);
+    }
+
+    #[test]
+    fn return_correct_audio_sample_entry_from_version_0_and_version_2_bytes() {
+        let result = get_audio_sample_entry_from_bytes(get_test_audio_sample_entry(0)).unwrap();
+        assert_eq!(result.channels, 2);
+        assert_eq!(result.sample_size, 16);
+        assert_eq!(result.sample_rate, 44100.0);
+
+        let mut version_2 = get_test_audio_sample_entry(2);
+        version_2.extend(72u32.to_be_bytes());
+        version_2.extend(96000.0f64.to_be_bytes());
+        version_2.extend(6u32.to_be_bytes());
+        version_2.extend(0x7F000000u32.to_be_bytes());
+        version_2.extend(24u32.to_be_bytes());
+        version_2.extend([0x00; 12]);
+
+        let result = get_audio_sample_entry_from_bytes(version_2).unwrap();
+        assert_eq!(result.channels, 6);
+        assert_eq!(result.sample_size, 24);
+        assert_eq!(result.sample_rate, 96000.0);
+        assert_eq!(result.bytes_per_frame, 18);
+    }
+
+    #[test]
+    fn return_bytes_per_frame_from_each_sound_description_version() {
+        let result = get_audio_sample_entry_from_bytes(get_test_audio_sample_entry(0)).unwrap();
+        assert_eq!(result.bytes_per_frame, 4);
+
+        let mut version_1 = get_test_audio_sample_entry(1);
+        version_1.extend([1, 2, 6, 2].map(u32::to_be_bytes).concat());
+
+        let result = get_audio_sample_entry_from_bytes(version_1).unwrap();
+        assert_eq!(result.bytes_per_frame, 6);
+
+        let mut version_2 = get_test_audio_sample_entry(2);
+        version_2.extend(72u32.to_be_bytes());
+        version_2.extend(48000.0f64.to_be_bytes());
+        version_2.extend(2u32.to_be_bytes());
+        version_2.extend(0x7F000000u32.to_be_bytes());
+        version_2.extend(32u32.to_be_bytes());
+        version_2.extend([0, 8, 1].map(u32::to_be_bytes).concat());
+
+        let result = get_audio_sample_entry_from_bytes(version_2).unwrap();
+        assert_eq!(result.bytes_per_frame, 8);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::mp4_atoms::headers::{
+    get_brands_from_bytes, get_handler_from_bytes, get_media_header_from_bytes,
+    get_movie_header_from_bytes, get_track_header_from_bytes,
+};
+use crate::mp4_atoms::ilst::{get_data_type_name, get_item_name, IlstItem, FREEFORM_ATOM_ID};
+use crate::mp4_atoms::sample_entries::{
+    get_audio_sample_entry_from_bytes, get_codec_name, get_video_sample_entry_from_bytes,
+    is_audio_sample_entry, is_video_sample_entry,
+};
+use crate::mp4_atoms::{format_duration, Mp4Atom, ILST_ATOM_ID, STSD_ATOM_ID};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+use uuid::Uuid;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/mp4_atoms/tree.tmpl");
+const INDENT_PER_LEVEL: &str = "  ";
+const OFFSET_COLUMN_WIDTH: usize = 12;
+const UUID_LENGTH_IN_BYTES: usize = 16;
+const DATA_TYPE_LENGTH_IN_BYTES: usize = 4;
+const STSD_ENTRY_COUNT_OFFSET: usize = 4;
+const FULL_ATOM_FIELDS_LENGTH_IN_BYTES: usize = 4;
+
+#[derive(Debug, Serialize)]
+struct TreeLine {
+    offset: String,
+    indent: String,
+    id: String,
+    size: String,
+    summary: String,
+}
+
+pub fn get_metadata(atoms: &[Mp4Atom]) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut lines: Vec<TreeLine> = vec![];
+    add_tree_lines(atoms, 0, "", &mut lines);
+
+    let output_values: Value = upon::value! {
+        atoms: lines,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn add_tree_lines(atoms: &[Mp4Atom], depth: usize, parent_id: &str, lines: &mut Vec<TreeLine>) {
+    for atom in atoms {
+        lines.push(TreeLine {
+            offset: format!("{:<width$}", atom.offset, width = OFFSET_COLUMN_WIDTH),
+            indent: INDENT_PER_LEVEL.repeat(depth),
+            id: atom.id.clone(),
+            size: atom.size.to_string(),
+            summary: get_atom_summary(atom, parent_id).unwrap_or_default(),
+        });
+
+        add_tree_lines(&atom.children, depth + 1, &atom.id, lines);
+    }
+}
+
+fn get_atom_summary(atom: &Mp4Atom, parent_id: &str) -> Option<String> {
+    let data = atom.data.clone();
+
+    let summary = match atom.id.as_str() {
+        "ftyp" => {
+            let brands = get_brands_from_bytes(data).ok()?;
+            format!(
+                "Major Brand: {}, Minor Version: {}, Compatible Brands: {}",
+                brands.major_brand,
+                brands.minor_version,
+                brands.compatible_brands.join(", ")
+            )
+        }
+        "mvhd" => {
+            let movie_header = get_movie_header_from_bytes(data).ok()?;
+            format!(
+                "Timescale: {}, Duration: {}",
+                movie_header.timescale,
+                format_duration(movie_header.duration, movie_header.timescale)
+            )
+        }
+        "tkhd" => {
+            let track_header = get_track_header_from_bytes(data).ok()?;
+            format!(
+                "Track ID: {}, Enabled: {}, Size: {} x {}",
+                track_header.track_id,
+                track_header.is_enabled,
+                track_header.width,
+                track_header.height
+            )
+        }
+        "mdhd" => {
+            let media_header = get_media_header_from_bytes(data).ok()?;
+            format!(
+                "Timescale: {}, Duration: {}, Language: {}",
+                media_header.timescale,
+                format_duration(media_header.duration, media_header.timescale),
+                media_header.language
+            )
+        }
+        "hdlr" => {
+            let handler = get_handler_from_bytes(data).ok()?;
+            match handler.name.is_empty() {
+                true => format!("Handler: {}", handler.handler_type),
+                false => format!("Handler: {}, Name: {}", handler.handler_type, handler.name),
+            }
+        }
+        STSD_ATOM_ID => {
+            let count_bytes = data.get(STSD_ENTRY_COUNT_OFFSET..STSD_ENTRY_COUNT_OFFSET + 4)?;
+            format!(
+                "Entries: {}",
+                u32::from_be_bytes([
+                    count_bytes[0],
+                    count_bytes[1],
+                    count_bytes[2],
+                    count_bytes[3]
+                ])
+            )
+        }
+        "uuid" => {
+            let uuid = Uuid::from_slice(data.get(..UUID_LENGTH_IN_BYTES)?).ok()?;
+            format!("UUID: {}", uuid.hyphenated().to_string().to_uppercase())
+        }
+        "mean" | "name" if parent_id == FREEFORM_ATOM_ID => {
+            String::from_utf8_lossy(data.get(FULL_ATOM_FIELDS_LENGTH_IN_BYTES..)?).to_string()
+        }
+        "data" => {
+            let type_bytes = data.get(..DATA_TYPE_LENGTH_IN_BYTES)?;
+            format!(
+                "Type: {}",
+                get_data_type_name(u32::from_be_bytes([
+                    0,
+                    type_bytes[1],
+                    type_bytes[2],
+                    type_bytes[3]
+                ]))
+            )
+        }
+        id if parent_id == STSD_ATOM_ID && is_audio_sample_entry(id) => {
+            let audio_sample_entry = get_audio_sample_entry_from_bytes(data).ok()?;
+            format!(
+                "{}, Channels: {}, Sample Size: {}, Sample Rate: {}",
+                get_codec_name(id),
+                audio_sample_entry.channels,
+                audio_sample_entry.sample_size,
+                audio_sample_entry.sample_rate
+            )
+        }
+        id if parent_id == STSD_ATOM_ID && is_video_sample_entry(id) => {
+            let video_sample_entry = get_video_sample_entry_from_bytes(data).ok()?;
+            format!(
+                "{}, Size: {} x {}",
+                get_codec_name(id),
+                video_sample_entry.width,
+                video_sample_entry.height
+            )
+        }
+        id if parent_id == ILST_ATOM_ID && id != FREEFORM_ATOM_ID => get_item_name(&IlstItem {
+            id: id.to_string(),
+            ..Default::default()
+        }),
+        _ => return None,
+    };
+
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_tree_lines_with_indented_children_and_summaries() {
+        let mut ftyp_data = b"M4A ".to_vec();
+        ftyp_data.extend(512u32.to_be_bytes());
+        ftyp_data.extend(b"M4A isom");
+
+        let atoms = vec![
+            Mp4Atom {
+                id: "ftyp".to_string(),
+                size: 24,
+                data: ftyp_data,
+                ..Default::default()
+            },
+            Mp4Atom {
+                id: "moov".to_string(),
+                offset: 24,
+                size: 16,
+                children: vec![Mp4Atom {
+                    id: "free".to_string(),
+                    offset: 32,
+                    size: 8,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        ];
+
+        let result = get_metadata(&atoms).unwrap();
+        assert!(result.text.contains(
+            "0           ftyp (24 bytes)  Major Brand: M4A , Minor Version: 512, Compatible Brands: M4A , isom"
+        ));
+        assert!(result.text.contains("32            free (8 bytes)"));
+    }
+
+    #[test]
+    fn return_tree_line_when_atom_size_exceeds_the_signed_integer_range() {
+        let atoms = vec![Mp4Atom {
+            id: "mdat".to_string(),
+            size: u64::MAX,
+            ..Default::default()
+        }];
+
+        let result = get_metadata(&atoms).unwrap();
+        assert!(result.text.contains(&format!("mdat ({} bytes)", u64::MAX)));
+    }
+}
@@ -0,0 +1,287 @@
+mod flac;
+pub mod opus;
+mod skeleton;
+mod speex;
+mod theora;
+mod unknown;
+mod vorbis;
+
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as UserComment};
+use crate::output::OutputEntry;
+use std::error::Error;
+
+const VORBIS_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"\x01vorbis";
+const OPUS_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"OpusHead";
+const FLAC_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"\x7FFLAC";
+const SPEEX_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"Speex   ";
+const THEORA_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"\x80theora";
+const SKELETON_IDENTIFICATION_HEADER_SIGNATURE: &[u8] = b"fishead\x00";
+const VORBIS_NUMBER_OF_METADATA_PACKETS: usize = 2;
+const OPUS_NUMBER_OF_METADATA_PACKETS: usize = 2;
+const SPEEX_NUMBER_OF_METADATA_PACKETS: usize = 2;
+const THEORA_NUMBER_OF_METADATA_PACKETS: usize = 2;
+const SKELETON_NUMBER_OF_METADATA_PACKETS: usize = 1;
+const UNKNOWN_CODEC_NUMBER_OF_METADATA_PACKETS: usize = 1;
+const VORBIS_CODEC_NAME: &str = "Vorbis";
+const OPUS_CODEC_NAME: &str = "Opus";
+const FLAC_CODEC_NAME: &str = "FLAC";
+const SPEEX_CODEC_NAME: &str = "Speex";
+const THEORA_CODEC_NAME: &str = "Theora";
+const SKELETON_CODEC_NAME: &str = "Skeleton";
+const UNKNOWN_CODEC_NAME: &str = "Unknown";
+const BAD_USER_COMMENT_KEY: &str = "XXXX";
+const BAD_USER_COMMENT_VALUE: &str =
+    "[Corrupt or Non-Standard Format User Comment. Halting processing comments.]";
+
+#[derive(Debug, PartialEq)]
+pub enum OggCodec {
+    Vorbis,
+    Opus,
+    Flac,
+    Speex,
+    Theora,
+    Skeleton,
+    Unknown,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CommentHeader {
+    pub vendor_comment: String,
+    pub user_comments: Vec<UserComment>,
+}
+
+pub fn get_codec_from_identification_packet(identification_packet: &[u8]) -> OggCodec {
+    if identification_packet.starts_with(VORBIS_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Vorbis;
+    }
+
+    if identification_packet.starts_with(OPUS_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Opus;
+    }
+
+    if identification_packet.starts_with(FLAC_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Flac;
+    }
+
+    if identification_packet.starts_with(SPEEX_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Speex;
+    }
+
+    if identification_packet.starts_with(THEORA_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Theora;
+    }
+
+    if identification_packet.starts_with(SKELETON_IDENTIFICATION_HEADER_SIGNATURE) {
+        return OggCodec::Skeleton;
+    }
+
+    OggCodec::Unknown
+}
+
+pub fn get_codec_name(codec: &OggCodec) -> String {
+    match codec {
+        OggCodec::Vorbis => VORBIS_CODEC_NAME.to_string(),
+        OggCodec::Opus => OPUS_CODEC_NAME.to_string(),
+        OggCodec::Flac => FLAC_CODEC_NAME.to_string(),
+        OggCodec::Speex => SPEEX_CODEC_NAME.to_string(),
+        OggCodec::Theora => THEORA_CODEC_NAME.to_string(),
+        OggCodec::Skeleton => SKELETON_CODEC_NAME.to_string(),
+        OggCodec::Unknown => UNKNOWN_CODEC_NAME.to_string(),
+    }
+}
+
+pub fn has_all_metadata_packets(codec: &OggCodec, packets: &[Vec<u8>]) -> bool {
+    match codec {
+        OggCodec::Vorbis => packets.len() >= VORBIS_NUMBER_OF_METADATA_PACKETS,
+        OggCodec::Opus => packets.len() >= OPUS_NUMBER_OF_METADATA_PACKETS,
+        OggCodec::Flac => flac::has_all_metadata_packets(packets),
+        OggCodec::Speex => packets.len() >= SPEEX_NUMBER_OF_METADATA_PACKETS,
+        OggCodec::Theora => packets.len() >= THEORA_NUMBER_OF_METADATA_PACKETS,
+        OggCodec::Skeleton => packets.len() >= SKELETON_NUMBER_OF_METADATA_PACKETS,
+        OggCodec::Unknown => packets.len() >= UNKNOWN_CODEC_NUMBER_OF_METADATA_PACKETS,
+    }
+}
+
+pub fn get_metadata_from_packets(
+    codec: OggCodec,
+    packets: Vec<Vec<u8>>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let result = match codec {
+        OggCodec::Vorbis => {
+            let (identification_packet, comment_packet) =
+                get_identification_and_comment_packets(packets);
+            vec![vorbis::get_metadata(identification_packet, comment_packet)?]
+        }
+        OggCodec::Opus => {
+            let (identification_packet, comment_packet) =
+                get_identification_and_comment_packets(packets);
+            vec![opus::get_metadata(identification_packet, comment_packet)?]
+        }
+        OggCodec::Flac => flac::get_metadata(packets, mandatory_sections_only)?,
+        OggCodec::Speex => {
+            let (identification_packet, comment_packet) =
+                get_identification_and_comment_packets(packets);
+            vec![speex::get_metadata(identification_packet, comment_packet)?]
+        }
+        OggCodec::Theora => {
+            let (identification_packet, comment_packet) =
+                get_identification_and_comment_packets(packets);
+            vec![theora::get_metadata(identification_packet, comment_packet)?]
+        }
+        OggCodec::Skeleton => {
+            let (identification_packet, _) = get_identification_and_comment_packets(packets);
+            vec![skeleton::get_metadata(identification_packet)?]
+        }
+        OggCodec::Unknown => {
+            let (identification_packet, _) = get_identification_and_comment_packets(packets);
+            vec![unknown::get_metadata(identification_packet)?]
+        }
+    };
+
+    Ok(result)
+}
+
+pub fn get_comment_header_from_packet(
+    mut comment_packet: Vec<u8>,
+    signature_length_in_bytes: usize,
+) -> Result<CommentHeader, Box<dyn Error>> {
+    skip_over_bytes(&mut comment_packet, signature_length_in_bytes)?;
+
+    let vendor_comment_length =
+        take_first_four_bytes_as_unsigned_integer(&mut comment_packet, Endian::Little)?;
+    let vendor_comment = String::from_utf8(take_first_number_of_bytes(
+        &mut comment_packet,
+        vendor_comment_length as usize,
+    )?)?;
+
+    let number_of_user_comments =
+        take_first_four_bytes_as_unsigned_integer(&mut comment_packet, Endian::Little)?;
+
+    let mut user_comments: Vec<UserComment> = vec![];
+    for _ in 0..number_of_user_comments {
+        match get_user_comment_from_packet(&mut comment_packet) {
+            Ok(comment) => user_comments.push(comment),
+            Err(_) => {
+                user_comments.push(UserComment {
+                    key: BAD_USER_COMMENT_KEY.to_string(),
+                    spacer: " ".to_string(),
+                    value: BAD_USER_COMMENT_VALUE.to_string(),
+                });
+                break;
+            }
+        }
+    }
+
+    set_key_value_pair_spacers(&mut user_comments);
+
+    Ok(CommentHeader {
+        vendor_comment,
+        user_comments,
+    })
+}
+
+fn get_identification_and_comment_packets(packets: Vec<Vec<u8>>) -> (Vec<u8>, Vec<u8>) {
+    let mut packets = packets.into_iter();
+    let identification_packet = packets.next().unwrap_or_default();
+    let comment_packet = packets.next().unwrap_or_default();
+
+    (identification_packet, comment_packet)
+}
+
+fn get_user_comment_from_packet(
+    comment_packet: &mut Vec<u8>,
+) -> Result<UserComment, Box<dyn Error>> {
+    let user_comment_length =
+        take_first_four_bytes_as_unsigned_integer(comment_packet, Endian::Little)?;
+    let user_comment = String::from_utf8(take_first_number_of_bytes(
+        comment_packet,
+        user_comment_length as usize,
+    )?)?;
+    let user_comment_key_value = user_comment
+        .split_once("=")
+        .ok_or(LocalError::InvalidVorbisComment)?;
+
+    Ok(UserComment {
+        key: user_comment_key_value.0.to_string(),
+        spacer: " ".to_string(),
+        value: user_comment_key_value.1.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_comment_packet(comments: &[&str]) -> Vec<u8> {
+        let vendor = "test vendor";
+        let mut packet: Vec<u8> = b"OpusTags".to_vec();
+        packet.extend((vendor.len() as u32).to_le_bytes());
+        packet.extend(vendor.as_bytes());
+        packet.extend((comments.len() as u32).to_le_bytes());
+        for comment in comments {
+            packet.extend((comment.len() as u32).to_le_bytes());
+            packet.extend(comment.as_bytes());
+        }
+        packet
+    }
+
+    #[test]
+    fn return_correct_codec_from_identification_packet_signatures() {
+        assert_eq!(
+            get_codec_from_identification_packet(b"\x01vorbis\x00\x00\x00\x00"),
+            OggCodec::Vorbis
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"OpusHead\x01\x02"),
+            OggCodec::Opus
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"\x7FFLAC\x01\x00"),
+            OggCodec::Flac
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"Speex   1.2.1"),
+            OggCodec::Speex
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"\x80theora"),
+            OggCodec::Theora
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"fishead\x00\x03\x00"),
+            OggCodec::Skeleton
+        );
+        assert_eq!(
+            get_codec_from_identification_packet(b"\x80kate\x00\x00\x00"),
+            OggCodec::Unknown
+        );
+    }
+
+    #[test]
+    fn return_correct_comment_header_from_valid_comment_packet() {
+        let test_packet = get_test_comment_packet(&["TITLE=Song", "ARTIST=Band"]);
+        let result = get_comment_header_from_packet(test_packet, 8).unwrap();
+
+        assert_eq!(result.vendor_comment, "test vendor");
+        assert_eq!(result.user_comments.len(), 2);
+        assert_eq!(result.user_comments[0].key, "TITLE");
+        assert_eq!(result.user_comments[0].value, "Song");
+        assert_eq!(result.user_comments[1].key, "ARTIST");
+        assert_eq!(result.user_comments[1].value, "Band");
+    }
+
+    #[test]
+    fn return_bad_user_comment_and_stop_when_a_comment_has_no_equals_sign() {
+        let test_packet = get_test_comment_packet(&["TITLE=Song", "NOEQUALS", "ARTIST=Band"]);
+        let result = get_comment_header_from_packet(test_packet, 8).unwrap();
+
+        assert_eq!(result.user_comments.len(), 2);
+        assert_eq!(result.user_comments[1].key, BAD_USER_COMMENT_KEY);
+        assert_eq!(result.user_comments[1].value, BAD_USER_COMMENT_VALUE);
+    }
+}
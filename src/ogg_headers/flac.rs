@@ -0,0 +1,137 @@
+use crate::blocks::get_metadata_from_block_bytes;
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/flac.tmpl");
+const FLAC_MAPPING_SIGNATURE_LENGTH_IN_BYTES: usize = 5;
+const FLAC_NATIVE_SIGNATURE_LENGTH_IN_BYTES: usize = 4;
+const STREAM_INFO_BLOCK_HEADER_BYTE_OFFSET: usize = 13;
+const UNKNOWN_NUMBER_OF_HEADER_PACKETS: u16 = 0;
+const UNKNOWN_NUMBER_OF_HEADER_PACKETS_OUTPUT_STRING: &str = "Unknown";
+
+pub fn get_metadata(
+    mut packets: Vec<Vec<u8>>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut identification_packet = packets.remove(0);
+
+    skip_over_bytes(
+        &mut identification_packet,
+        FLAC_MAPPING_SIGNATURE_LENGTH_IN_BYTES,
+    )?;
+    let major_version = take_first_byte(&mut identification_packet)?;
+    let minor_version = take_first_byte(&mut identification_packet)?;
+    let number_of_header_packets =
+        take_first_two_bytes_as_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    skip_over_bytes(
+        &mut identification_packet,
+        FLAC_NATIVE_SIGNATURE_LENGTH_IN_BYTES,
+    )?;
+
+    let output_values: Value = upon::value! {
+        major_version: major_version,
+        minor_version: minor_version,
+        number_of_header_packets: format_number_of_header_packets(number_of_header_packets),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    let mut output = vec![OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    }];
+
+    if number_of_header_packets != UNKNOWN_NUMBER_OF_HEADER_PACKETS {
+        packets.truncate(number_of_header_packets as usize);
+    }
+
+    let mut blocks = vec![identification_packet];
+    blocks.extend(packets);
+
+    output.extend(get_metadata_from_block_bytes(
+        blocks,
+        mandatory_sections_only,
+    )?);
+
+    Ok(output)
+}
+
+pub fn has_all_metadata_packets(packets: &[Vec<u8>]) -> bool {
+    let Some(identification_packet) = packets.first() else {
+        return false;
+    };
+
+    if identification_packet.len() <= STREAM_INFO_BLOCK_HEADER_BYTE_OFFSET {
+        return true;
+    }
+
+    let number_of_header_packets = u16::from_be_bytes([
+        identification_packet[FLAC_MAPPING_SIGNATURE_LENGTH_IN_BYTES + 2],
+        identification_packet[FLAC_MAPPING_SIGNATURE_LENGTH_IN_BYTES + 3],
+    ]);
+
+    if number_of_header_packets != UNKNOWN_NUMBER_OF_HEADER_PACKETS {
+        return packets.len() > number_of_header_packets as usize;
+    }
+
+    packets
+        .iter()
+        .enumerate()
+        .any(|(index, packet)| match index {
+            0 => is_last_block(packet[STREAM_INFO_BLOCK_HEADER_BYTE_OFFSET]),
+            _ => packet
+                .first()
+                .is_some_and(|header_byte| is_last_block(*header_byte)),
+        })
+}
+
+fn is_last_block(header_byte: u8) -> bool {
+    (header_byte >> 7) == 1
+}
+
+fn format_number_of_header_packets(number_of_header_packets: u16) -> String {
+    match number_of_header_packets {
+        UNKNOWN_NUMBER_OF_HEADER_PACKETS => {
+            UNKNOWN_NUMBER_OF_HEADER_PACKETS_OUTPUT_STRING.to_string()
+        }
+        _ => number_of_header_packets.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_identification_packet(number_of_header_packets: u16, is_last: bool) -> Vec<u8> {
+        let mut packet: Vec<u8> = b"\x7FFLAC\x01\x00".to_vec();
+        packet.extend(number_of_header_packets.to_be_bytes());
+        packet.extend(b"fLaC");
+        packet.push(if is_last { 0x80 } else { 0x00 });
+        packet.extend([0x00, 0x00, 0x22]);
+        packet.extend([0; 34]);
+        packet
+    }
+
+    #[test]
+    fn return_true_when_all_counted_header_packets_are_present() {
+        let packets = vec![get_test_identification_packet(1, false), vec![0x84]];
+        assert!(has_all_metadata_packets(&packets));
+        assert!(!has_all_metadata_packets(&packets[..1]));
+    }
+
+    #[test]
+    fn return_true_when_header_packet_count_is_unknown_and_the_last_block_is_found() {
+        let packets = vec![
+            get_test_identification_packet(0, false),
+            vec![0x04],
+            vec![0x81],
+        ];
+        assert!(has_all_metadata_packets(&packets));
+        assert!(!has_all_metadata_packets(&packets[..2]));
+    }
+}
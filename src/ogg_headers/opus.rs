@@ -0,0 +1,168 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_number_of_bytes, take_first_two_bytes_as_signed_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::ogg_headers::get_comment_header_from_packet;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/opus.tmpl");
+const OPUS_SIGNATURE_LENGTH_IN_BYTES: usize = 8;
+const OUTPUT_GAIN_Q8_DIVISOR: f64 = 256.0;
+const RTP_MAPPING_FAMILY: u8 = 0;
+const CHANNEL_MAPPING_FAMILY_NAMES: [(u8, &str); 5] = [
+    (0, "Mono or Stereo (RTP)"),
+    (1, "Vorbis Channel Order"),
+    (2, "Ambisonics"),
+    (3, "Ambisonics with Demixing Matrix"),
+    (255, "Undefined"),
+];
+const UNKNOWN_CHANNEL_MAPPING_FAMILY: &str = "Unknown";
+
+#[derive(Debug, Default, PartialEq)]
+struct IdentificationHeader {
+    version: u8,
+    channels: u8,
+    pre_skip: u16,
+    input_sample_rate: u32,
+    output_gain: String,
+    channel_mapping_family: u8,
+    stream_count: u8,
+    coupled_stream_count: u8,
+    channel_mapping: String,
+}
+
+pub fn get_metadata(
+    identification_packet: Vec<u8>,
+    comment_packet: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let identification_header = get_identification_header_from_packet(identification_packet)?;
+    let comment_header =
+        get_comment_header_from_packet(comment_packet, OPUS_SIGNATURE_LENGTH_IN_BYTES)?;
+
+    let output_values: Value = upon::value! {
+        version: identification_header.version,
+        channels: identification_header.channels,
+        pre_skip: identification_header.pre_skip,
+        input_sample_rate: identification_header.input_sample_rate as f64 / 1000.0,
+        output_gain: identification_header.output_gain,
+        channel_mapping_family: identification_header.channel_mapping_family,
+        channel_mapping_family_name: get_channel_mapping_family_name(identification_header.channel_mapping_family),
+        has_channel_mapping_table: identification_header.channel_mapping_family != RTP_MAPPING_FAMILY,
+        stream_count: identification_header.stream_count,
+        coupled_stream_count: identification_header.coupled_stream_count,
+        channel_mapping: identification_header.channel_mapping,
+        vendor_comment: comment_header.vendor_comment,
+        user_comments: comment_header.user_comments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_identification_header_from_packet(
+    mut identification_packet: Vec<u8>,
+) -> Result<IdentificationHeader, LocalError> {
+    skip_over_bytes(&mut identification_packet, OPUS_SIGNATURE_LENGTH_IN_BYTES)?;
+
+    let version = take_first_byte(&mut identification_packet)?;
+    let channels = take_first_byte(&mut identification_packet)?;
+    let pre_skip =
+        take_first_two_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let input_sample_rate =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let output_gain = format_output_gain(take_first_two_bytes_as_signed_integer(
+        &mut identification_packet,
+        Endian::Little,
+    )?);
+    let channel_mapping_family = take_first_byte(&mut identification_packet)?;
+
+    let mut identification_header = IdentificationHeader {
+        version,
+        channels,
+        pre_skip,
+        input_sample_rate,
+        output_gain,
+        channel_mapping_family,
+        ..Default::default()
+    };
+
+    if channel_mapping_family != RTP_MAPPING_FAMILY {
+        identification_header.stream_count = take_first_byte(&mut identification_packet)?;
+        identification_header.coupled_stream_count = take_first_byte(&mut identification_packet)?;
+        identification_header.channel_mapping =
+            take_first_number_of_bytes(&mut identification_packet, channels as usize)?
+                .iter()
+                .map(|channel| channel.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+    }
+
+    Ok(identification_header)
+}
+
+pub fn format_output_gain(output_gain: i16) -> String {
+    format!("{:.2}", output_gain as f64 / OUTPUT_GAIN_Q8_DIVISOR)
+}
+
+pub fn get_channel_mapping_family_name(channel_mapping_family: u8) -> String {
+    CHANNEL_MAPPING_FAMILY_NAMES
+        .iter()
+        .find(|(family, _)| *family == channel_mapping_family)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(UNKNOWN_CHANNEL_MAPPING_FAMILY.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_identification_header_from_valid_stereo_packet() {
+        let mut test_packet: Vec<u8> = b"OpusHead".to_vec();
+        test_packet.extend([
+            0x01, 0x02, 0x38, 0x01, 0x80, 0xBB, 0x00, 0x00, 0x00, 0x01, 0x00,
+        ]);
+
+        let correct_result = IdentificationHeader {
+            version: 1,
+            channels: 2,
+            pre_skip: 312,
+            input_sample_rate: 48000,
+            output_gain: "1.00".to_string(),
+            ..Default::default()
+        };
+
+        let result = get_identification_header_from_packet(test_packet).unwrap();
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_channel_mapping_table_when_mapping_family_is_not_zero() {
+        let mut test_packet: Vec<u8> = b"OpusHead".to_vec();
+        test_packet.extend([
+            0x01, 0x03, 0x00, 0x00, 0x44, 0xAC, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ]);
+        test_packet.extend([0x02, 0x01, 0x00, 0x02, 0x01]);
+
+        let result = get_identification_header_from_packet(test_packet).unwrap();
+        assert_eq!(result.channel_mapping_family, 1);
+        assert_eq!(result.stream_count, 2);
+        assert_eq!(result.coupled_stream_count, 1);
+        assert_eq!(result.channel_mapping, "0, 2, 1");
+    }
+
+    #[test]
+    fn return_correct_negative_output_gain_from_q8_value() {
+        let result = format_output_gain(-1536);
+        assert_eq!(result, "-6.00");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_eight_bytes_as_signed_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/skeleton.tmpl");
+const SKELETON_SIGNATURE_LENGTH_IN_BYTES: usize = 8;
+const UNKNOWN_VALUE_OUTPUT_STRING: &str = "Unknown";
+
+pub fn get_metadata(mut identification_packet: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes(
+        &mut identification_packet,
+        SKELETON_SIGNATURE_LENGTH_IN_BYTES,
+    )?;
+
+    let version_major =
+        take_first_two_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let version_minor =
+        take_first_two_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let presentation_time_numerator =
+        take_first_eight_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let presentation_time_denominator =
+        take_first_eight_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let base_time_numerator =
+        take_first_eight_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let base_time_denominator =
+        take_first_eight_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+
+    let output_values: Value = upon::value! {
+        version: format!("{}.{}", version_major, version_minor),
+        presentation_time: format_rational_time(presentation_time_numerator, presentation_time_denominator),
+        base_time: format_rational_time(base_time_numerator, base_time_denominator),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn format_rational_time(numerator: i64, denominator: i64) -> String {
+    if denominator == 0 {
+        return UNKNOWN_VALUE_OUTPUT_STRING.to_string();
+    }
+
+    format!("{:.3} seconds", numerator as f64 / denominator as f64)
+}
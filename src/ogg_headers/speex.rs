@@ -0,0 +1,164 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_signed_integer, take_first_number_of_bytes_as_string,
+    Endian,
+};
+use crate::errors::LocalError;
+use crate::ogg_headers::get_comment_header_from_packet;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/speex.tmpl");
+const SPEEX_SIGNATURE_LENGTH_IN_BYTES: usize = 8;
+const SPEEX_VERSION_STRING_LENGTH_IN_BYTES: usize = 20;
+const SPEEX_COMMENT_SIGNATURE_LENGTH_IN_BYTES: usize = 0;
+const SPEEX_MODES: [&str; 3] = ["Narrowband", "Wideband", "Ultra-wideband"];
+const UNKNOWN_VALUE_OUTPUT_STRING: &str = "Unknown";
+const UNKNOWN_BITRATE: i32 = -1;
+
+#[derive(Debug, Default, PartialEq)]
+struct SpeexHeader {
+    version: String,
+    version_id: i32,
+    sample_rate: i32,
+    mode: String,
+    mode_bitstream_version: i32,
+    channels: i32,
+    bitrate: String,
+    frame_size: i32,
+    is_vbr: bool,
+    frames_per_packet: i32,
+    extra_headers: i32,
+}
+
+pub fn get_metadata(
+    identification_packet: Vec<u8>,
+    comment_packet: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let speex_header = get_speex_header_from_packet(identification_packet)?;
+    let comment_header =
+        get_comment_header_from_packet(comment_packet, SPEEX_COMMENT_SIGNATURE_LENGTH_IN_BYTES)?;
+
+    let output_values: Value = upon::value! {
+        version: speex_header.version,
+        version_id: speex_header.version_id,
+        sample_rate: speex_header.sample_rate as f64 / 1000.0,
+        mode: speex_header.mode,
+        mode_bitstream_version: speex_header.mode_bitstream_version,
+        channels: speex_header.channels,
+        bitrate: speex_header.bitrate,
+        frame_size: speex_header.frame_size,
+        is_vbr: speex_header.is_vbr,
+        frames_per_packet: speex_header.frames_per_packet,
+        extra_headers: speex_header.extra_headers,
+        vendor_comment: comment_header.vendor_comment,
+        user_comments: comment_header.user_comments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_speex_header_from_packet(
+    mut identification_packet: Vec<u8>,
+) -> Result<SpeexHeader, LocalError> {
+    skip_over_bytes(&mut identification_packet, SPEEX_SIGNATURE_LENGTH_IN_BYTES)?;
+
+    let version = take_first_number_of_bytes_as_string(
+        &mut identification_packet,
+        SPEEX_VERSION_STRING_LENGTH_IN_BYTES,
+    )?;
+    let version_id =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let _header_size =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let sample_rate =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let mode = take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let mode_bitstream_version =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let channels =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let bitrate =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let frame_size =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let vbr = take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let frames_per_packet =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+    let extra_headers =
+        take_first_four_bytes_as_signed_integer(&mut identification_packet, Endian::Little)?;
+
+    Ok(SpeexHeader {
+        version,
+        version_id,
+        sample_rate,
+        mode: get_mode_name(mode),
+        mode_bitstream_version,
+        channels,
+        bitrate: format_bitrate(bitrate),
+        frame_size,
+        is_vbr: vbr != 0,
+        frames_per_packet,
+        extra_headers,
+    })
+}
+
+fn get_mode_name(mode: i32) -> String {
+    usize::try_from(mode)
+        .ok()
+        .and_then(|index| SPEEX_MODES.get(index))
+        .unwrap_or(&UNKNOWN_VALUE_OUTPUT_STRING)
+        .to_string()
+}
+
+fn format_bitrate(bitrate: i32) -> String {
+    match bitrate {
+        UNKNOWN_BITRATE => UNKNOWN_VALUE_OUTPUT_STRING.to_string(),
+        _ => format!("{} kbit/s", bitrate as f64 / 1000.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_speex_header_from_valid_wideband_packet() {
+        let mut test_packet: Vec<u8> = b"Speex   ".to_vec();
+        let mut version = b"1.2.1".to_vec();
+        version.resize(20, 0);
+        test_packet.extend(version);
+        for field in [1i32, 80, 16000, 1, 4, 1, -1, 320, 1, 1, 0, 0, 0] {
+            test_packet.extend(field.to_le_bytes());
+        }
+
+        let correct_result = SpeexHeader {
+            version: "1.2.1".to_string(),
+            version_id: 1,
+            sample_rate: 16000,
+            mode: "Wideband".to_string(),
+            mode_bitstream_version: 4,
+            channels: 1,
+            bitrate: UNKNOWN_VALUE_OUTPUT_STRING.to_string(),
+            frame_size: 320,
+            is_vbr: true,
+            frames_per_packet: 1,
+            extra_headers: 0,
+        };
+
+        let result = get_speex_header_from_packet(test_packet).unwrap();
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_unknown_mode_name_from_out_of_range_mode() {
+        assert_eq!(get_mode_name(3), UNKNOWN_VALUE_OUTPUT_STRING);
+        assert_eq!(get_mode_name(-1), UNKNOWN_VALUE_OUTPUT_STRING);
+    }
+}
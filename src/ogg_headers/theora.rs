@@ -0,0 +1,93 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_three_bytes_as_32bit_unsigned_integer, take_first_two_bytes_as_unsigned_integer,
+    Endian,
+};
+use crate::ogg_headers::get_comment_header_from_packet;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/theora.tmpl");
+const THEORA_COMMON_HEADER_LENGTH_IN_BYTES: usize = 7;
+const FRAME_SIZE_IN_MACRO_BLOCKS_LENGTH_IN_BYTES: usize = 4;
+const PICTURE_OFFSETS_LENGTH_IN_BYTES: usize = 2;
+const COLOUR_SPACES: [&str; 3] = ["Undefined", "Rec. 470M", "Rec. 470BG"];
+const PIXEL_FORMATS: [&str; 4] = ["4:2:0", "Reserved", "4:2:2", "4:4:4"];
+const UNKNOWN_VALUE_OUTPUT_STRING: &str = "Unknown";
+
+pub fn get_metadata(
+    mut identification_packet: Vec<u8>,
+    comment_packet: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes(
+        &mut identification_packet,
+        THEORA_COMMON_HEADER_LENGTH_IN_BYTES,
+    )?;
+
+    let version_major = take_first_byte(&mut identification_packet)?;
+    let version_minor = take_first_byte(&mut identification_packet)?;
+    let version_revision = take_first_byte(&mut identification_packet)?;
+    skip_over_bytes(
+        &mut identification_packet,
+        FRAME_SIZE_IN_MACRO_BLOCKS_LENGTH_IN_BYTES,
+    )?;
+    let picture_width =
+        take_first_three_bytes_as_32bit_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let picture_height =
+        take_first_three_bytes_as_32bit_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    skip_over_bytes(&mut identification_packet, PICTURE_OFFSETS_LENGTH_IN_BYTES)?;
+    let frame_rate_numerator =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let frame_rate_denominator =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let aspect_ratio_numerator =
+        take_first_three_bytes_as_32bit_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let aspect_ratio_denominator =
+        take_first_three_bytes_as_32bit_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let colour_space = take_first_byte(&mut identification_packet)?;
+    let nominal_bitrate =
+        take_first_three_bytes_as_32bit_unsigned_integer(&mut identification_packet, Endian::Big)?;
+    let quality_and_pixel_format =
+        take_first_two_bytes_as_unsigned_integer(&mut identification_packet, Endian::Big)?;
+
+    let comment_header =
+        get_comment_header_from_packet(comment_packet, THEORA_COMMON_HEADER_LENGTH_IN_BYTES)?;
+
+    let output_values: Value = upon::value! {
+        version: format!("{}.{}.{}", version_major, version_minor, version_revision),
+        picture_width: picture_width,
+        picture_height: picture_height,
+        frame_rate: format_frame_rate(frame_rate_numerator, frame_rate_denominator),
+        aspect_ratio: format!("{}:{}", aspect_ratio_numerator, aspect_ratio_denominator),
+        colour_space: get_name_from_index(&COLOUR_SPACES, colour_space as usize),
+        nominal_bitrate: nominal_bitrate / 1000,
+        quality: quality_and_pixel_format >> 10,
+        pixel_format: get_name_from_index(&PIXEL_FORMATS, ((quality_and_pixel_format >> 3) & 3) as usize),
+        vendor_comment: comment_header.vendor_comment,
+        user_comments: comment_header.user_comments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn format_frame_rate(numerator: u32, denominator: u32) -> String {
+    if denominator == 0 {
+        return UNKNOWN_VALUE_OUTPUT_STRING.to_string();
+    }
+
+    format!("{:.3}", numerator as f64 / denominator as f64)
+}
+
+fn get_name_from_index(names: &[&str], index: usize) -> String {
+    names
+        .get(index)
+        .unwrap_or(&UNKNOWN_VALUE_OUTPUT_STRING)
+        .to_string()
+}
@@ -0,0 +1,24 @@
+use crate::formating::format_bytes_as_string_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/unknown.tmpl");
+const SIGNATURE_BYTES_TO_DISPLAY: usize = 8;
+
+pub fn get_metadata(identification_packet: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let signature_length = identification_packet.len().min(SIGNATURE_BYTES_TO_DISPLAY);
+
+    let output_values: Value = upon::value! {
+        signature: format_bytes_as_string_of_bytes(&identification_packet[..signature_length]),
+        packet_size: identification_packet.len(),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Unsupported,
+        text: formated_output,
+    })
+}
@@ -0,0 +1,178 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer, Endian,
+};
+use crate::ogg_headers::get_comment_header_from_packet;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use byte_unit::rust_decimal::prelude::Zero;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/ogg_headers/vorbis.tmpl");
+const VORBIS_COMMON_HEADER_LENGTH_IN_BYTES: usize = 7;
+const FIXED_BIT_RATE_OUTPUT_STRING: &str = "Fixed";
+const VBR_ABR_BIT_RATE_OUTPUT_STRING: &str = "VBR or ABR";
+const MAX_LIMITED_BIT_RATE_OUTPUT_STRING: &str = "Maximum Limited";
+const MIN_LIMITED_BIT_RATE_OUTPUT_STRING: &str = "Minimum Limited";
+const UNKNOWN_BIT_RATE_OUTPUT_STRING: &str = "Unknown";
+
+pub fn get_metadata(
+    mut identification_packet: Vec<u8>,
+    comment_packet: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes(
+        &mut identification_packet,
+        VORBIS_COMMON_HEADER_LENGTH_IN_BYTES,
+    )?;
+
+    let vorbis_version =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let audio_channels = take_first_byte(&mut identification_packet)?;
+    let audio_sample_rate =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let bitrate_maximum =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let bitrate_nominal =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let bitrate_minimum =
+        take_first_four_bytes_as_unsigned_integer(&mut identification_packet, Endian::Little)?;
+    let blocksizes: (u8, u8) =
+        get_blocksizes_from_byte(take_first_byte(&mut identification_packet)?);
+    let bitrate_type =
+        get_bitrate_type_from_bitrate_values(bitrate_minimum, bitrate_nominal, bitrate_maximum);
+
+    let comment_header =
+        get_comment_header_from_packet(comment_packet, VORBIS_COMMON_HEADER_LENGTH_IN_BYTES)?;
+
+    let output_values: Value = upon::value! {
+        vorbis_version: vorbis_version,
+        audio_channels: audio_channels,
+        audio_sample_rate: audio_sample_rate as f64 / 1000.0,
+        bitrate_maximum: bitrate_maximum/1000,
+        bitrate_nominal: bitrate_nominal/1000,
+        bitrate_minimum: bitrate_minimum/1000,
+        blocksize_0: blocksizes.0,
+        blocksize_1: blocksizes.1,
+        bitrate_type: bitrate_type,
+        vendor_comment: comment_header.vendor_comment,
+        user_comments: comment_header.user_comments,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_blocksizes_from_byte(blocksize_byte: u8) -> (u8, u8) {
+    let blocksize_1: u8 = blocksize_byte & 15;
+    let blocksize_0: u8 = blocksize_byte >> 4;
+
+    (blocksize_0, blocksize_1)
+}
+
+fn get_bitrate_type_from_bitrate_values(minimum: u32, nominal: u32, maximum: u32) -> String {
+    if maximum == nominal && nominal == minimum {
+        return FIXED_BIT_RATE_OUTPUT_STRING.to_string();
+    }
+
+    if !nominal.is_zero() && maximum.is_zero() && minimum.is_zero() {
+        return VBR_ABR_BIT_RATE_OUTPUT_STRING.to_string();
+    }
+
+    if !maximum.is_zero() && nominal.is_zero() && minimum.is_zero() {
+        return MAX_LIMITED_BIT_RATE_OUTPUT_STRING.to_string();
+    }
+
+    if !minimum.is_zero() && nominal.is_zero() && maximum.is_zero() {
+        return MIN_LIMITED_BIT_RATE_OUTPUT_STRING.to_string();
+    }
+
+    UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_correct_blocksizes_from_valid_blocksize_byte() {
+        let test_byte = 0b11010101;
+        let correct_blocksizes = (13, 5);
+        let blocksizes = get_blocksizes_from_byte(test_byte);
+
+        assert_eq!(correct_blocksizes, blocksizes);
+    }
+
+    #[test]
+    fn return_fixed_bit_rate_output_string_when_all_bitrates_are_equal() {
+        let test_minimum = 123456;
+        let test_nominal = 123456;
+        let test_maximum = 123456;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(output_string, FIXED_BIT_RATE_OUTPUT_STRING.to_string());
+    }
+
+    #[test]
+    fn return_variable_bit_rate_output_string_when_min_and_max_are_zero_but_nominal_is_not_zero() {
+        let test_minimum = 0;
+        let test_nominal = 123456;
+        let test_maximum = 0;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(output_string, VBR_ABR_BIT_RATE_OUTPUT_STRING.to_string());
+    }
+
+    #[test]
+    fn return_max_limited_bit_rate_output_string_when_min_and_nominal_are_zero_but_max_is_not_zero()
+    {
+        let test_minimum = 0;
+        let test_nominal = 0;
+        let test_maximum = 123456;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(
+            output_string,
+            MAX_LIMITED_BIT_RATE_OUTPUT_STRING.to_string()
+        );
+    }
+
+    #[test]
+    fn return_min_limited_bit_rate_output_string_when_max_and_nominal_are_zero_but_min_is_not_zero()
+    {
+        let test_minimum = 123456;
+        let test_nominal = 0;
+        let test_maximum = 0;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(
+            output_string,
+            MIN_LIMITED_BIT_RATE_OUTPUT_STRING.to_string()
+        );
+    }
+
+    #[test]
+    fn return_unknown_bit_rate_output_string_when_max_and_nominal_bitrates_are_set_and_min_is_zero()
+    {
+        let test_minimum = 0;
+        let test_nominal = 123456;
+        let test_maximum = 123456;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(output_string, UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string());
+    }
+
+    #[test]
+    fn return_unknown_bit_rate_output_string_when_min_and_nominal_bitrates_are_set_and_max_is_zero()
+    {
+        let test_minimum = 123456;
+        let test_nominal = 123456;
+        let test_maximum = 0;
+        let output_string =
+            get_bitrate_type_from_bitrate_values(test_minimum, test_nominal, test_maximum);
+        assert_eq!(output_string, UNKNOWN_BIT_RATE_OUTPUT_STRING.to_string());
+    }
+}
@@ -0,0 +1,83 @@
+mod envelope;
+mod vhdr;
+
+use crate::byte_arrays::Endian;
+use crate::chunks::{extra, skipped, text};
+use crate::fileio::{
+    read_bytes_from_file, read_chunk_id_from_file, read_chunk_size_from_file,
+    skip_over_bytes_in_file,
+};
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+
+const VOICE_HEADER_CHUNK_ID: &str = "vhdr";
+const NAME_CHUNK_ID: &str = "name";
+const NAME_TEMPLATE_TITLE: &str = "Name (NAME)";
+const AUTHOR_CHUNK_ID: &str = "auth";
+const AUTHOR_TEMPLATE_TITLE: &str = "Author (AUTH)";
+const ANNOTATION_CHUNK_ID: &str = "anno";
+const ANNOTATION_TEMPLATE_TITLE: &str = "Annotation (ANNO)";
+const COPYRIGHT_CHUNK_ID: &str = "(c) ";
+const COPYRIGHT_TEMPLATE_TITLE: &str = "Copyright ((c) )";
+const ATTACK_CHUNK_ID: &str = "atak";
+const ATTACK_TEMPLATE_TITLE: &str = "Attack Envelope (ATAK)";
+const RELEASE_CHUNK_ID: &str = "rlse";
+const RELEASE_TEMPLATE_TITLE: &str = "Release Envelope (RLSE)";
+const BODY_CHUNK_ID: &str = "body";
+const MANDATORY_CHUNKS: [&str; 1] = [VOICE_HEADER_CHUNK_ID];
+
+pub fn get_metadata_from_svx_chunks(
+    svx_file: &mut File,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output: Vec<OutputEntry> = vec![];
+
+    loop {
+        let chunk_id = read_chunk_id_from_file(svx_file)?;
+
+        if chunk_id.is_empty() {
+            break;
+        }
+
+        let chunk_size = read_chunk_size_from_file(svx_file, Endian::Big)?;
+
+        if mandatory_sections_only && !MANDATORY_CHUNKS.contains(&chunk_id.as_str()) {
+            skip_over_bytes_in_file(svx_file, chunk_size)?;
+            continue;
+        }
+
+        if chunk_id == BODY_CHUNK_ID {
+            skip_over_bytes_in_file(svx_file, chunk_size)?;
+            output.push(skipped::get_metadata(chunk_id)?);
+            continue;
+        }
+
+        let chunk_data = read_bytes_from_file(svx_file, chunk_size)?;
+        output.push(get_svx_chunk_metadata(chunk_id, chunk_data)?);
+    }
+
+    Ok(output)
+}
+
+fn get_svx_chunk_metadata(
+    chunk_id: String,
+    chunk_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let result = match chunk_id.as_str() {
+        VOICE_HEADER_CHUNK_ID => vhdr::get_metadata(chunk_data)?,
+        NAME_CHUNK_ID => text::get_metadata(NAME_TEMPLATE_TITLE, chunk_data)?,
+        AUTHOR_CHUNK_ID => text::get_metadata(AUTHOR_TEMPLATE_TITLE, chunk_data)?,
+        ANNOTATION_CHUNK_ID => text::get_metadata(ANNOTATION_TEMPLATE_TITLE, chunk_data)?,
+        COPYRIGHT_CHUNK_ID => text::get_metadata(COPYRIGHT_TEMPLATE_TITLE, chunk_data)?,
+        ATTACK_CHUNK_ID => envelope::get_metadata(ATTACK_TEMPLATE_TITLE, chunk_data)?,
+        RELEASE_CHUNK_ID => envelope::get_metadata(RELEASE_TEMPLATE_TITLE, chunk_data)?,
+        _ => extra::get_metadata(chunk_id, chunk_data)?,
+    };
+
+    Ok(result)
+}
+
+pub fn format_fixed_point_as_string(fixed_point_value: u32) -> String {
+    format!("{:.4}", fixed_point_value as f64 / 65536.0)
+}
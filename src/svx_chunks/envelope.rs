@@ -0,0 +1,78 @@
+use crate::byte_arrays::{
+    take_first_four_bytes_as_unsigned_integer, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::svx_chunks::format_fixed_point_as_string;
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/svx_chunks/envelope.tmpl");
+const ENVELOPE_POINT_LENGTH_IN_BYTES: usize = 6;
+
+#[derive(Debug, Serialize, PartialEq)]
+struct EnvelopePoint {
+    duration: u16,
+    destination: String,
+}
+
+pub fn get_metadata(title: &str, chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let envelope_points = get_envelope_points_from_bytes(chunk_data)?;
+
+    let output_values: Value = upon::value! {
+        title: title,
+        title_underline: "-".repeat(title.len() + 1),
+        envelope_points: envelope_points,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+fn get_envelope_points_from_bytes(
+    mut chunk_data: Vec<u8>,
+) -> Result<Vec<EnvelopePoint>, LocalError> {
+    let mut envelope_points: Vec<EnvelopePoint> = vec![];
+
+    while chunk_data.len() >= ENVELOPE_POINT_LENGTH_IN_BYTES {
+        let duration = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+        let destination = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+
+        envelope_points.push(EnvelopePoint {
+            duration,
+            destination: format_fixed_point_as_string(destination),
+        });
+    }
+
+    Ok(envelope_points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_envelope_points_ignoring_trailing_bytes() {
+        let chunk_data: Vec<u8> = vec![
+            0x00, 0x0A, 0x00, 0x01, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x40, 0x00, 0x00,
+        ];
+        let result = get_envelope_points_from_bytes(chunk_data).unwrap();
+        let correct_result = vec![
+            EnvelopePoint {
+                duration: 10,
+                destination: "1.0000".to_string(),
+            },
+            EnvelopePoint {
+                duration: 20,
+                destination: "0.2500".to_string(),
+            },
+        ];
+        assert_eq!(result, correct_result);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::byte_arrays::{
+    take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::svx_chunks::format_fixed_point_as_string;
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/svx_chunks/vhdr.tmpl");
+const COMPRESSION_TYPES: [&str; 2] = ["None", "Fibonacci-delta"];
+
+pub fn get_metadata(mut chunk_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let one_shot_hi_samples =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let repeat_hi_samples =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let samples_per_hi_cycle =
+        take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let sample_rate = take_first_two_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+    let octaves = take_first_byte(&mut chunk_data)?;
+    let compression = take_first_byte(&mut chunk_data)?;
+    let volume = take_first_four_bytes_as_unsigned_integer(&mut chunk_data, Endian::Big)?;
+
+    let output_values: Value = upon::value! {
+        one_shot_hi_samples: one_shot_hi_samples,
+        repeat_hi_samples: repeat_hi_samples,
+        samples_per_hi_cycle: samples_per_hi_cycle,
+        sample_rate: sample_rate,
+        octaves: octaves,
+        compression: get_compression_name(compression),
+        volume: format_fixed_point_as_string(volume),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_compression_name(compression: u8) -> String {
+    match COMPRESSION_TYPES.get(compression as usize) {
+        Some(name) => name.to_string(),
+        None => format!("Unknown ({})", compression),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_output_from_vhdr_chunk_data() {
+        let mut chunk_data: Vec<u8> = vec![];
+        chunk_data.extend(1000u32.to_be_bytes());
+        chunk_data.extend(500u32.to_be_bytes());
+        chunk_data.extend(32u32.to_be_bytes());
+        chunk_data.extend(8363u16.to_be_bytes());
+        chunk_data.extend([1, 1]);
+        chunk_data.extend(0x8000u32.to_be_bytes());
+
+        let result = get_metadata(chunk_data).unwrap();
+        let correct_result = "----------------------------\nVoice Header (VHDR) Details:\n----------------------------\nOne Shot Samples:      1000\nRepeat Samples:        500\nSamples per Cycle:     32\nSample Rate:           8363 Hz\nOctaves:               1\nCompression:           Fibonacci-delta\nVolume:                0.5000\n";
+        assert_eq!(result.text, correct_result);
+    }
+}
@@ -0,0 +1,124 @@
+pub mod it;
+pub mod protracker;
+pub mod s3m;
+pub mod xm;
+
+use crate::byte_arrays::{
+    take_first_four_bytes_as_unsigned_integer, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use serde::Serialize;
+use std::error::Error;
+use upon::Value;
+
+const NAMES_TEMPLATE_CONTENT: &str = include_str!("templates/tracker_modules/names.tmpl");
+const MESSAGE_TEMPLATE_CONTENT: &str = include_str!("templates/tracker_modules/message.tmpl");
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct ModuleName {
+    pub number: String,
+    pub name: String,
+    pub details: String,
+}
+
+impl ModuleName {
+    pub fn new(index: usize, name: String, details: String) -> Self {
+        Self {
+            number: format!("{:02}", index + 1),
+            name,
+            details,
+        }
+    }
+}
+
+pub fn get_bytes_at_offset(
+    module_data: &[u8],
+    offset: usize,
+    number_of_bytes: usize,
+) -> Result<Vec<u8>, LocalError> {
+    match module_data.get(offset..offset.saturating_add(number_of_bytes)) {
+        Some(bytes) => Ok(bytes.to_vec()),
+        None => Err(LocalError::InsufficientBytesToTake(
+            offset.saturating_add(number_of_bytes),
+            module_data.len(),
+        )),
+    }
+}
+
+pub fn get_u16_at_offset(module_data: &[u8], offset: usize) -> Result<u16, LocalError> {
+    let mut value_bytes = get_bytes_at_offset(module_data, offset, 2)?;
+    take_first_two_bytes_as_unsigned_integer(&mut value_bytes, Endian::Little)
+}
+
+pub fn get_u32_at_offset(module_data: &[u8], offset: usize) -> Result<u32, LocalError> {
+    let mut value_bytes = get_bytes_at_offset(module_data, offset, 4)?;
+    take_first_four_bytes_as_unsigned_integer(&mut value_bytes, Endian::Little)
+}
+
+pub fn get_string_at_offset(
+    module_data: &[u8],
+    offset: usize,
+    number_of_bytes: usize,
+) -> Result<String, LocalError> {
+    let string_bytes = get_bytes_at_offset(module_data, offset, number_of_bytes)?;
+    let string_length = string_bytes
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(string_bytes.len());
+
+    Ok(String::from_utf8_lossy(&string_bytes[..string_length])
+        .trim_end()
+        .to_string())
+}
+
+pub fn get_names_metadata(
+    title: &str,
+    names: Vec<ModuleName>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        title: title,
+        title_underline: "-".repeat(title.len()),
+        names: names,
+    };
+
+    let formated_output = get_file_chunk_output(NAMES_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+pub fn get_message_metadata(message: String) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        message: message,
+    };
+
+    let formated_output = get_file_chunk_output(MESSAGE_TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_string_up_to_first_null_byte_at_offset() {
+        let module_data = b"xxSong Name\0garbage   ".to_vec();
+        let result = get_string_at_offset(&module_data, 2, 20).unwrap();
+        assert_eq!(result, "Song Name");
+    }
+
+    #[test]
+    fn return_error_when_offset_is_past_the_end_of_the_data() {
+        let module_data = vec![0u8; 10];
+        let result = get_bytes_at_offset(&module_data, 8, 4);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,317 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::tracker_modules::{
+    get_bytes_at_offset, get_message_metadata, get_names_metadata, get_string_at_offset,
+    get_u32_at_offset, ModuleName,
+};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/tracker_modules/it.tmpl");
+const SONG_NAME_OFFSET: usize = 4;
+const NAME_LENGTH_IN_BYTES: usize = 26;
+const HEADER_FIELDS_OFFSET: usize = 32;
+const HEADER_FIELDS_LENGTH_IN_BYTES: usize = 28;
+const SEPARATION_AND_PITCH_WHEEL_LENGTH_IN_BYTES: usize = 2;
+const ORDER_TABLE_OFFSET: usize = 192;
+const OFFSET_LENGTH_IN_BYTES: usize = 4;
+const INSTRUMENT_NAME_OFFSET: usize = 32;
+const SAMPLE_FLAGS_OFFSET: usize = 18;
+const SAMPLE_VOLUME_OFFSET: usize = 19;
+const SAMPLE_NAME_OFFSET: usize = 20;
+const SAMPLE_LENGTH_OFFSET: usize = 48;
+const SAMPLE_SPEED_OFFSET: usize = 60;
+const SAMPLE_HAS_DATA_FLAG: u8 = 0x01;
+const SIXTEEN_BIT_SAMPLE_FLAG: u8 = 0x02;
+const STEREO_FLAG: u16 = 0x01;
+const USE_INSTRUMENTS_FLAG: u16 = 0x04;
+const LINEAR_SLIDES_FLAG: u16 = 0x08;
+const MESSAGE_ATTACHED_FLAG: u16 = 0x01;
+const IMPULSE_TRACKER_ID: u16 = 0;
+const TRACKER_NAMES: [(u16, &str); 4] = [
+    (IMPULSE_TRACKER_ID, "Impulse Tracker"),
+    (1, "Schism Tracker"),
+    (5, "OpenMPT"),
+    (6, "BeRoTracker"),
+];
+const INSTRUMENTS_TEMPLATE_TITLE: &str = "Instruments";
+const SAMPLES_TEMPLATE_TITLE: &str = "Samples";
+
+type NameAndDetails = (String, String);
+
+#[derive(Debug, Default, PartialEq)]
+struct ItHeader {
+    number_of_orders: u16,
+    number_of_instruments: u16,
+    number_of_samples: u16,
+    number_of_patterns: u16,
+    created_with: u16,
+    compatible_with: u16,
+    flags: u16,
+    special: u16,
+    global_volume: u8,
+    mix_volume: u8,
+    initial_speed: u8,
+    initial_tempo: u8,
+    message_length: u16,
+    message_offset: u32,
+}
+
+pub fn get_metadata(
+    module_data: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let it_header = get_it_header_from_bytes(&module_data)?;
+    let mut output = vec![get_header_metadata(&module_data, &it_header)?];
+
+    if mandatory_sections_only {
+        return Ok(output);
+    }
+
+    let message = get_message_from_bytes(&module_data, &it_header);
+    if !message.is_empty() {
+        output.push(get_message_metadata(message)?);
+    }
+
+    let instrument_offsets_start = ORDER_TABLE_OFFSET + it_header.number_of_orders as usize;
+    let instruments = get_names_from_offsets(
+        &module_data,
+        instrument_offsets_start,
+        it_header.number_of_instruments,
+        get_instrument_name,
+    );
+    if !instruments.is_empty() {
+        output.push(get_names_metadata(INSTRUMENTS_TEMPLATE_TITLE, instruments)?);
+    }
+
+    let sample_offsets_start = instrument_offsets_start
+        + it_header.number_of_instruments as usize * OFFSET_LENGTH_IN_BYTES;
+    let samples = get_names_from_offsets(
+        &module_data,
+        sample_offsets_start,
+        it_header.number_of_samples,
+        get_sample_name,
+    );
+    if !samples.is_empty() {
+        output.push(get_names_metadata(SAMPLES_TEMPLATE_TITLE, samples)?);
+    }
+
+    Ok(output)
+}
+
+fn get_it_header_from_bytes(module_data: &[u8]) -> Result<ItHeader, Box<dyn Error>> {
+    let mut header_fields = get_bytes_at_offset(
+        module_data,
+        HEADER_FIELDS_OFFSET,
+        HEADER_FIELDS_LENGTH_IN_BYTES,
+    )?;
+
+    let mut it_header = ItHeader {
+        number_of_orders: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        number_of_instruments: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        number_of_samples: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        number_of_patterns: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        created_with: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        compatible_with: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        flags: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        special: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        global_volume: take_first_byte(&mut header_fields)?,
+        mix_volume: take_first_byte(&mut header_fields)?,
+        initial_speed: take_first_byte(&mut header_fields)?,
+        initial_tempo: take_first_byte(&mut header_fields)?,
+        ..Default::default()
+    };
+
+    skip_over_bytes(
+        &mut header_fields,
+        SEPARATION_AND_PITCH_WHEEL_LENGTH_IN_BYTES,
+    )?;
+    it_header.message_length =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+    it_header.message_offset =
+        take_first_four_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+
+    Ok(it_header)
+}
+
+fn get_header_metadata(
+    module_data: &[u8],
+    it_header: &ItHeader,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        song_name: get_string_at_offset(module_data, SONG_NAME_OFFSET, NAME_LENGTH_IN_BYTES)?,
+        created_with: format_tracker_version(it_header.created_with),
+        compatible_with: format_version(it_header.compatible_with),
+        number_of_orders: it_header.number_of_orders,
+        number_of_instruments: it_header.number_of_instruments,
+        number_of_samples: it_header.number_of_samples,
+        number_of_patterns: it_header.number_of_patterns,
+        stereo: it_header.flags & STEREO_FLAG != 0,
+        use_instruments: it_header.flags & USE_INSTRUMENTS_FLAG != 0,
+        linear_slides: it_header.flags & LINEAR_SLIDES_FLAG != 0,
+        global_volume: it_header.global_volume,
+        mix_volume: it_header.mix_volume,
+        initial_speed: it_header.initial_speed,
+        initial_tempo: it_header.initial_tempo,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_message_from_bytes(module_data: &[u8], it_header: &ItHeader) -> String {
+    if it_header.special & MESSAGE_ATTACHED_FLAG == 0 {
+        return String::new();
+    }
+
+    let Ok(message_bytes) = get_bytes_at_offset(
+        module_data,
+        it_header.message_offset as usize,
+        it_header.message_length as usize,
+    ) else {
+        return String::new();
+    };
+
+    let message_length = message_bytes
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(message_bytes.len());
+
+    String::from_utf8_lossy(&message_bytes[..message_length])
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .trim_end()
+        .to_string()
+}
+
+fn get_names_from_offsets(
+    module_data: &[u8],
+    offsets_start: usize,
+    number_of_offsets: u16,
+    get_name: fn(&[u8], usize) -> Option<NameAndDetails>,
+) -> Vec<ModuleName> {
+    let mut names: Vec<ModuleName> = vec![];
+
+    for index in 0..number_of_offsets as usize {
+        let Ok(offset) =
+            get_u32_at_offset(module_data, offsets_start + index * OFFSET_LENGTH_IN_BYTES)
+        else {
+            break;
+        };
+
+        let Some((name, details)) = get_name(module_data, offset as usize) else {
+            continue;
+        };
+
+        if name.trim().is_empty() && details.is_empty() {
+            continue;
+        }
+
+        names.push(ModuleName::new(index, name, details));
+    }
+
+    names
+}
+
+fn get_instrument_name(module_data: &[u8], offset: usize) -> Option<NameAndDetails> {
+    let name = get_string_at_offset(
+        module_data,
+        offset + INSTRUMENT_NAME_OFFSET,
+        NAME_LENGTH_IN_BYTES,
+    )
+    .ok()?;
+
+    Some((name, String::new()))
+}
+
+fn get_sample_name(module_data: &[u8], offset: usize) -> Option<NameAndDetails> {
+    let name = get_string_at_offset(
+        module_data,
+        offset + SAMPLE_NAME_OFFSET,
+        NAME_LENGTH_IN_BYTES,
+    )
+    .ok()?;
+    let flags = get_bytes_at_offset(module_data, offset + SAMPLE_FLAGS_OFFSET, 1).ok()?[0];
+    let volume = get_bytes_at_offset(module_data, offset + SAMPLE_VOLUME_OFFSET, 1).ok()?[0];
+    let length = get_u32_at_offset(module_data, offset + SAMPLE_LENGTH_OFFSET).ok()?;
+    let speed = get_u32_at_offset(module_data, offset + SAMPLE_SPEED_OFFSET).ok()?;
+
+    if flags & SAMPLE_HAS_DATA_FLAG == 0 {
+        return Some((name, String::new()));
+    }
+
+    let bits_per_sample = match flags & SIXTEEN_BIT_SAMPLE_FLAG {
+        0 => 8,
+        _ => 16,
+    };
+
+    Some((
+        name,
+        format!(
+            "{} samples, {}-bit, volume {}, C5 speed {} Hz",
+            length, bits_per_sample, volume, speed
+        ),
+    ))
+}
+
+fn format_tracker_version(tracker_version: u16) -> String {
+    let tracker_id = tracker_version >> 12;
+
+    match TRACKER_NAMES.iter().find(|(id, _)| *id == tracker_id) {
+        Some((IMPULSE_TRACKER_ID, name)) => format!("{} {}", name, format_version(tracker_version)),
+        Some((_, name)) => format!("{} ({:#06X})", name, tracker_version),
+        None => format!("Unknown ({:#06X})", tracker_version),
+    }
+}
+
+fn format_version(version: u16) -> String {
+    format!("{:X}.{:02X}", (version >> 8) & 0x0F, version & 0xFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_tracker_name_and_version() {
+        assert_eq!(format_tracker_version(0x0214), "Impulse Tracker 2.14");
+        assert_eq!(format_tracker_version(0x5131), "OpenMPT (0x5131)");
+    }
+
+    #[test]
+    fn return_message_with_carriage_returns_as_new_lines() {
+        let mut module_data = vec![0u8; 10];
+        module_data.extend(b"Line one\rLine two\0");
+        let it_header = ItHeader {
+            special: MESSAGE_ATTACHED_FLAG,
+            message_length: 18,
+            message_offset: 10,
+            ..Default::default()
+        };
+        let result = get_message_from_bytes(&module_data, &it_header);
+        assert_eq!(result, "Line one\nLine two");
+    }
+}
@@ -0,0 +1,152 @@
+use crate::byte_arrays::{take_first_two_bytes_as_unsigned_integer, Endian};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::tracker_modules::{
+    get_bytes_at_offset, get_names_metadata, get_string_at_offset, ModuleName,
+};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/tracker_modules/protracker.tmpl");
+pub const SIGNATURE_OFFSET: usize = 1080;
+pub const SIGNATURE_LENGTH_IN_BYTES: usize = 4;
+const TITLE_LENGTH_IN_BYTES: usize = 20;
+const NUMBER_OF_SAMPLES: usize = 31;
+const SAMPLE_HEADER_LENGTH_IN_BYTES: usize = 30;
+const SAMPLE_NAME_LENGTH_IN_BYTES: usize = 22;
+const SONG_LENGTH_OFFSET: usize = 950;
+const RESTART_POSITION_OFFSET: usize = 951;
+const ORDER_TABLE_OFFSET: usize = 952;
+const ORDER_TABLE_LENGTH_IN_BYTES: usize = 128;
+const FOUR_CHANNEL_SIGNATURES: [&[u8]; 4] = [b"M.K.", b"M!K!", b"FLT4", b"4CHN"];
+const EIGHT_CHANNEL_SIGNATURES: [&[u8]; 3] = [b"FLT8", b"OKTA", b"CD81"];
+const SAMPLES_TEMPLATE_TITLE: &str = "Samples";
+
+pub fn get_channels_from_signature(signature: &[u8]) -> Option<u8> {
+    if FOUR_CHANNEL_SIGNATURES.contains(&signature) {
+        return Some(4);
+    }
+
+    if EIGHT_CHANNEL_SIGNATURES.contains(&signature) {
+        return Some(8);
+    }
+
+    let signature_text = std::str::from_utf8(signature).ok()?;
+
+    if let Some(channels) = signature_text.strip_suffix("CHN") {
+        return channels.parse::<u8>().ok();
+    }
+
+    if let Some(channels) = signature_text.strip_suffix("CH") {
+        return channels.parse::<u8>().ok();
+    }
+
+    None
+}
+
+pub fn get_metadata(
+    module_data: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output = vec![get_header_metadata(&module_data)?];
+
+    if !mandatory_sections_only {
+        let samples = get_samples_from_bytes(&module_data)?;
+        if !samples.is_empty() {
+            output.push(get_names_metadata(SAMPLES_TEMPLATE_TITLE, samples)?);
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_header_metadata(module_data: &[u8]) -> Result<OutputEntry, Box<dyn Error>> {
+    let signature_bytes =
+        get_bytes_at_offset(module_data, SIGNATURE_OFFSET, SIGNATURE_LENGTH_IN_BYTES)?;
+    let order_table =
+        get_bytes_at_offset(module_data, ORDER_TABLE_OFFSET, ORDER_TABLE_LENGTH_IN_BYTES)?;
+    let number_of_patterns = order_table
+        .iter()
+        .max()
+        .map_or(0, |order| *order as u16 + 1);
+
+    let output_values: Value = upon::value! {
+        title: get_string_at_offset(module_data, 0, TITLE_LENGTH_IN_BYTES)?,
+        signature: String::from_utf8_lossy(&signature_bytes).to_string(),
+        channels: get_channels_from_signature(&signature_bytes).unwrap_or_default(),
+        number_of_samples: NUMBER_OF_SAMPLES,
+        song_length: get_bytes_at_offset(module_data, SONG_LENGTH_OFFSET, 1)?[0],
+        restart_position: get_bytes_at_offset(module_data, RESTART_POSITION_OFFSET, 1)?[0],
+        number_of_patterns: number_of_patterns,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_samples_from_bytes(module_data: &[u8]) -> Result<Vec<ModuleName>, Box<dyn Error>> {
+    let mut samples: Vec<ModuleName> = vec![];
+
+    for index in 0..NUMBER_OF_SAMPLES {
+        let sample_offset = TITLE_LENGTH_IN_BYTES + index * SAMPLE_HEADER_LENGTH_IN_BYTES;
+        let name = get_string_at_offset(module_data, sample_offset, SAMPLE_NAME_LENGTH_IN_BYTES)?;
+
+        let mut sample_header = get_bytes_at_offset(
+            module_data,
+            sample_offset + SAMPLE_NAME_LENGTH_IN_BYTES,
+            SAMPLE_HEADER_LENGTH_IN_BYTES - SAMPLE_NAME_LENGTH_IN_BYTES,
+        )?;
+        let length =
+            take_first_two_bytes_as_unsigned_integer(&mut sample_header, Endian::Big)? as u32 * 2;
+        let finetune = get_signed_finetune(sample_header[0]);
+        let volume = sample_header[1];
+
+        if name.trim().is_empty() && length == 0 {
+            continue;
+        }
+
+        let details = match length {
+            0 => String::new(),
+            _ => format!("{} bytes, volume {}, finetune {}", length, volume, finetune),
+        };
+
+        samples.push(ModuleName::new(index, name, details));
+    }
+
+    Ok(samples)
+}
+
+fn get_signed_finetune(finetune_byte: u8) -> i8 {
+    let finetune = (finetune_byte & 0x0F) as i8;
+
+    if finetune > 7 {
+        finetune - 16
+    } else {
+        finetune
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_channels_from_signatures() {
+        assert_eq!(get_channels_from_signature(b"M.K."), Some(4));
+        assert_eq!(get_channels_from_signature(b"6CHN"), Some(6));
+        assert_eq!(get_channels_from_signature(b"16CH"), Some(16));
+        assert_eq!(get_channels_from_signature(b"OKTA"), Some(8));
+        assert_eq!(get_channels_from_signature(b"ABCD"), None);
+    }
+
+    #[test]
+    fn return_correct_signed_finetune_from_nibble() {
+        assert_eq!(get_signed_finetune(0x07), 7);
+        assert_eq!(get_signed_finetune(0x0F), -1);
+        assert_eq!(get_signed_finetune(0x08), -8);
+    }
+}
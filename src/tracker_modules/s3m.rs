@@ -0,0 +1,212 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::tracker_modules::{
+    get_bytes_at_offset, get_names_metadata, get_string_at_offset, get_u32_at_offset, ModuleName,
+};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/tracker_modules/s3m.tmpl");
+pub const SIGNATURE_OFFSET: usize = 44;
+pub const SIGNATURE: &[u8] = b"SCRM";
+const TITLE_LENGTH_IN_BYTES: usize = 28;
+const HEADER_FIELDS_OFFSET: usize = 32;
+const HEADER_FIELDS_LENGTH_IN_BYTES: usize = 12;
+const FLAGS_LENGTH_IN_BYTES: usize = 2;
+const VOLUME_FIELDS_OFFSET: usize = 48;
+const VOLUME_FIELDS_LENGTH_IN_BYTES: usize = 4;
+const CHANNEL_SETTINGS_OFFSET: usize = 64;
+const CHANNEL_SETTINGS_LENGTH_IN_BYTES: usize = 32;
+const DISABLED_CHANNEL_FLAG: u8 = 0x80;
+const ORDER_TABLE_OFFSET: usize = 96;
+const PARAPOINTER_LENGTH_IN_BYTES: usize = 2;
+const PARAPOINTER_MULTIPLIER: usize = 16;
+const INSTRUMENT_HEADER_LENGTH_IN_BYTES: usize = 80;
+const INSTRUMENT_NAME_OFFSET: usize = 48;
+const SAMPLE_LENGTH_OFFSET: usize = 16;
+const SAMPLE_VOLUME_OFFSET: usize = 28;
+const SAMPLE_SPEED_OFFSET: usize = 32;
+const SAMPLE_INSTRUMENT_TYPE: u8 = 1;
+const STEREO_FLAG: u8 = 0x80;
+const INSTRUMENTS_TEMPLATE_TITLE: &str = "Instruments";
+
+const TRACKER_NAMES: [(u16, &str); 7] = [
+    (1, "Scream Tracker"),
+    (2, "Imago Orpheus"),
+    (3, "Impulse Tracker"),
+    (4, "Schism Tracker"),
+    (5, "OpenMPT"),
+    (6, "BeRoTracker"),
+    (7, "CreamTracker"),
+];
+const SAMPLE_FORMATS: [(u16, &str); 2] = [(1, "Signed"), (2, "Unsigned")];
+
+pub fn get_metadata(
+    module_data: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let mut output = vec![get_header_metadata(&module_data)?];
+
+    if !mandatory_sections_only {
+        let instruments = get_instruments_from_bytes(&module_data)?;
+        if !instruments.is_empty() {
+            output.push(get_names_metadata(INSTRUMENTS_TEMPLATE_TITLE, instruments)?);
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_header_metadata(module_data: &[u8]) -> Result<OutputEntry, Box<dyn Error>> {
+    let mut header_fields = get_bytes_at_offset(
+        module_data,
+        HEADER_FIELDS_OFFSET,
+        HEADER_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let number_of_orders =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+    let number_of_instruments =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+    let number_of_patterns =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+    skip_over_bytes(&mut header_fields, FLAGS_LENGTH_IN_BYTES)?;
+    let tracker_version =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+    let sample_format =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?;
+
+    let mut volume_fields = get_bytes_at_offset(
+        module_data,
+        VOLUME_FIELDS_OFFSET,
+        VOLUME_FIELDS_LENGTH_IN_BYTES,
+    )?;
+    let global_volume = take_first_byte(&mut volume_fields)?;
+    let initial_speed = take_first_byte(&mut volume_fields)?;
+    let initial_tempo = take_first_byte(&mut volume_fields)?;
+    let master_volume = take_first_byte(&mut volume_fields)?;
+
+    let channel_settings = get_bytes_at_offset(
+        module_data,
+        CHANNEL_SETTINGS_OFFSET,
+        CHANNEL_SETTINGS_LENGTH_IN_BYTES,
+    )?;
+    let channels = channel_settings
+        .iter()
+        .filter(|setting| **setting & DISABLED_CHANNEL_FLAG == 0)
+        .count();
+
+    let output_values: Value = upon::value! {
+        title: get_string_at_offset(module_data, 0, TITLE_LENGTH_IN_BYTES)?,
+        tracker: format_tracker_version(tracker_version),
+        number_of_orders: number_of_orders,
+        number_of_instruments: number_of_instruments,
+        number_of_patterns: number_of_patterns,
+        channels: channels,
+        sample_format: get_name_from_table(&SAMPLE_FORMATS, sample_format),
+        global_volume: global_volume,
+        master_volume: master_volume & !STEREO_FLAG,
+        stereo: master_volume & STEREO_FLAG != 0,
+        initial_speed: initial_speed,
+        initial_tempo: initial_tempo,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_instruments_from_bytes(module_data: &[u8]) -> Result<Vec<ModuleName>, Box<dyn Error>> {
+    let mut header_fields = get_bytes_at_offset(module_data, HEADER_FIELDS_OFFSET, 4)?;
+    let number_of_orders =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)? as usize;
+    let number_of_instruments =
+        take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)? as usize;
+
+    let mut parapointers = get_bytes_at_offset(
+        module_data,
+        ORDER_TABLE_OFFSET + number_of_orders,
+        number_of_instruments * PARAPOINTER_LENGTH_IN_BYTES,
+    )?;
+    let mut instruments: Vec<ModuleName> = vec![];
+
+    for index in 0..number_of_instruments {
+        let instrument_offset =
+            take_first_two_bytes_as_unsigned_integer(&mut parapointers, Endian::Little)? as usize
+                * PARAPOINTER_MULTIPLIER;
+        let Ok(instrument_header) = get_bytes_at_offset(
+            module_data,
+            instrument_offset,
+            INSTRUMENT_HEADER_LENGTH_IN_BYTES,
+        ) else {
+            break;
+        };
+
+        let name = get_string_at_offset(
+            &instrument_header,
+            INSTRUMENT_NAME_OFFSET,
+            TITLE_LENGTH_IN_BYTES,
+        )?;
+        let details = get_instrument_details(&instrument_header)?;
+
+        if name.trim().is_empty() && details.is_empty() {
+            continue;
+        }
+
+        instruments.push(ModuleName::new(index, name, details));
+    }
+
+    Ok(instruments)
+}
+
+fn get_instrument_details(instrument_header: &[u8]) -> Result<String, Box<dyn Error>> {
+    match instrument_header[0] {
+        0 => Ok(String::new()),
+        SAMPLE_INSTRUMENT_TYPE => {
+            let length = get_u32_at_offset(instrument_header, SAMPLE_LENGTH_OFFSET)?;
+            let volume = instrument_header[SAMPLE_VOLUME_OFFSET];
+            let speed = get_u32_at_offset(instrument_header, SAMPLE_SPEED_OFFSET)?;
+
+            Ok(format!(
+                "{} samples, volume {}, C4 speed {} Hz",
+                length, volume, speed
+            ))
+        }
+        _ => Ok("AdLib instrument".to_string()),
+    }
+}
+
+fn format_tracker_version(tracker_version: u16) -> String {
+    let tracker_id = tracker_version >> 12;
+    let tracker_name = get_name_from_table(&TRACKER_NAMES, tracker_id);
+
+    format!(
+        "{} {:X}.{:02X}",
+        tracker_name,
+        (tracker_version >> 8) & 0x0F,
+        tracker_version & 0xFF
+    )
+}
+
+fn get_name_from_table(table: &[(u16, &str)], id: u16) -> String {
+    match table.iter().find(|(table_id, _)| *table_id == id) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_tracker_name_and_version() {
+        assert_eq!(format_tracker_version(0x1320), "Scream Tracker 3.20");
+        assert_eq!(format_tracker_version(0x3216), "Impulse Tracker 2.16");
+    }
+}
@@ -0,0 +1,309 @@
+use crate::byte_arrays::{take_first_two_bytes_as_unsigned_integer, Endian};
+use crate::errors::LocalError;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::tracker_modules::{
+    get_bytes_at_offset, get_names_metadata, get_string_at_offset, get_u16_at_offset,
+    get_u32_at_offset, ModuleName,
+};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/tracker_modules/xm.tmpl");
+pub const ID_TEXT: &[u8] = b"Extended Module: ";
+const MODULE_NAME_OFFSET: usize = 17;
+const NAME_LENGTH_IN_BYTES: usize = 20;
+const TRACKER_NAME_OFFSET: usize = 38;
+const VERSION_OFFSET: usize = 58;
+const HEADER_SIZE_OFFSET: usize = 60;
+const HEADER_FIELDS_OFFSET: usize = 64;
+const HEADER_FIELDS_LENGTH_IN_BYTES: usize = 16;
+const LINEAR_FREQUENCY_TABLE_FLAG: u16 = 0x01;
+const PATTERN_PACKED_SIZE_OFFSET: usize = 7;
+const INSTRUMENT_NAME_OFFSET: usize = 4;
+const INSTRUMENT_NAME_LENGTH_IN_BYTES: usize = 22;
+const INSTRUMENT_SAMPLE_COUNT_OFFSET: usize = 27;
+const SAMPLE_HEADER_SIZE_OFFSET: usize = 29;
+const SAMPLE_HEADER_LENGTH_IN_BYTES: usize = 40;
+const SAMPLE_VOLUME_OFFSET: usize = 12;
+const SAMPLE_TYPE_OFFSET: usize = 14;
+const SAMPLE_NAME_OFFSET: usize = 18;
+const SIXTEEN_BIT_SAMPLE_FLAG: u8 = 0x10;
+const INSTRUMENTS_TEMPLATE_TITLE: &str = "Instruments";
+const SAMPLES_TEMPLATE_TITLE: &str = "Samples";
+
+#[derive(Debug, Default, PartialEq)]
+struct XmHeader {
+    song_length: u16,
+    restart_position: u16,
+    channels: u16,
+    number_of_patterns: u16,
+    number_of_instruments: u16,
+    flags: u16,
+    default_tempo: u16,
+    default_bpm: u16,
+}
+
+pub fn get_metadata(
+    module_data: Vec<u8>,
+    mandatory_sections_only: bool,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    let xm_header = get_xm_header_from_bytes(&module_data)?;
+    let mut output = vec![get_header_metadata(&module_data, &xm_header)?];
+
+    if !mandatory_sections_only {
+        let (instruments, samples) =
+            get_instruments_and_samples_from_bytes(&module_data, &xm_header)?;
+
+        if !instruments.is_empty() {
+            output.push(get_names_metadata(INSTRUMENTS_TEMPLATE_TITLE, instruments)?);
+        }
+
+        if !samples.is_empty() {
+            output.push(get_names_metadata(SAMPLES_TEMPLATE_TITLE, samples)?);
+        }
+    }
+
+    Ok(output)
+}
+
+fn get_xm_header_from_bytes(module_data: &[u8]) -> Result<XmHeader, LocalError> {
+    let mut header_fields = get_bytes_at_offset(
+        module_data,
+        HEADER_FIELDS_OFFSET,
+        HEADER_FIELDS_LENGTH_IN_BYTES,
+    )?;
+
+    Ok(XmHeader {
+        song_length: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        restart_position: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        channels: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        number_of_patterns: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        number_of_instruments: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        flags: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+        default_tempo: take_first_two_bytes_as_unsigned_integer(
+            &mut header_fields,
+            Endian::Little,
+        )?,
+        default_bpm: take_first_two_bytes_as_unsigned_integer(&mut header_fields, Endian::Little)?,
+    })
+}
+
+fn get_header_metadata(
+    module_data: &[u8],
+    xm_header: &XmHeader,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let version_bytes = get_bytes_at_offset(module_data, VERSION_OFFSET, 2)?;
+
+    let frequency_table = match xm_header.flags & LINEAR_FREQUENCY_TABLE_FLAG {
+        0 => "Amiga",
+        _ => "Linear",
+    };
+
+    let output_values: Value = upon::value! {
+        module_name: get_string_at_offset(module_data, MODULE_NAME_OFFSET, NAME_LENGTH_IN_BYTES)?,
+        tracker_name: get_string_at_offset(module_data, TRACKER_NAME_OFFSET, NAME_LENGTH_IN_BYTES)?,
+        version: format!("{}.{:02}", version_bytes[1], version_bytes[0]),
+        song_length: xm_header.song_length,
+        restart_position: xm_header.restart_position,
+        channels: xm_header.channels,
+        number_of_patterns: xm_header.number_of_patterns,
+        number_of_instruments: xm_header.number_of_instruments,
+        frequency_table: frequency_table,
+        default_tempo: xm_header.default_tempo,
+        default_bpm: xm_header.default_bpm,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_instruments_and_samples_from_bytes(
+    module_data: &[u8],
+    xm_header: &XmHeader,
+) -> Result<(Vec<ModuleName>, Vec<ModuleName>), Box<dyn Error>> {
+    let mut instruments: Vec<ModuleName> = vec![];
+    let mut samples: Vec<ModuleName> = vec![];
+
+    let Ok(mut offset) = get_first_instrument_offset(module_data, xm_header) else {
+        return Ok((instruments, samples));
+    };
+
+    for index in 0..xm_header.number_of_instruments as usize {
+        let Ok(instrument_size) = get_u32_at_offset(module_data, offset) else {
+            break;
+        };
+        let Ok(name) = get_string_at_offset(
+            module_data,
+            offset + INSTRUMENT_NAME_OFFSET,
+            INSTRUMENT_NAME_LENGTH_IN_BYTES,
+        ) else {
+            break;
+        };
+        let number_of_samples =
+            get_u16_at_offset(module_data, offset + INSTRUMENT_SAMPLE_COUNT_OFFSET).unwrap_or(0);
+
+        let details = match number_of_samples {
+            0 => String::new(),
+            1 => "1 sample".to_string(),
+            _ => format!("{} samples", number_of_samples),
+        };
+
+        if !name.trim().is_empty() || number_of_samples > 0 {
+            instruments.push(ModuleName::new(index, name, details));
+        }
+
+        let mut sample_header_size = 0;
+        if number_of_samples > 0 {
+            let Ok(header_size) =
+                get_u32_at_offset(module_data, offset + SAMPLE_HEADER_SIZE_OFFSET)
+            else {
+                break;
+            };
+            sample_header_size = header_size as usize;
+        }
+
+        offset += instrument_size as usize;
+        let mut sample_data_size: usize = 0;
+
+        for _ in 0..number_of_samples {
+            let Ok(sample_length) = get_u32_at_offset(module_data, offset) else {
+                break;
+            };
+            let Ok(sample_header) =
+                get_bytes_at_offset(module_data, offset, SAMPLE_HEADER_LENGTH_IN_BYTES)
+            else {
+                break;
+            };
+            let Ok(sample_name) = get_string_at_offset(
+                &sample_header,
+                SAMPLE_NAME_OFFSET,
+                INSTRUMENT_NAME_LENGTH_IN_BYTES,
+            ) else {
+                break;
+            };
+            let bits_per_sample = match sample_header[SAMPLE_TYPE_OFFSET] & SIXTEEN_BIT_SAMPLE_FLAG
+            {
+                0 => 8,
+                _ => 16,
+            };
+
+            samples.push(ModuleName::new(
+                samples.len(),
+                sample_name,
+                format!(
+                    "{} bytes, {}-bit, volume {}",
+                    sample_length, bits_per_sample, sample_header[SAMPLE_VOLUME_OFFSET]
+                ),
+            ));
+
+            sample_data_size += sample_length as usize;
+            offset += sample_header_size;
+        }
+
+        offset += sample_data_size;
+    }
+
+    Ok((instruments, samples))
+}
+
+fn get_first_instrument_offset(
+    module_data: &[u8],
+    xm_header: &XmHeader,
+) -> Result<usize, LocalError> {
+    let header_size = get_u32_at_offset(module_data, HEADER_SIZE_OFFSET)? as usize;
+    let mut offset = HEADER_SIZE_OFFSET + header_size;
+
+    for _ in 0..xm_header.number_of_patterns {
+        let pattern_header_length = get_u32_at_offset(module_data, offset)? as usize;
+        let packed_size = get_u16_at_offset(module_data, offset + PATTERN_PACKED_SIZE_OFFSET)?;
+        offset += pattern_header_length + packed_size as usize;
+    }
+
+    Ok(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_module_data() -> Vec<u8> {
+        let mut module_data = vec![0u8; HEADER_SIZE_OFFSET];
+        module_data.extend(4u32.to_le_bytes());
+        module_data.extend(9u32.to_le_bytes());
+        module_data.extend([0x00, 0x40, 0x00, 0x02, 0x00, 0x80, 0x80]);
+
+        let mut instrument = 33u32.to_le_bytes().to_vec();
+        instrument.extend(b"Lead Synth".iter().chain([0u8; 12].iter()));
+        instrument.push(0);
+        instrument.extend(1u16.to_le_bytes());
+        instrument.extend(40u32.to_le_bytes());
+        module_data.extend(instrument);
+
+        let mut sample = 4u32.to_le_bytes().to_vec();
+        sample.extend([0u8; 8]);
+        sample.extend([64, 0, 0, 128, 0, 0]);
+        sample.extend(b"lead.wav".iter().chain([0u8; 14].iter()));
+        module_data.extend(sample);
+        module_data.extend([0u8; 4]);
+
+        module_data
+    }
+
+    #[test]
+    fn return_instruments_and_samples_after_skipping_patterns() {
+        let module_data = get_test_module_data();
+        let xm_header = XmHeader {
+            number_of_patterns: 1,
+            number_of_instruments: 1,
+            ..Default::default()
+        };
+
+        let (instruments, samples) =
+            get_instruments_and_samples_from_bytes(&module_data, &xm_header).unwrap();
+        assert_eq!(
+            instruments,
+            vec![ModuleName::new(
+                0,
+                "Lead Synth".to_string(),
+                "1 sample".to_string()
+            )]
+        );
+        assert_eq!(
+            samples,
+            vec![ModuleName::new(
+                0,
+                "lead.wav".to_string(),
+                "4 bytes, 8-bit, volume 64".to_string()
+            )]
+        );
+    }
+    #[test]
+    fn return_instruments_without_samples_when_the_sample_header_is_truncated() {
+        let mut module_data = get_test_module_data();
+        module_data.truncate(module_data.len() - 30);
+        let xm_header = XmHeader {
+            number_of_patterns: 1,
+            number_of_instruments: 1,
+            ..Default::default()
+        };
+
+        let (instruments, samples) =
+            get_instruments_and_samples_from_bytes(&module_data, &xm_header).unwrap();
+        assert_eq!(instruments.len(), 1);
+        assert!(samples.is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+pub mod apev2;
+pub mod comparison;
+pub mod id3v1;
+pub mod lyrics3;
+
+use crate::fileio::read_bytes_from_file;
+use crate::output::OutputEntry;
+use std::error::Error;
+use std::fs::File;
+use std::io::{Seek, SeekFrom};
+
+pub const COMMON_FIELD_NAMES: [&str; 6] = ["Title", "Artist", "Album", "Year", "Track", "Genre"];
+
+pub type CommonFields = Vec<(&'static str, String)>;
+
+#[derive(Debug, Default)]
+pub struct TrailingTags {
+    pub start: u64,
+    id3v1: Option<Vec<u8>>,
+    apev2: Option<Vec<u8>>,
+    lyrics3: Option<Vec<u8>>,
+}
+
+impl TrailingTags {
+    pub fn get_metadata(&self) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+        let mut output: Vec<OutputEntry> = vec![];
+
+        if let Some(apev2_tag) = &self.apev2 {
+            output.push(apev2::get_metadata(apev2_tag.clone())?);
+        }
+
+        if let Some(lyrics3_tag) = &self.lyrics3 {
+            output.push(lyrics3::get_metadata(lyrics3_tag.clone())?);
+        }
+
+        if let Some(id3v1_tag) = &self.id3v1 {
+            output.push(id3v1::get_metadata(id3v1_tag.clone())?);
+        }
+
+        Ok(output)
+    }
+
+    pub fn get_common_fields(&self) -> Result<Vec<(&'static str, CommonFields)>, Box<dyn Error>> {
+        let mut tags: Vec<(&'static str, CommonFields)> = vec![];
+
+        if let Some(id3v1_tag) = &self.id3v1 {
+            tags.push(("ID3v1", id3v1::get_common_fields(id3v1_tag.clone())?));
+        }
+
+        if let Some(apev2_tag) = &self.apev2 {
+            tags.push(("APEv2", apev2::get_common_fields(apev2_tag.clone())?));
+        }
+
+        if let Some(lyrics3_tag) = &self.lyrics3 {
+            tags.push((
+                "Lyrics3v2",
+                lyrics3::get_common_fields(lyrics3_tag.clone())?,
+            ));
+        }
+
+        Ok(tags)
+    }
+}
+
+pub fn get_metadata_from_trailing_tags(
+    file: &mut File,
+) -> Result<Vec<OutputEntry>, Box<dyn Error>> {
+    read_trailing_tags_from_file(file)?.get_metadata()
+}
+
+pub fn read_trailing_tags_from_file(file: &mut File) -> Result<TrailingTags, Box<dyn Error>> {
+    let mut trailing_tags = TrailingTags {
+        start: file.metadata()?.len(),
+        ..Default::default()
+    };
+
+    trailing_tags.id3v1 = read_id3v1_tag_from_file(file, trailing_tags.start)?;
+    if trailing_tags.id3v1.is_some() {
+        trailing_tags.start -= id3v1::TAG_LENGTH_IN_BYTES as u64;
+    }
+
+    loop {
+        if trailing_tags.apev2.is_none() {
+            if let Some(apev2_tag) = read_apev2_tag_from_file(file, trailing_tags.start)? {
+                trailing_tags.start -= apev2_tag.len() as u64;
+                trailing_tags.apev2 = Some(apev2_tag);
+                continue;
+            }
+        }
+
+        if trailing_tags.lyrics3.is_none() {
+            if let Some(lyrics3_tag) = read_lyrics3_tag_from_file(file, trailing_tags.start)? {
+                trailing_tags.start -= lyrics3_tag.len() as u64;
+                trailing_tags.lyrics3 = Some(lyrics3_tag);
+                continue;
+            }
+        }
+
+        return Ok(trailing_tags);
+    }
+}
+
+fn read_id3v1_tag_from_file(
+    file: &mut File,
+    tag_end: u64,
+) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    let tag_length = id3v1::TAG_LENGTH_IN_BYTES as u64;
+
+    if tag_end < tag_length {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(tag_end - tag_length))?;
+    let tag_data = read_bytes_from_file(file, id3v1::TAG_LENGTH_IN_BYTES)?;
+
+    if !id3v1::is_id3v1_tag(&tag_data) {
+        return Ok(None);
+    }
+
+    Ok(Some(tag_data))
+}
+
+fn read_apev2_tag_from_file(
+    file: &mut File,
+    tag_end: u64,
+) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    let footer_length = apev2::FOOTER_LENGTH_IN_BYTES as u64;
+
+    if tag_end < footer_length {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(tag_end - footer_length))?;
+    let footer = read_bytes_from_file(file, apev2::FOOTER_LENGTH_IN_BYTES)?;
+
+    let Some(tag_size) = apev2::get_tag_size_from_footer(&footer) else {
+        return Ok(None);
+    };
+
+    if tag_size < footer_length || tag_size > tag_end {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(tag_end - tag_size))?;
+    let tag_data = read_bytes_from_file(file, tag_size as usize)?;
+
+    Ok(Some(tag_data))
+}
+
+fn read_lyrics3_tag_from_file(
+    file: &mut File,
+    tag_end: u64,
+) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    let footer_length = lyrics3::FOOTER_LENGTH_IN_BYTES as u64;
+
+    if tag_end < footer_length {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(tag_end - footer_length))?;
+    let footer = read_bytes_from_file(file, lyrics3::FOOTER_LENGTH_IN_BYTES)?;
+
+    let Some(tag_size) = lyrics3::get_tag_size_from_footer(&footer) else {
+        return Ok(None);
+    };
+
+    if tag_size > tag_end {
+        return Ok(None);
+    }
+
+    file.seek(SeekFrom::Start(tag_end - tag_size))?;
+    let tag_data = read_bytes_from_file(file, tag_size as usize)?;
+
+    if !lyrics3::is_lyrics3_tag(&tag_data) {
+        return Ok(None);
+    }
+
+    Ok(Some(tag_data))
+}
@@ -0,0 +1,201 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_four_bytes_as_unsigned_integer, take_first_number_of_bytes, Endian,
+};
+use crate::errors::LocalError;
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as Item};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::{CommonFields, COMMON_FIELD_NAMES};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/trailing_tags/apev2.tmpl");
+pub const FOOTER_LENGTH_IN_BYTES: usize = 32;
+const PREAMBLE: &[u8] = b"APETAGEX";
+const TAG_SIZE_OFFSET: usize = 12;
+const TAG_SIZE_LENGTH_IN_BYTES: usize = 4;
+const ITEM_TYPE_MASK: u32 = 0x06;
+const ITEM_TYPE_SHIFT: u32 = 1;
+const BINARY_ITEM_TYPE: u32 = 1;
+const EXTERNAL_LOCATOR_ITEM_TYPE: u32 = 2;
+const READ_ONLY_FLAG_MASK: u32 = 0x01;
+const FLAGS_OFFSET: usize = 20;
+const HEADER_PRESENT_FLAG_MASK: u32 = 0x8000_0000;
+
+pub fn get_tag_size_from_footer(footer: &[u8]) -> Option<u64> {
+    if footer.len() < FOOTER_LENGTH_IN_BYTES || !footer.starts_with(PREAMBLE) {
+        return None;
+    }
+
+    let size_bytes = &footer[TAG_SIZE_OFFSET..TAG_SIZE_OFFSET + TAG_SIZE_LENGTH_IN_BYTES];
+    let tag_size =
+        u32::from_le_bytes([size_bytes[0], size_bytes[1], size_bytes[2], size_bytes[3]]) as u64;
+
+    let flag_bytes = &footer[FLAGS_OFFSET..FLAGS_OFFSET + TAG_SIZE_LENGTH_IN_BYTES];
+    let flags = u32::from_le_bytes([flag_bytes[0], flag_bytes[1], flag_bytes[2], flag_bytes[3]]);
+
+    match flags & HEADER_PRESENT_FLAG_MASK {
+        0 => Some(tag_size),
+        _ => Some(tag_size + FOOTER_LENGTH_IN_BYTES as u64),
+    }
+}
+
+struct Footer {
+    version: u32,
+    item_count: u32,
+    flags: u32,
+}
+
+pub fn get_metadata(mut tag_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let footer = take_footer_from_tag_data(&mut tag_data)?;
+    let items = get_items_from_bytes(tag_data, footer.item_count)?;
+
+    let output_values: Value = upon::value! {
+        version: format!("{:.3}", footer.version as f64 / 1000.0),
+        item_count: footer.item_count,
+        is_read_only: (footer.flags & READ_ONLY_FLAG_MASK) != 0,
+        items: items,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+pub fn get_common_fields(mut tag_data: Vec<u8>) -> Result<CommonFields, Box<dyn Error>> {
+    let footer = take_footer_from_tag_data(&mut tag_data)?;
+    let items = get_items_from_bytes(tag_data, footer.item_count)?;
+
+    Ok(COMMON_FIELD_NAMES
+        .iter()
+        .filter_map(|field_name| {
+            items
+                .iter()
+                .find(|item| item.key.eq_ignore_ascii_case(field_name) && !item.value.is_empty())
+                .map(|item| (*field_name, item.value.trim().to_string()))
+        })
+        .collect())
+}
+
+fn take_footer_from_tag_data(tag_data: &mut Vec<u8>) -> Result<Footer, LocalError> {
+    let footer_start = tag_data.len().saturating_sub(FOOTER_LENGTH_IN_BYTES);
+    let mut footer = tag_data.split_off(footer_start);
+
+    skip_over_bytes(&mut footer, PREAMBLE.len())?;
+    let version = take_first_four_bytes_as_unsigned_integer(&mut footer, Endian::Little)?;
+    skip_over_bytes(&mut footer, TAG_SIZE_LENGTH_IN_BYTES)?;
+    let item_count = take_first_four_bytes_as_unsigned_integer(&mut footer, Endian::Little)?;
+    let flags = take_first_four_bytes_as_unsigned_integer(&mut footer, Endian::Little)?;
+
+    if tag_data.starts_with(PREAMBLE) {
+        skip_over_bytes(tag_data, FOOTER_LENGTH_IN_BYTES)?;
+    }
+
+    Ok(Footer {
+        version,
+        item_count,
+        flags,
+    })
+}
+
+fn get_items_from_bytes(mut item_data: Vec<u8>, item_count: u32) -> Result<Vec<Item>, LocalError> {
+    let mut items: Vec<Item> = vec![];
+
+    for _ in 0..item_count {
+        if item_data.is_empty() {
+            break;
+        }
+
+        let value_size = take_first_four_bytes_as_unsigned_integer(&mut item_data, Endian::Little)?;
+        let item_flags = take_first_four_bytes_as_unsigned_integer(&mut item_data, Endian::Little)?;
+        let key_length = item_data
+            .iter()
+            .position(|byte| *byte == 0x00)
+            .unwrap_or(item_data.len());
+        let key_bytes = take_first_number_of_bytes(&mut item_data, key_length)?;
+        let terminator_length = 1.min(item_data.len());
+        skip_over_bytes(&mut item_data, terminator_length)?;
+        let value_length = (value_size as usize).min(item_data.len());
+        let value_bytes = take_first_number_of_bytes(&mut item_data, value_length)?;
+
+        items.push(Item {
+            key: String::from_utf8_lossy(&key_bytes).to_string(),
+            spacer: String::new(),
+            value: format_item_value(item_flags, &value_bytes),
+        });
+    }
+
+    set_key_value_pair_spacers(&mut items);
+
+    Ok(items)
+}
+
+fn format_item_value(item_flags: u32, value_bytes: &[u8]) -> String {
+    match (item_flags & ITEM_TYPE_MASK) >> ITEM_TYPE_SHIFT {
+        BINARY_ITEM_TYPE => {
+            let description_length = value_bytes
+                .iter()
+                .position(|byte| *byte == 0x00)
+                .unwrap_or(0);
+            let description = String::from_utf8_lossy(&value_bytes[..description_length]);
+
+            if description.is_empty() {
+                format!("[Binary data: {} bytes]", value_bytes.len())
+            } else {
+                format!("[Binary data: {} bytes] {}", value_bytes.len(), description)
+            }
+        }
+        EXTERNAL_LOCATOR_ITEM_TYPE => {
+            format!("[External link] {}", String::from_utf8_lossy(value_bytes))
+        }
+        _ => String::from_utf8_lossy(value_bytes)
+            .split('\0')
+            .collect::<Vec<&str>>()
+            .join(" / "),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_item(key: &str, flags: u32, value: &[u8]) -> Vec<u8> {
+        let mut item: Vec<u8> = (value.len() as u32).to_le_bytes().to_vec();
+        item.extend(flags.to_le_bytes());
+        item.extend(key.as_bytes());
+        item.push(0x00);
+        item.extend(value);
+        item
+    }
+
+    #[test]
+    fn return_correct_tag_size_from_valid_footer() {
+        let mut footer: Vec<u8> = PREAMBLE.to_vec();
+        footer.extend(2000u32.to_le_bytes());
+        footer.extend(100u32.to_le_bytes());
+        footer.extend([0x00; 16]);
+        assert_eq!(get_tag_size_from_footer(&footer), Some(100));
+        assert_eq!(get_tag_size_from_footer(&[0x00; 32]), None);
+
+        footer[FLAGS_OFFSET + 3] = 0x80;
+        assert_eq!(get_tag_size_from_footer(&footer), Some(132));
+    }
+
+    #[test]
+    fn return_correct_items_from_valid_bytes() {
+        let mut item_data = get_test_item("Artist", 0, b"One\0Two");
+        item_data.extend(get_test_item(
+            "Cover Art (Front)",
+            2,
+            b"cover.jpg\0\xFF\xD8",
+        ));
+
+        let result = get_items_from_bytes(item_data, 2).unwrap();
+        assert_eq!(result[0].key, "Artist");
+        assert_eq!(result[0].value, "One / Two");
+        assert_eq!(result[1].value, "[Binary data: 12 bytes] cover.jpg");
+    }
+}
@@ -0,0 +1,149 @@
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as Disagreement};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::{id3v1, CommonFields, COMMON_FIELD_NAMES};
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/trailing_tags/comparison.tmpl");
+const TRACK_FIELD_NAME: &str = "Track";
+const YEAR_FIELD_NAME: &str = "Year";
+const YEAR_LENGTH: usize = 4;
+const TRACK_TOTAL_SEPARATOR: char = '/';
+const MINIMUM_NUMBER_OF_TAGS_TO_COMPARE: usize = 2;
+
+pub fn get_metadata(
+    tags: Vec<(&'static str, CommonFields)>,
+) -> Result<Option<OutputEntry>, Box<dyn Error>> {
+    if tags.len() < MINIMUM_NUMBER_OF_TAGS_TO_COMPARE {
+        return Ok(None);
+    }
+
+    let mut disagreements = get_disagreements(&tags);
+    set_key_value_pair_spacers(&mut disagreements);
+
+    let tag_names: Vec<&str> = tags.iter().map(|(tag_name, _)| *tag_name).collect();
+
+    let output_values: Value = upon::value! {
+        tags: tag_names.join(", "),
+        disagreements: disagreements,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(Some(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    }))
+}
+
+fn get_disagreements(tags: &[(&'static str, CommonFields)]) -> Vec<Disagreement> {
+    let mut disagreements: Vec<Disagreement> = vec![];
+
+    for field_name in COMMON_FIELD_NAMES {
+        let values: Vec<(&str, &String)> = tags
+            .iter()
+            .filter_map(|(tag_name, fields)| {
+                fields
+                    .iter()
+                    .find(|(name, _)| *name == field_name)
+                    .map(|(_, value)| (*tag_name, value))
+            })
+            .collect();
+
+        let Some((_, first_value)) = values.first() else {
+            continue;
+        };
+
+        let is_agreed = values
+            .iter()
+            .all(|(_, value)| is_same_value(field_name, first_value, value));
+
+        if !is_agreed {
+            disagreements.push(Disagreement {
+                key: field_name.to_string(),
+                spacer: String::new(),
+                value: values
+                    .iter()
+                    .map(|(tag_name, value)| format!("{} \"{}\"", tag_name, value))
+                    .collect::<Vec<String>>()
+                    .join(" / "),
+            });
+        }
+    }
+
+    disagreements
+}
+
+fn is_same_value(field_name: &str, first_value: &str, second_value: &str) -> bool {
+    let first_value = normalize_value(field_name, first_value);
+    let second_value = normalize_value(field_name, second_value);
+
+    first_value == second_value
+        || is_truncated_id3v1_value(&first_value, &second_value)
+        || is_truncated_id3v1_value(&second_value, &first_value)
+}
+
+fn normalize_value(field_name: &str, value: &str) -> String {
+    let value = value.trim().to_lowercase();
+
+    match field_name {
+        TRACK_FIELD_NAME => value
+            .split(TRACK_TOTAL_SEPARATOR)
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_start_matches('0')
+            .to_string(),
+        YEAR_FIELD_NAME => value.chars().take(YEAR_LENGTH).collect(),
+        _ => value,
+    }
+}
+
+fn is_truncated_id3v1_value(truncated_value: &str, full_value: &str) -> bool {
+    truncated_value.len() == id3v1::TEXT_FIELD_LENGTH_IN_BYTES
+        && full_value.starts_with(truncated_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_only_fields_that_disagree_between_tags() {
+        let tags = vec![
+            (
+                "ID3v2",
+                vec![
+                    ("Title", "A Title".to_string()),
+                    ("Year", "1999-05-01".to_string()),
+                    ("Track", "3".to_string()),
+                ],
+            ),
+            (
+                "ID3v1",
+                vec![
+                    ("Title", "Another Title".to_string()),
+                    ("Year", "1999".to_string()),
+                ],
+            ),
+            ("APEv2", vec![("Track", "03/12".to_string())]),
+        ];
+
+        let result = get_disagreements(&tags);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].key, "Title");
+        assert_eq!(
+            result[0].value,
+            "ID3v2 \"A Title\" / ID3v1 \"Another Title\""
+        );
+    }
+
+    #[test]
+    fn treat_truncated_id3v1_values_as_matching() {
+        let full_value = "A Very Long Title That Does Not Fit In ID3v1";
+        let truncated_value = &full_value[..id3v1::TEXT_FIELD_LENGTH_IN_BYTES];
+        assert!(is_same_value("Title", full_value, truncated_value));
+        assert!(!is_same_value("Title", full_value, "A Very Long"));
+    }
+}
@@ -0,0 +1,379 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_number_of_bytes,
+    take_first_number_of_bytes_as_string,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::CommonFields;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/trailing_tags/id3v1.tmpl");
+pub const TAG_LENGTH_IN_BYTES: usize = 128;
+const TAG_ID: &[u8] = b"TAG";
+pub const TEXT_FIELD_LENGTH_IN_BYTES: usize = 30;
+const YEAR_LENGTH_IN_BYTES: usize = 4;
+const ID3V1_1_COMMENT_LENGTH_IN_BYTES: usize = 28;
+const UNSET_GENRE_ID: u8 = 0xFF;
+
+const GENRES: [&str; 192] = [
+    "Blues",
+    "Classic Rock",
+    "Country",
+    "Dance",
+    "Disco",
+    "Funk",
+    "Grunge",
+    "Hip-Hop",
+    "Jazz",
+    "Metal",
+    "New Age",
+    "Oldies",
+    "Other",
+    "Pop",
+    "R&B",
+    "Rap",
+    "Reggae",
+    "Rock",
+    "Techno",
+    "Industrial",
+    "Alternative",
+    "Ska",
+    "Death Metal",
+    "Pranks",
+    "Soundtrack",
+    "Euro-Techno",
+    "Ambient",
+    "Trip-Hop",
+    "Vocal",
+    "Jazz+Funk",
+    "Fusion",
+    "Trance",
+    "Classical",
+    "Instrumental",
+    "Acid",
+    "House",
+    "Game",
+    "Sound Clip",
+    "Gospel",
+    "Noise",
+    "Alternative Rock",
+    "Bass",
+    "Soul",
+    "Punk",
+    "Space",
+    "Meditative",
+    "Instrumental Pop",
+    "Instrumental Rock",
+    "Ethnic",
+    "Gothic",
+    "Darkwave",
+    "Techno-Industrial",
+    "Electronic",
+    "Pop-Folk",
+    "Eurodance",
+    "Dream",
+    "Southern Rock",
+    "Comedy",
+    "Cult",
+    "Gangsta",
+    "Top 40",
+    "Christian Rap",
+    "Pop/Funk",
+    "Jungle",
+    "Native American",
+    "Cabaret",
+    "New Wave",
+    "Psychedelic",
+    "Rave",
+    "Showtunes",
+    "Trailer",
+    "Lo-Fi",
+    "Tribal",
+    "Acid Punk",
+    "Acid Jazz",
+    "Polka",
+    "Retro",
+    "Musical",
+    "Rock & Roll",
+    "Hard Rock",
+    "Folk",
+    "Folk-Rock",
+    "National Folk",
+    "Swing",
+    "Fast Fusion",
+    "Bebop",
+    "Latin",
+    "Revival",
+    "Celtic",
+    "Bluegrass",
+    "Avantgarde",
+    "Gothic Rock",
+    "Progressive Rock",
+    "Psychedelic Rock",
+    "Symphonic Rock",
+    "Slow Rock",
+    "Big Band",
+    "Chorus",
+    "Easy Listening",
+    "Acoustic",
+    "Humour",
+    "Speech",
+    "Chanson",
+    "Opera",
+    "Chamber Music",
+    "Sonata",
+    "Symphony",
+    "Booty Bass",
+    "Primus",
+    "Porn Groove",
+    "Satire",
+    "Slow Jam",
+    "Club",
+    "Tango",
+    "Samba",
+    "Folklore",
+    "Ballad",
+    "Power Ballad",
+    "Rhythmic Soul",
+    "Freestyle",
+    "Duet",
+    "Punk Rock",
+    "Drum Solo",
+    "A Cappella",
+    "Euro-House",
+    "Dance Hall",
+    "Goa",
+    "Drum & Bass",
+    "Club-House",
+    "Hardcore Techno",
+    "Terror",
+    "Indie",
+    "BritPop",
+    "Negerpunk",
+    "Polsk Punk",
+    "Beat",
+    "Christian Gangsta Rap",
+    "Heavy Metal",
+    "Black Metal",
+    "Crossover",
+    "Contemporary Christian",
+    "Christian Rock",
+    "Merengue",
+    "Salsa",
+    "Thrash Metal",
+    "Anime",
+    "Jpop",
+    "Synthpop",
+    "Abstract",
+    "Art Rock",
+    "Baroque",
+    "Bhangra",
+    "Big Beat",
+    "Breakbeat",
+    "Chillout",
+    "Downtempo",
+    "Dub",
+    "EBM",
+    "Eclectic",
+    "Electro",
+    "Electroclash",
+    "Emo",
+    "Experimental",
+    "Garage",
+    "Global",
+    "IDM",
+    "Illbient",
+    "Industro-Goth",
+    "Jam Band",
+    "Krautrock",
+    "Leftfield",
+    "Lounge",
+    "Math Rock",
+    "New Romantic",
+    "Nu-Breakz",
+    "Post-Punk",
+    "Post-Rock",
+    "Psytrance",
+    "Shoegaze",
+    "Space Rock",
+    "Trop Rock",
+    "World Music",
+    "Neoclassical",
+    "Audiobook",
+    "Audio Theatre",
+    "Neue Deutsche Welle",
+    "Podcast",
+    "Indie Rock",
+    "G-Funk",
+    "Dubstep",
+    "Garage Rock",
+    "Psybient",
+];
+
+pub fn is_id3v1_tag(tag_data: &[u8]) -> bool {
+    tag_data.len() == TAG_LENGTH_IN_BYTES && tag_data.starts_with(TAG_ID)
+}
+
+struct Id3v1Tag {
+    title: String,
+    artist: String,
+    album: String,
+    year: String,
+    comment: String,
+    track: Option<u8>,
+    genre_id: u8,
+}
+
+pub fn get_metadata(tag_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let tag = get_tag_from_bytes(tag_data)?;
+
+    let output_values: Value = upon::value! {
+        version: match tag.track {
+            Some(_) => "ID3v1.1",
+            None => "ID3v1",
+        },
+        title: tag.title,
+        artist: tag.artist,
+        album: tag.album,
+        year: tag.year,
+        comment: tag.comment,
+        track: tag.track.map(|track| track.to_string()).unwrap_or_default(),
+        genre: format_genre(tag.genre_id),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+pub fn get_common_fields(tag_data: Vec<u8>) -> Result<CommonFields, Box<dyn Error>> {
+    let tag = get_tag_from_bytes(tag_data)?;
+
+    let fields = [
+        ("Title", tag.title),
+        ("Artist", tag.artist),
+        ("Album", tag.album),
+        ("Year", tag.year),
+        (
+            "Track",
+            tag.track.map(|track| track.to_string()).unwrap_or_default(),
+        ),
+        (
+            "Genre",
+            GENRES
+                .get(tag.genre_id as usize)
+                .map(|genre| genre.to_string())
+                .unwrap_or_default(),
+        ),
+    ];
+
+    Ok(fields
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .collect())
+}
+
+fn get_tag_from_bytes(mut tag_data: Vec<u8>) -> Result<Id3v1Tag, Box<dyn Error>> {
+    skip_over_bytes(&mut tag_data, TAG_ID.len())?;
+    let title = take_text_field(&mut tag_data, TEXT_FIELD_LENGTH_IN_BYTES)?;
+    let artist = take_text_field(&mut tag_data, TEXT_FIELD_LENGTH_IN_BYTES)?;
+    let album = take_text_field(&mut tag_data, TEXT_FIELD_LENGTH_IN_BYTES)?;
+    let year = take_text_field(&mut tag_data, YEAR_LENGTH_IN_BYTES)?;
+    let comment_bytes = take_first_number_of_bytes(&mut tag_data, TEXT_FIELD_LENGTH_IN_BYTES)?;
+    let genre_id = take_first_byte(&mut tag_data)?;
+
+    let (comment, track) = get_comment_and_track_from_bytes(comment_bytes)?;
+
+    Ok(Id3v1Tag {
+        title,
+        artist,
+        album,
+        year,
+        comment,
+        track,
+        genre_id,
+    })
+}
+
+fn take_text_field(tag_data: &mut Vec<u8>, field_length: usize) -> Result<String, Box<dyn Error>> {
+    let field_bytes = take_first_number_of_bytes(tag_data, field_length)?;
+    let text_length = field_bytes
+        .iter()
+        .position(|byte| *byte == 0x00)
+        .unwrap_or(field_bytes.len());
+
+    Ok(String::from_utf8_lossy(&field_bytes[..text_length])
+        .trim()
+        .to_string())
+}
+
+fn get_comment_and_track_from_bytes(
+    mut comment_bytes: Vec<u8>,
+) -> Result<(String, Option<u8>), Box<dyn Error>> {
+    let is_id3v1_1 = comment_bytes[ID3V1_1_COMMENT_LENGTH_IN_BYTES] == 0x00
+        && comment_bytes[ID3V1_1_COMMENT_LENGTH_IN_BYTES + 1] != 0x00;
+
+    if is_id3v1_1 {
+        let track = comment_bytes[ID3V1_1_COMMENT_LENGTH_IN_BYTES + 1];
+        let comment = take_text_field(&mut comment_bytes, ID3V1_1_COMMENT_LENGTH_IN_BYTES)?;
+        return Ok((comment, Some(track)));
+    }
+
+    let comment_length = comment_bytes.len();
+    let comment = take_first_number_of_bytes_as_string(&mut comment_bytes, comment_length)?;
+
+    Ok((comment.trim().to_string(), None))
+}
+
+pub fn format_genre(genre_id: u8) -> String {
+    if genre_id == UNSET_GENRE_ID {
+        return String::new();
+    }
+
+    match GENRES.get(genre_id as usize) {
+        Some(genre) => format!("{} ({})", genre, genre_id),
+        None => format!("Unknown ({})", genre_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_tag(comment: &[u8], genre_id: u8) -> Vec<u8> {
+        let mut tag = b"TAG".to_vec();
+        for field in [b"A Title".as_slice(), b"An Artist", b"An Album"] {
+            let mut field = field.to_vec();
+            field.resize(TEXT_FIELD_LENGTH_IN_BYTES, 0x00);
+            tag.extend(field);
+        }
+        tag.extend(b"1999");
+        let mut comment = comment.to_vec();
+        comment.resize(TEXT_FIELD_LENGTH_IN_BYTES, 0x00);
+        tag.extend(comment);
+        tag.push(genre_id);
+        tag
+    }
+
+    #[test]
+    fn return_track_number_from_id3v1_1_comment() {
+        let mut comment = b"A Comment".to_vec();
+        comment.resize(TEXT_FIELD_LENGTH_IN_BYTES, 0x00);
+        comment[TEXT_FIELD_LENGTH_IN_BYTES - 1] = 7;
+        let result = get_comment_and_track_from_bytes(comment).unwrap();
+        assert_eq!(result, ("A Comment".to_string(), Some(7)));
+    }
+
+    #[test]
+    fn return_correct_output_from_id3v1_tag() {
+        let tag = get_test_tag(b"A Comment", 17);
+        assert!(is_id3v1_tag(&tag));
+        let result = get_metadata(tag).unwrap();
+        let correct_result = "----------\nID3v1 Tag:\n----------\nVersion:               ID3v1\nTitle:                 A Title\nArtist:                An Artist\nAlbum:                 An Album\nYear:                  1999\nComment:               A Comment\nGenre:                 Rock (17)\n";
+        assert_eq!(result.text, correct_result);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::byte_arrays::{skip_over_bytes, take_first_number_of_bytes};
+use crate::errors::LocalError;
+use crate::formating::{set_key_value_pair_spacers, KeyValuePair as Field};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use crate::trailing_tags::CommonFields;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/trailing_tags/lyrics3.tmpl");
+pub const FOOTER_LENGTH_IN_BYTES: usize = 15;
+const BEGIN_ID: &[u8] = b"LYRICSBEGIN";
+const END_ID: &[u8] = b"LYRICS200";
+const TAG_SIZE_LENGTH_IN_BYTES: usize = 6;
+const FIELD_ID_LENGTH_IN_BYTES: usize = 3;
+const FIELD_SIZE_LENGTH_IN_BYTES: usize = 5;
+const LINE_BREAK: &str = "\r\n";
+const KEY_VALUE_SEPARATOR_LENGTH: usize = 2;
+
+const FIELD_NAMES: [(&str, &str); 8] = [
+    ("IND", "Indications"),
+    ("LYR", "Lyrics"),
+    ("INF", "Information"),
+    ("AUT", "Author"),
+    ("EAL", "Album"),
+    ("EAR", "Artist"),
+    ("ETT", "Title"),
+    ("IMG", "Image Links"),
+];
+
+const COMMON_FIELD_IDS: [(&str, &str); 3] = [("ETT", "Title"), ("EAR", "Artist"), ("EAL", "Album")];
+
+pub fn get_tag_size_from_footer(footer: &[u8]) -> Option<u64> {
+    if footer.len() < FOOTER_LENGTH_IN_BYTES || !footer.ends_with(END_ID) {
+        return None;
+    }
+
+    let size_text = std::str::from_utf8(&footer[..TAG_SIZE_LENGTH_IN_BYTES]).ok()?;
+    let tag_size = size_text.parse::<u64>().ok()?;
+
+    Some(tag_size + FOOTER_LENGTH_IN_BYTES as u64)
+}
+
+pub fn is_lyrics3_tag(tag_data: &[u8]) -> bool {
+    tag_data.starts_with(BEGIN_ID)
+}
+
+pub fn get_metadata(tag_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let raw_fields = get_fields_from_tag_data(tag_data)?;
+
+    let mut fields: Vec<Field> = raw_fields
+        .iter()
+        .map(|(id, value)| Field {
+            key: get_field_name(id),
+            spacer: String::new(),
+            value: value.trim_end().to_string(),
+        })
+        .collect();
+    set_key_value_pair_spacers(&mut fields);
+
+    for field in fields.iter_mut() {
+        let indent = " ".repeat(field.key.len() + field.spacer.len() + KEY_VALUE_SEPARATOR_LENGTH);
+        field.value = field.value.replace(LINE_BREAK, &format!("\n{}", indent));
+    }
+
+    let output_values: Value = upon::value! {
+        field_count: fields.len(),
+        fields: fields,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
+
+pub fn get_common_fields(tag_data: Vec<u8>) -> Result<CommonFields, Box<dyn Error>> {
+    let raw_fields = get_fields_from_tag_data(tag_data)?;
+
+    Ok(COMMON_FIELD_IDS
+        .iter()
+        .filter_map(|(common_id, common_name)| {
+            raw_fields
+                .iter()
+                .find(|(id, value)| id == common_id && !value.trim().is_empty())
+                .map(|(_, value)| (*common_name, value.trim().to_string()))
+        })
+        .collect())
+}
+
+fn get_fields_from_tag_data(mut tag_data: Vec<u8>) -> Result<Vec<(String, String)>, LocalError> {
+    let fields_length = tag_data.len().saturating_sub(FOOTER_LENGTH_IN_BYTES);
+    tag_data.truncate(fields_length);
+    skip_over_bytes(&mut tag_data, BEGIN_ID.len())?;
+
+    let mut fields: Vec<(String, String)> = vec![];
+
+    while tag_data.len() >= FIELD_ID_LENGTH_IN_BYTES + FIELD_SIZE_LENGTH_IN_BYTES {
+        let id_bytes = take_first_number_of_bytes(&mut tag_data, FIELD_ID_LENGTH_IN_BYTES)?;
+        let size_bytes = take_first_number_of_bytes(&mut tag_data, FIELD_SIZE_LENGTH_IN_BYTES)?;
+
+        let Some(field_size) = String::from_utf8_lossy(&size_bytes).parse::<usize>().ok() else {
+            break;
+        };
+
+        let value_length = field_size.min(tag_data.len());
+        let value_bytes = take_first_number_of_bytes(&mut tag_data, value_length)?;
+
+        fields.push((
+            String::from_utf8_lossy(&id_bytes).to_string(),
+            String::from_utf8_lossy(&value_bytes).to_string(),
+        ));
+    }
+
+    Ok(fields)
+}
+
+fn get_field_name(field_id: &str) -> String {
+    match FIELD_NAMES.iter().find(|(id, _)| *id == field_id) {
+        Some((_, name)) => name.to_string(),
+        None => format!("Unknown ({})", field_id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_tag() -> Vec<u8> {
+        let mut tag = BEGIN_ID.to_vec();
+        tag.extend(b"IND00002".iter().chain(b"10"));
+        tag.extend(b"LYR00010".iter().chain(b"One\r\nTwo\r\n"));
+        tag.extend(b"ETT00007".iter().chain(b"A Title"));
+        tag.extend(format!("{:06}", tag.len()).as_bytes());
+        tag.extend(END_ID);
+        tag
+    }
+
+    #[test]
+    fn return_correct_tag_size_from_valid_footer() {
+        let tag = get_test_tag();
+        let footer = &tag[tag.len() - FOOTER_LENGTH_IN_BYTES..];
+        assert_eq!(get_tag_size_from_footer(footer), Some(tag.len() as u64));
+        assert_eq!(get_tag_size_from_footer(&[0x00; 15]), None);
+    }
+
+    #[test]
+    fn return_correct_fields_from_valid_tag() {
+        let result = get_fields_from_tag_data(get_test_tag()).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[1], ("LYR".to_string(), "One\r\nTwo\r\n".to_string()));
+
+        let common_fields = get_common_fields(get_test_tag()).unwrap();
+        assert_eq!(common_fields, vec![("Title", "A Title".to_string())]);
+    }
+}
@@ -0,0 +1,125 @@
+pub mod channel_info;
+pub mod checksum;
+pub mod header;
+
+pub const BLOCK_ID: &[u8] = b"wvpk";
+pub const BLOCK_HEADER_LENGTH_IN_BYTES: usize = 32;
+pub const CHANNEL_INFO_ID: u8 = 0x0D;
+pub const RIFF_HEADER_ID: u8 = 0x21;
+pub const RIFF_TRAILER_ID: u8 = 0x22;
+pub const ALT_HEADER_ID: u8 = 0x23;
+pub const ALT_TRAILER_ID: u8 = 0x24;
+pub const MD5_CHECKSUM_ID: u8 = 0x26;
+pub const SAMPLE_RATE_ID: u8 = 0x27;
+pub const ALT_MD5_CHECKSUM_ID: u8 = 0x29;
+pub const METADATA_SUB_BLOCK_IDS: [u8; 8] = [
+    CHANNEL_INFO_ID,
+    RIFF_HEADER_ID,
+    RIFF_TRAILER_ID,
+    ALT_HEADER_ID,
+    ALT_TRAILER_ID,
+    MD5_CHECKSUM_ID,
+    SAMPLE_RATE_ID,
+    ALT_MD5_CHECKSUM_ID,
+];
+const FUNCTION_ID_MASK: u8 = 0x3F;
+const ODD_SIZE_FLAG_MASK: u8 = 0x40;
+const LARGE_SIZE_FLAG_MASK: u8 = 0x80;
+const SMALL_SUB_BLOCK_HEADER_LENGTH_IN_BYTES: usize = 2;
+const LARGE_SUB_BLOCK_HEADER_LENGTH_IN_BYTES: usize = 4;
+const SUB_BLOCK_NAMES: [(u8, &str); 25] = [
+    (0x00, "Dummy"),
+    (0x01, "Encoder Info"),
+    (0x02, "Decorrelation Terms"),
+    (0x03, "Decorrelation Weights"),
+    (0x04, "Decorrelation Samples"),
+    (0x05, "Entropy Variables"),
+    (0x06, "Hybrid Profile"),
+    (0x07, "Shaping Weights"),
+    (0x08, "Float Info"),
+    (0x09, "Int32 Info"),
+    (0x0A, "WavPack Bitstream"),
+    (0x0B, "Correction Bitstream"),
+    (0x0C, "Extended Float Bitstream"),
+    (CHANNEL_INFO_ID, "Channel Info"),
+    (0x0E, "DSD Block"),
+    (RIFF_HEADER_ID, "RIFF Header"),
+    (RIFF_TRAILER_ID, "RIFF Trailer"),
+    (ALT_HEADER_ID, "Alternate Header"),
+    (ALT_TRAILER_ID, "Alternate Trailer"),
+    (0x25, "Config Block"),
+    (MD5_CHECKSUM_ID, "MD5 Checksum"),
+    (SAMPLE_RATE_ID, "Sample Rate"),
+    (0x28, "Alternate Extension"),
+    (0x2A, "New Config Block"),
+    (0x2F, "Block Checksum"),
+];
+
+#[derive(Debug, PartialEq)]
+pub struct SubBlockHeader {
+    pub id: u8,
+    pub data_size: usize,
+    pub padded_size: usize,
+}
+
+pub fn get_sub_block_header_length_from_first_byte(first_byte: u8) -> usize {
+    if first_byte & LARGE_SIZE_FLAG_MASK != 0 {
+        LARGE_SUB_BLOCK_HEADER_LENGTH_IN_BYTES
+    } else {
+        SMALL_SUB_BLOCK_HEADER_LENGTH_IN_BYTES
+    }
+}
+
+pub fn get_sub_block_header_from_bytes(header_bytes: &[u8]) -> SubBlockHeader {
+    let id_byte = header_bytes.first().copied().unwrap_or_default();
+    let size_in_words = header_bytes
+        .iter()
+        .skip(1)
+        .rev()
+        .fold(0, |size, byte| (size << 8) | *byte as usize);
+    let padded_size = size_in_words * 2;
+
+    let data_size = if id_byte & ODD_SIZE_FLAG_MASK != 0 {
+        padded_size.saturating_sub(1)
+    } else {
+        padded_size
+    };
+
+    SubBlockHeader {
+        id: id_byte & FUNCTION_ID_MASK,
+        data_size,
+        padded_size,
+    }
+}
+
+pub fn get_sub_block_name(sub_block_id: u8) -> String {
+    SUB_BLOCK_NAMES
+        .iter()
+        .find(|(id, _)| *id == sub_block_id)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or(format!("Unknown Sub-Block {:#04X}", sub_block_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_sub_block_header_from_small_odd_sized_header_bytes() {
+        let result = get_sub_block_header_from_bytes(&[0x66, 0x08]);
+        let correct_result = SubBlockHeader {
+            id: MD5_CHECKSUM_ID,
+            data_size: 15,
+            padded_size: 16,
+        };
+        assert_eq!(result, correct_result);
+    }
+
+    #[test]
+    fn return_correct_sub_block_header_from_large_header_bytes() {
+        assert_eq!(get_sub_block_header_length_from_first_byte(0x8A), 4);
+        let result = get_sub_block_header_from_bytes(&[0x8A, 0x00, 0x01, 0x00]);
+        assert_eq!(result.id, 0x0A);
+        assert_eq!(result.data_size, 512);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::chunks::fmt::format_speaker_position;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/wavpack_blocks/channel_info.tmpl");
+const EXTENDED_CHANNEL_INFO_LENGTH_IN_BYTES: usize = 6;
+const EXTENDED_CHANNEL_MASK_OFFSET: usize = 3;
+const CHANNEL_MASK_OFFSET: usize = 1;
+
+pub fn get_metadata(sub_block_data: Vec<u8>) -> Result<OutputEntry, Box<dyn Error>> {
+    let (number_of_channels, channel_mask) = get_channels_and_mask_from_bytes(&sub_block_data);
+
+    let output_values: Value = upon::value! {
+        number_of_channels: number_of_channels,
+        channel_mask: format_speaker_position(channel_mask),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_channels_and_mask_from_bytes(sub_block_data: &[u8]) -> (u32, u32) {
+    let first_byte = sub_block_data.first().copied().unwrap_or_default() as u32;
+
+    if sub_block_data.len() >= EXTENDED_CHANNEL_INFO_LENGTH_IN_BYTES {
+        let number_of_channels = (first_byte | ((sub_block_data[2] as u32 & 0x0F) << 8)) + 1;
+        let channel_mask = get_mask_from_bytes(&sub_block_data[EXTENDED_CHANNEL_MASK_OFFSET..]);
+        return (number_of_channels, channel_mask);
+    }
+
+    let mask_bytes = sub_block_data
+        .get(CHANNEL_MASK_OFFSET..)
+        .unwrap_or_default();
+    (first_byte, get_mask_from_bytes(mask_bytes))
+}
+
+fn get_mask_from_bytes(mask_bytes: &[u8]) -> u32 {
+    mask_bytes
+        .iter()
+        .take(4)
+        .rev()
+        .fold(0, |mask, byte| (mask << 8) | *byte as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_channels_and_mask_from_short_and_extended_bytes() {
+        assert_eq!(get_channels_and_mask_from_bytes(&[0x02, 0x03]), (2, 3));
+        assert_eq!(
+            get_channels_and_mask_from_bytes(&[0x05, 0x05, 0x00, 0x3F, 0x00, 0x00]),
+            (6, 0x3F)
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use crate::formating::format_bytes_as_string_of_bytes;
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/wavpack_blocks/checksum.tmpl");
+
+pub fn get_metadata(
+    coverage: &str,
+    sub_block_data: Vec<u8>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    let output_values: Value = upon::value! {
+        coverage: coverage,
+        checksum: format_bytes_as_string_of_bytes(&sub_block_data).replace(' ', ""),
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Optional,
+        text: formated_output,
+    })
+}
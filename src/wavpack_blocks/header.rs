@@ -0,0 +1,127 @@
+use crate::byte_arrays::{
+    skip_over_bytes, take_first_byte, take_first_four_bytes_as_unsigned_integer,
+    take_first_two_bytes_as_unsigned_integer, Endian,
+};
+use crate::output::{OutputEntry, Section};
+use crate::template::get_file_chunk_output;
+use std::error::Error;
+use upon::Value;
+
+const TEMPLATE_CONTENT: &str = include_str!("../templates/wavpack_blocks/header.tmpl");
+const BLOCK_ID_AND_SIZE_LENGTH_IN_BYTES: usize = 8;
+const BYTES_PER_SAMPLE_MASK: u32 = 0x03;
+const MONO_FLAG_MASK: u32 = 0x04;
+const HYBRID_FLAG_MASK: u32 = 0x08;
+const JOINT_STEREO_FLAG_MASK: u32 = 0x10;
+const FLOAT_FLAG_MASK: u32 = 0x80;
+const SAMPLE_RATE_INDEX_SHIFT: u32 = 23;
+const SAMPLE_RATE_INDEX_MASK: u32 = 0x0F;
+const HAS_CHECKSUM_FLAG_MASK: u32 = 0x1000_0000;
+const FALSE_STEREO_FLAG_MASK: u32 = 0x4000_0000;
+const DSD_FLAG_MASK: u32 = 0x8000_0000;
+const UNKNOWN_TOTAL_SAMPLES: u32 = 0xFFFF_FFFF;
+const CUSTOM_SAMPLE_RATE_INDEX: u32 = 15;
+const SAMPLE_RATES: [u32; 15] = [
+    6000, 8000, 9600, 11025, 12000, 16000, 22050, 24000, 32000, 44100, 48000, 64000, 88200, 96000,
+    192000,
+];
+
+pub fn get_metadata(
+    mut header_data: Vec<u8>,
+    number_of_blocks: usize,
+    custom_sample_rate: Option<u32>,
+) -> Result<OutputEntry, Box<dyn Error>> {
+    skip_over_bytes(&mut header_data, BLOCK_ID_AND_SIZE_LENGTH_IN_BYTES)?;
+    let version = take_first_two_bytes_as_unsigned_integer(&mut header_data, Endian::Little)?;
+    let block_index_upper_bits = take_first_byte(&mut header_data)?;
+    let total_samples_upper_bits = take_first_byte(&mut header_data)?;
+    let total_samples_lower_bits =
+        take_first_four_bytes_as_unsigned_integer(&mut header_data, Endian::Little)?;
+    let block_index_lower_bits =
+        take_first_four_bytes_as_unsigned_integer(&mut header_data, Endian::Little)?;
+    let block_samples =
+        take_first_four_bytes_as_unsigned_integer(&mut header_data, Endian::Little)?;
+    let flags = take_first_four_bytes_as_unsigned_integer(&mut header_data, Endian::Little)?;
+
+    let sample_rate_index = (flags >> SAMPLE_RATE_INDEX_SHIFT) & SAMPLE_RATE_INDEX_MASK;
+    let sample_rate = get_sample_rate(sample_rate_index, custom_sample_rate);
+
+    let mut total_samples = String::from("Unknown");
+    let mut duration = String::new();
+    if total_samples_lower_bits != UNKNOWN_TOTAL_SAMPLES {
+        let samples = ((total_samples_upper_bits as u64) << 32) + total_samples_lower_bits as u64;
+        total_samples = samples.to_string();
+
+        if sample_rate > 0 {
+            duration = format!("{:.3} seconds", samples as f64 / sample_rate as f64);
+        }
+    }
+
+    let is_float = flags & FLOAT_FLAG_MASK != 0;
+    let bits_per_sample = ((flags & BYTES_PER_SAMPLE_MASK) + 1) * 8;
+
+    let output_values: Value = upon::value! {
+        version: format!("{:#X}", version),
+        total_samples: total_samples,
+        duration: duration,
+        block_index: ((block_index_upper_bits as u64) << 32) + block_index_lower_bits as u64,
+        block_samples: block_samples,
+        number_of_blocks: number_of_blocks,
+        bits_per_sample: bits_per_sample,
+        is_float: is_float,
+        channels: format_channels(flags),
+        is_joint_stereo: flags & JOINT_STEREO_FLAG_MASK != 0,
+        compression_mode: if flags & HYBRID_FLAG_MASK != 0 { "Hybrid (Lossy)" } else { "Lossless" },
+        is_dsd: flags & DSD_FLAG_MASK != 0,
+        has_checksum: flags & HAS_CHECKSUM_FLAG_MASK != 0,
+        sample_rate_index: sample_rate_index,
+        sample_rate: sample_rate as f64 / 1000.0,
+    };
+
+    let formated_output = get_file_chunk_output(TEMPLATE_CONTENT, output_values)?;
+
+    Ok(OutputEntry {
+        section: Section::Mandatory,
+        text: formated_output,
+    })
+}
+
+fn get_sample_rate(sample_rate_index: u32, custom_sample_rate: Option<u32>) -> u32 {
+    if sample_rate_index == CUSTOM_SAMPLE_RATE_INDEX {
+        return custom_sample_rate.unwrap_or_default();
+    }
+
+    SAMPLE_RATES
+        .get(sample_rate_index as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn format_channels(flags: u32) -> String {
+    if flags & FALSE_STEREO_FLAG_MASK != 0 {
+        "Stereo (Encoded as Mono)".to_string()
+    } else if flags & MONO_FLAG_MASK != 0 {
+        "Mono".to_string()
+    } else {
+        "Stereo".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn return_correct_sample_rates_from_indexes() {
+        assert_eq!(get_sample_rate(9, None), 44100);
+        assert_eq!(get_sample_rate(15, Some(352800)), 352800);
+        assert_eq!(get_sample_rate(15, None), 0);
+    }
+
+    #[test]
+    fn return_correct_channel_description_from_flags() {
+        assert_eq!(format_channels(0x04), "Mono");
+        assert_eq!(format_channels(0x4000_0004), "Stereo (Encoded as Mono)");
+        assert_eq!(format_channels(0x00), "Stereo");
+    }
+}